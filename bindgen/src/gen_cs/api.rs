@@ -0,0 +1,1477 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use uniffi_bindgen::interface::*;
+use uniffi_bindgen::ComponentInterface;
+
+use super::{filters, ArgumentsKey, Config};
+
+// Machine readable description of the public C# API generated for a component. Names and
+// signatures are the ones emitted by the templates, so `rename` and the naming rules of
+// `CsCodeOracle` are already applied. Two reports can be compared with `diff`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiReport {
+    pub namespace: String,
+    pub types: Vec<ApiType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiType {
+    // Nested types are qualified with the enclosing type, e.g. `Shape.Circle`.
+    pub name: String,
+    pub kind: TypeKind,
+    pub access: String,
+    // Interfaces implemented by the consumer of the bindings, e.g. callback interfaces. Adding
+    // members to these is a breaking change.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub implementable: bool,
+    pub members: Vec<ApiMember>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiMember {
+    pub kind: MemberKind,
+    pub name: String,
+    // Return type of methods and operators, or type of properties and fields.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub returns: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<ApiParameter>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub is_static: bool,
+    // Properties with a setter.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub settable: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiParameter {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TypeKind {
+    Class,
+    StaticClass,
+    Interface,
+    Record,
+    RecordStruct,
+    Enum,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemberKind {
+    Constructor,
+    Method,
+    Operator,
+    Property,
+    Field,
+    Variant,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+// `CancellationToken` parameter appended to async methods, see `arg_list_decl_with_token`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    // Synchronous methods, and async methods of interfaces implemented in C#.
+    None,
+    Optional,
+    Required,
+}
+
+impl Token {
+    fn of(is_async: bool) -> Self {
+        if is_async {
+            Token::Optional
+        } else {
+            Token::None
+        }
+    }
+}
+
+impl fmt::Display for TypeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TypeKind::Class => "class",
+            TypeKind::StaticClass => "static class",
+            TypeKind::Interface => "interface",
+            TypeKind::Record => "record",
+            TypeKind::RecordStruct => "record struct",
+            TypeKind::Enum => "enum",
+        })
+    }
+}
+
+impl fmt::Display for MemberKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MemberKind::Constructor => "constructor",
+            MemberKind::Method => "method",
+            MemberKind::Operator => "operator",
+            MemberKind::Property => "property",
+            MemberKind::Field => "field",
+            MemberKind::Variant => "variant",
+        })
+    }
+}
+
+impl ApiType {
+    fn new(name: String, kind: TypeKind, access: String) -> Self {
+        Self {
+            name,
+            kind,
+            access,
+            implementable: false,
+            members: Vec::new(),
+        }
+    }
+}
+
+impl ApiMember {
+    fn new(kind: MemberKind, name: String) -> Self {
+        Self {
+            kind,
+            name,
+            returns: None,
+            parameters: Vec::new(),
+            is_static: false,
+            settable: false,
+        }
+    }
+
+    fn returns(mut self, returns: String) -> Self {
+        self.returns = Some(returns);
+        self
+    }
+
+    fn parameters(mut self, parameters: Vec<ApiParameter>) -> Self {
+        self.parameters = parameters;
+        self
+    }
+
+    fn is_static(mut self, is_static: bool) -> Self {
+        self.is_static = is_static;
+        self
+    }
+
+    fn settable(mut self, settable: bool) -> Self {
+        self.settable = settable;
+        self
+    }
+
+    // Members are identified by kind, name and parameter types, like C# overloads.
+    fn key(&self) -> String {
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|p| p.type_.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        match self.kind {
+            MemberKind::Constructor | MemberKind::Method | MemberKind::Operator => {
+                format!("{} {}({parameters})", self.kind, self.name)
+            }
+            _ => format!("{} {}", self.kind, self.name),
+        }
+    }
+
+    // Whether calls to `old` still compile against `self`, because `self` only appends
+    // parameters with default values.
+    fn extends(&self, old: &ApiMember) -> bool {
+        self.kind == old.kind
+            && self.name == old.name
+            && self.returns == old.returns
+            && self.is_static == old.is_static
+            && self.parameters.len() > old.parameters.len()
+            && self.parameters[..old.parameters.len()]
+                .iter()
+                .zip(&old.parameters)
+                .all(|(new, old)| new.name == old.name && new.type_ == old.type_)
+            && self.parameters[old.parameters.len()..]
+                .iter()
+                .all(|p| p.default.is_some())
+    }
+}
+
+// Generate the API report for the given ComponentInterface.
+pub fn generate_api_report(config: &Config, ci: &ComponentInterface) -> Result<ApiReport> {
+    let report = ReportBuilder { config, ci };
+    let mut types = Vec::new();
+
+    if !ci.function_definitions().is_empty() {
+        let mut methods_class = ApiType::new(
+            config.methods_class_name(ci),
+            TypeKind::StaticClass,
+            config.access_modifier(),
+        );
+        for func in ci.function_definitions() {
+            if config.is_excluded(func.name())
+                || config.function_access_modifier(func.name()) != "public"
+            {
+                continue;
+            }
+            methods_class.members.extend(report.callable(
+                filters::fn_name(func.name())?,
+                &func.arguments(),
+                func.arguments_key(),
+                func.return_type(),
+                func.is_async(),
+                true,
+            )?);
+        }
+        types.push(methods_class);
+    }
+
+    for obj in ci.object_definitions() {
+        if !config.is_excluded(obj.name()) {
+            types.extend(report.object(obj)?);
+        }
+    }
+    for rec in ci.record_definitions() {
+        if !config.is_excluded(rec.name()) {
+            types.push(report.record(rec)?);
+        }
+    }
+    for e in ci.enum_definitions() {
+        if config.is_excluded(e.name()) {
+            continue;
+        }
+        if ci.is_name_used_as_error(e.name()) {
+            types.extend(report.error(e)?);
+        } else {
+            types.extend(report.enum_(e)?);
+        }
+    }
+    for cbi in ci.callback_interface_definitions() {
+        if config.is_excluded(cbi.name()) {
+            continue;
+        }
+        let mut interface = ApiType::new(
+            report.type_name(cbi)?,
+            TypeKind::Interface,
+            config.type_access_modifier(cbi.name()),
+        );
+        interface.implementable = true;
+        for meth in cbi.methods() {
+            interface.members.push(
+                ApiMember::new(
+                    MemberKind::Method,
+                    filters::async_name(&filters::fn_name(meth.name())?, &meth.is_async(), config)?,
+                )
+                .returns(report.return_type(meth.return_type(), meth.is_async())?)
+                .parameters(report.parameters(
+                    &meth.arguments(),
+                    None,
+                    Token::None,
+                    "",
+                )?),
+            );
+        }
+        types.push(interface);
+    }
+
+    for t in &mut types {
+        t.members.sort_by_key(ApiMember::key);
+    }
+    types.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(ApiReport {
+        namespace: config.namespace(),
+        types,
+    })
+}
+
+struct ReportBuilder<'a> {
+    config: &'a Config,
+    ci: &'a ComponentInterface,
+}
+
+impl ReportBuilder<'_> {
+    fn type_name(&self, as_type: &impl AsType) -> Result<String> {
+        Ok(filters::type_name(as_type, self.ci, self.config)?)
+    }
+
+    // Type of a record field or argument, see `collections.overrides`.
+    fn member_type_name(&self, as_type: &impl AsType, prefix: &str, name: &str) -> String {
+        super::CsCodeOracle.member_type_label(
+            &as_type.as_type(),
+            &format!("{prefix}.{name}"),
+            self.ci,
+            self.config,
+        )
+    }
+
+    // Parameters as declared by the `arg_list_decl_with_token` macro.
+    fn parameters(
+        &self,
+        args: &[&Argument],
+        arguments_key: Option<&str>,
+        token: Token,
+        bytes_type: &str,
+    ) -> Result<Vec<ApiParameter>> {
+        let mut parameters = Vec::new();
+        for (index, arg) in args.iter().enumerate() {
+            let bytes_as_type = filters::is_bytes(*arg)? && !bytes_type.is_empty();
+            let drops_default =
+                !bytes_type.is_empty() && filters::has_bytes_args_from(args, &index)?;
+            let default = match arg.default_value() {
+                Some(default) if !drops_default => Some(filters::render_literal(
+                    default,
+                    *arg,
+                    self.ci,
+                    self.config,
+                )?),
+                _ => None,
+            };
+            parameters.push(ApiParameter {
+                name: filters::var_name(arg.name())?,
+                type_: if bytes_as_type {
+                    bytes_type.to_string()
+                } else {
+                    match arguments_key {
+                        Some(prefix) => self.member_type_name(*arg, prefix, arg.name()),
+                        None => self.type_name(*arg)?,
+                    }
+                },
+                default,
+            });
+        }
+        if token != Token::None {
+            parameters.push(ApiParameter {
+                name: "cancellationToken".to_string(),
+                type_: "CancellationToken".to_string(),
+                default: (token == Token::Optional).then(|| "default".to_string()),
+            });
+        }
+        Ok(parameters)
+    }
+
+    // See the `return_type` macro.
+    fn return_type(&self, return_type: Option<&Type>, is_async: bool) -> Result<String> {
+        if !is_async {
+            return self.blocking_return_type(return_type);
+        }
+        Ok(match return_type {
+            Some(return_type) => format!(
+                "{}<{}>",
+                self.config.async_type_name(),
+                self.type_name(return_type)?
+            ),
+            None => self.config.async_type_name().to_string(),
+        })
+    }
+
+    // See the `blocking_return_type` macro.
+    fn blocking_return_type(&self, return_type: Option<&Type>) -> Result<String> {
+        match return_type {
+            Some(return_type) => self.type_name(return_type),
+            None => Ok("void".to_string()),
+        }
+    }
+
+    // Functions and methods, with the `*Blocking` twins of async functions and the span
+    // overloads of functions taking `bytes`.
+    fn callable(
+        &self,
+        name: String,
+        args: &[&Argument],
+        arguments_key: Option<String>,
+        return_type: Option<&Type>,
+        is_async: bool,
+        is_static: bool,
+    ) -> Result<Vec<ApiMember>> {
+        let async_name = filters::async_name(&name, &is_async, self.config)?;
+        let mut members = vec![ApiMember::new(MemberKind::Method, async_name.clone())
+            .returns(self.return_type(return_type, is_async)?)
+            .parameters(self.parameters(args, arguments_key.as_deref(), Token::of(is_async), "")?)
+            .is_static(is_static)];
+        if is_async && self.config.async_blocking() {
+            members.push(
+                ApiMember::new(MemberKind::Method, format!("{name}Blocking"))
+                    .returns(self.blocking_return_type(return_type)?)
+                    .parameters(self.parameters(
+                        args,
+                        arguments_key.as_deref(),
+                        Token::of(is_async),
+                        "",
+                    )?)
+                    .is_static(is_static),
+            );
+        }
+        if filters::has_bytes_args(args)? {
+            let bytes_type = if is_async {
+                "ReadOnlyMemory<byte>"
+            } else {
+                "ReadOnlySpan<byte>"
+            };
+            members.push(
+                ApiMember::new(MemberKind::Method, async_name)
+                    .returns(self.return_type(return_type, is_async)?)
+                    .parameters(self.parameters(
+                        args,
+                        arguments_key.as_deref(),
+                        Token::of(is_async),
+                        bytes_type,
+                    )?)
+                    .is_static(is_static),
+            );
+        }
+        Ok(members)
+    }
+
+    fn constructor(&self, cons: &Constructor, impl_name: &str) -> Result<Vec<ApiMember>> {
+        if cons.is_primary_constructor() && !cons.is_async() {
+            return Ok(vec![ApiMember::new(
+                MemberKind::Constructor,
+                impl_name.to_string(),
+            )
+            .parameters(self.parameters(
+                &cons.arguments(),
+                None,
+                Token::None,
+                "",
+            )?)]);
+        }
+        let name = if cons.is_primary_constructor() {
+            format!("{impl_name}Async")
+        } else {
+            filters::method_name(cons.name(), impl_name, self.config)?
+        };
+        let mut members = vec![ApiMember::new(
+            MemberKind::Method,
+            filters::async_name(&name, &cons.is_async(), self.config)?,
+        )
+        .returns(if cons.is_async() {
+            format!("{}<{impl_name}>", self.config.async_type_name())
+        } else {
+            impl_name.to_string()
+        })
+        .parameters(self.parameters(&cons.arguments(), None, Token::of(cons.is_async()), "")?)
+        .is_static(true)];
+        if cons.is_async() && self.config.async_blocking() {
+            let blocking_name = if cons.is_primary_constructor() {
+                format!("{impl_name}Blocking")
+            } else {
+                format!("{name}Blocking")
+            };
+            members.push(
+                ApiMember::new(MemberKind::Method, blocking_name)
+                    .returns(impl_name.to_string())
+                    .parameters(self.parameters(&cons.arguments(), None, Token::Optional, "")?)
+                    .is_static(true),
+            );
+        }
+        Ok(members)
+    }
+
+    // Accessors exposed as properties, see `object_properties`.
+    fn object_properties(&self, obj: &Object, impl_name: &str) -> Result<Vec<ApiMember>> {
+        let mut members = Vec::new();
+        for property in self.config.object_properties(obj) {
+            if !self
+                .config
+                .is_public_member(obj.name(), property.getter.name())
+            {
+                continue;
+            }
+            members.push(
+                ApiMember::new(
+                    MemberKind::Property,
+                    filters::method_name(&property.name, impl_name, self.config)?,
+                )
+                .returns(self.type_name(property.value_type)?)
+                .settable(property.setter.is_some()),
+            );
+        }
+        Ok(members)
+    }
+
+    fn object(&self, obj: &Object) -> Result<Vec<ApiType>> {
+        let (interface_name, impl_name) = filters::object_names(obj, self.ci, self.config)?;
+
+        let mut interface = ApiType::new(
+            interface_name,
+            TypeKind::Interface,
+            self.config.type_access_modifier(obj.name()),
+        );
+        interface.implementable = obj.has_callback_interface();
+        let properties = self.object_properties(obj, &impl_name)?;
+        interface.members.extend(properties.iter().cloned());
+        for meth in obj.methods() {
+            if !self.config.is_public_member(obj.name(), meth.name())
+                || self.config.is_property_accessor(obj, meth)
+            {
+                continue;
+            }
+            interface.members.push(
+                ApiMember::new(
+                    MemberKind::Method,
+                    filters::async_name(
+                        &filters::method_name(meth.name(), &impl_name, self.config)?,
+                        &meth.is_async(),
+                        self.config,
+                    )?,
+                )
+                .returns(self.return_type(meth.return_type(), meth.is_async())?)
+                .parameters(self.parameters(
+                    &meth.arguments(),
+                    meth.arguments_key().as_deref(),
+                    if obj.has_callback_interface() {
+                        Token::None
+                    } else {
+                        Token::of(meth.is_async())
+                    },
+                    "",
+                )?),
+            );
+        }
+
+        let mut class = ApiType::new(
+            impl_name.clone(),
+            TypeKind::Class,
+            self.config.type_access_modifier(obj.name()),
+        );
+        class.members.push(
+            ApiMember::new(MemberKind::Constructor, impl_name.clone()).parameters(vec![
+                ApiParameter {
+                    name: "pointer".to_string(),
+                    type_: "ulong".to_string(),
+                    default: None,
+                },
+            ]),
+        );
+        for cons in obj.constructors() {
+            if !self.config.is_public_member(obj.name(), cons.name()) {
+                continue;
+            }
+            class.members.extend(self.constructor(cons, &impl_name)?);
+        }
+        for name in ["Destroy", "Dispose"] {
+            class
+                .members
+                .push(ApiMember::new(MemberKind::Method, name.to_string()).returns("void".into()));
+        }
+        class.members.extend(properties);
+        for meth in obj.methods() {
+            if !self.config.is_public_member(obj.name(), meth.name())
+                || self.config.is_property_accessor(obj, meth)
+            {
+                continue;
+            }
+            let mut members = self.callable(
+                filters::method_name(meth.name(), &impl_name, self.config)?,
+                &meth.arguments(),
+                meth.arguments_key(),
+                meth.return_type(),
+                meth.is_async(),
+                false,
+            )?;
+            // Async methods of interfaces implemented in C# don't take a `CancellationToken`,
+            // the class adds an overload requiring one.
+            if obj.has_callback_interface() && meth.is_async() {
+                let with_token = &mut members[0];
+                if let Some(token) = with_token.parameters.last_mut() {
+                    token.default = None;
+                }
+                let mut without_token = with_token.clone();
+                without_token.parameters.pop();
+                members.insert(0, without_token);
+            }
+            class.members.extend(members);
+        }
+        if let Some(enumerable) = self.config.async_enumerable(obj) {
+            class.members.push(
+                ApiMember::new(MemberKind::Method, "GetAsyncEnumerator".to_string())
+                    .returns(format!(
+                        "IAsyncEnumerator<{}>",
+                        self.type_name(enumerable.item_type)?
+                    ))
+                    .parameters(self.parameters(&[], None, Token::Optional, "")?),
+            );
+        }
+        let nullable_self = format!("{impl_name}?");
+        for tm in obj.uniffi_traits() {
+            match tm {
+                UniffiTrait::Display { .. } => class.members.push(to_string_member()),
+                UniffiTrait::Eq { .. } => {
+                    class.members.push(equals_member(&nullable_self));
+                    if !self.config.reference_equality_operators() {
+                        class
+                            .members
+                            .extend(operator_members(&["==", "!="], &nullable_self));
+                    }
+                }
+                UniffiTrait::Hash { .. } => class.members.push(get_hash_code_member()),
+                UniffiTrait::Ord { .. } => {
+                    class.members.push(compare_to_member(&nullable_self));
+                    class
+                        .members
+                        .extend(operator_members(&["<", ">", "<=", ">="], &nullable_self));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(vec![interface, class])
+    }
+
+    // Members shared by records and enums with associated data, see `value_type_methods` and
+    // `value_type_uniffi_traits`.
+    fn value_type_members(
+        &self,
+        methods: Vec<&Method>,
+        uniffi_trait_methods: &UniffiTraitMethods,
+    ) -> Result<Vec<ApiMember>> {
+        let mut members = Vec::new();
+        for meth in methods {
+            if !self
+                .config
+                .is_public_member(meth.object_name(), meth.name())
+            {
+                continue;
+            }
+            members.extend(self.callable(
+                filters::fn_name(meth.name())?,
+                &meth.arguments(),
+                meth.arguments_key(),
+                meth.return_type(),
+                meth.is_async(),
+                false,
+            )?);
+        }
+        if uniffi_trait_methods.display_fmt.is_some() || uniffi_trait_methods.debug_fmt.is_some() {
+            members.push(to_string_member());
+        }
+        if let Some(eq) = &uniffi_trait_methods.eq_eq {
+            let other = format!(
+                "{}?",
+                filters::class_name(eq.object_name(), self.ci, self.config)?
+            );
+            members.push(equals_member(&other));
+            members.push(get_hash_code_member());
+        }
+        if let Some(cmp) = &uniffi_trait_methods.ord_cmp {
+            let other = format!(
+                "{}?",
+                filters::class_name(cmp.object_name(), self.ci, self.config)?
+            );
+            members.push(compare_to_member(&other));
+            members.extend(operator_members(&["<", ">", "<=", ">="], &other));
+        }
+        Ok(members)
+    }
+
+    fn record(&self, rec: &Record) -> Result<ApiType> {
+        let type_name = self.type_name(rec)?;
+        let is_class = self.config.is_class_record(rec);
+        let mut record = ApiType::new(
+            type_name.clone(),
+            if is_class {
+                TypeKind::Class
+            } else if self.config.is_struct_record(rec) {
+                TypeKind::RecordStruct
+            } else {
+                TypeKind::Record
+            },
+            self.config.type_access_modifier(rec.name()),
+        );
+        let (ordered_fields, _) = filters::order_fields(rec.fields())?;
+        let mut parameters = Vec::new();
+        for field in &ordered_fields {
+            let default = match field.default_value() {
+                Some(default) => Some(filters::render_literal(
+                    default,
+                    field,
+                    self.ci,
+                    self.config,
+                )?),
+                None => None,
+            };
+            let field_type = self.member_type_name(field, rec.name(), field.name());
+            parameters.push(ApiParameter {
+                name: filters::property_name(field.name())?,
+                type_: field_type.clone(),
+                default,
+            });
+            record.members.push(
+                ApiMember::new(MemberKind::Property, filters::property_name(field.name())?)
+                    .returns(field_type)
+                    .settable(is_class),
+            );
+        }
+        record
+            .members
+            .push(ApiMember::new(MemberKind::Constructor, type_name).parameters(parameters));
+        record
+            .members
+            .extend(self.value_type_members(rec.methods(), &rec.uniffi_trait_methods())?);
+        Ok(record)
+    }
+
+    fn enum_(&self, e: &Enum) -> Result<Vec<ApiType>> {
+        let type_name = self.type_name(e)?;
+        let access = self.config.type_access_modifier(e.name());
+        let uniffi_trait_methods = e.uniffi_trait_methods();
+
+        if e.is_flat() {
+            let mut enum_ = ApiType::new(type_name.clone(), TypeKind::Enum, access.clone());
+            for variant in e.variants() {
+                enum_.members.push(ApiMember::new(
+                    MemberKind::Variant,
+                    filters::enum_variant(variant.name())?,
+                ));
+            }
+            let mut types = vec![enum_];
+
+            // See `flat_enum_extension_methods` and `flat_enum_uniffi_traits`.
+            let mut extensions = ApiType::new(
+                format!("{type_name}Extensions"),
+                TypeKind::StaticClass,
+                access,
+            );
+            let self_param = ApiParameter {
+                name: "self_".to_string(),
+                type_: type_name,
+                default: None,
+            };
+            for meth in e.methods() {
+                if !self.config.is_public_member(e.name(), meth.name()) {
+                    continue;
+                }
+                for mut member in self.callable(
+                    filters::fn_name(meth.name())?,
+                    &meth.arguments(),
+                    meth.arguments_key(),
+                    meth.return_type(),
+                    meth.is_async(),
+                    true,
+                )? {
+                    member.parameters.insert(0, self_param.clone());
+                    extensions.members.push(member);
+                }
+            }
+            for (fmt, name) in [
+                (&uniffi_trait_methods.debug_fmt, "ToDebugString"),
+                (&uniffi_trait_methods.display_fmt, "ToDisplayString"),
+            ] {
+                if fmt.is_some() {
+                    extensions.members.push(
+                        ApiMember::new(MemberKind::Method, name.to_string())
+                            .returns("string".to_string())
+                            .parameters(vec![self_param.clone()])
+                            .is_static(true),
+                    );
+                }
+            }
+            if !extensions.members.is_empty() {
+                types.push(extensions);
+            }
+            return Ok(types);
+        }
+
+        let mut enum_ = ApiType::new(type_name.clone(), TypeKind::Record, access);
+        let mut types = Vec::new();
+        for variant in e.variants() {
+            let variant_name = filters::class_name(variant.name(), self.ci, self.config)?;
+            let mut parameters = Vec::new();
+            for (index, field) in variant.fields().iter().enumerate() {
+                let field_name =
+                    filters::property_name(&filters::or_pos_var(field.name(), &(index + 1))?)?;
+                let field_name = if field_name == variant_name {
+                    format!("{field_name}Value")
+                } else {
+                    field_name
+                };
+                parameters.push(ApiParameter {
+                    name: field_name,
+                    type_: self.variant_field_type_name(field, &variant_name)?,
+                    default: None,
+                });
+            }
+            let mut record = ApiType::new(
+                format!("{type_name}.{variant_name}"),
+                TypeKind::Record,
+                "public".to_string(),
+            );
+            for parameter in &parameters {
+                record.members.push(
+                    ApiMember::new(MemberKind::Property, parameter.name.clone())
+                        .returns(parameter.type_.clone()),
+                );
+            }
+            record.members.push(
+                ApiMember::new(MemberKind::Constructor, variant_name.clone())
+                    .parameters(parameters),
+            );
+            types.push(record);
+        }
+
+        if self.config.sealed_enums() {
+            let mut match_parameters = Vec::new();
+            let mut switch_parameters = Vec::new();
+            for variant in e.variants() {
+                let variant_name = filters::class_name(variant.name(), self.ci, self.config)?;
+                let name = filters::var_name(variant.name())?;
+                match_parameters.push(ApiParameter {
+                    name: name.clone(),
+                    type_: format!("Func<{variant_name}, TResult>"),
+                    default: None,
+                });
+                switch_parameters.push(ApiParameter {
+                    name,
+                    type_: format!("Action<{variant_name}>"),
+                    default: None,
+                });
+                enum_.members.push(
+                    ApiMember::new(MemberKind::Property, format!("Is{variant_name}"))
+                        .returns("bool".to_string()),
+                );
+                enum_.members.push(
+                    ApiMember::new(MemberKind::Property, format!("As{variant_name}"))
+                        .returns(format!("{variant_name}?")),
+                );
+            }
+            enum_.members.push(
+                ApiMember::new(MemberKind::Method, "Match<TResult>".to_string())
+                    .returns("TResult".to_string())
+                    .parameters(match_parameters),
+            );
+            enum_.members.push(
+                ApiMember::new(MemberKind::Method, "Switch".to_string())
+                    .returns("void".to_string())
+                    .parameters(switch_parameters),
+            );
+        }
+        enum_
+            .members
+            .extend(self.value_type_members(e.methods(), &uniffi_trait_methods)?);
+        types.insert(0, enum_);
+        Ok(types)
+    }
+
+    fn error(&self, e: &Enum) -> Result<Vec<ApiType>> {
+        let type_name = self.type_name(e)?;
+        let mut types = vec![ApiType::new(
+            type_name.clone(),
+            TypeKind::Class,
+            self.config.type_access_modifier(e.name()),
+        )];
+        for variant in e.variants() {
+            let variant_name = filters::error_variant_name(variant, self.config)?;
+            let mut class = ApiType::new(
+                format!("{type_name}.{variant_name}"),
+                TypeKind::Class,
+                "public".to_string(),
+            );
+            let mut parameters = Vec::new();
+            if e.is_flat() {
+                parameters.push(ApiParameter {
+                    name: "message".to_string(),
+                    type_: "string".to_string(),
+                    default: None,
+                });
+            }
+            for (index, field) in variant.fields().iter().enumerate() {
+                let field_name =
+                    filters::var_name(&filters::or_pos_var(field.name(), &(index + 1))?)?;
+                let field_type = self.variant_field_type_name(field, &variant_name)?;
+                class.members.push(
+                    ApiMember::new(MemberKind::Field, field_name.clone())
+                        .returns(field_type.clone()),
+                );
+                parameters.push(ApiParameter {
+                    name: field_name,
+                    type_: field_type,
+                    default: None,
+                });
+            }
+            class
+                .members
+                .push(ApiMember::new(MemberKind::Constructor, variant_name).parameters(parameters));
+            types.push(class);
+        }
+        Ok(types)
+    }
+
+    // See the `enum_parameter_type_name` macro.
+    fn variant_field_type_name(&self, field: &Field, variant_name: &str) -> Result<String> {
+        let type_name = self.type_name(field)?;
+        Ok(if type_name == variant_name {
+            format!("{}.{type_name}", self.config.namespace())
+        } else {
+            type_name
+        })
+    }
+}
+
+fn to_string_member() -> ApiMember {
+    ApiMember::new(MemberKind::Method, "ToString".to_string()).returns("string".to_string())
+}
+
+fn get_hash_code_member() -> ApiMember {
+    ApiMember::new(MemberKind::Method, "GetHashCode".to_string()).returns("int".to_string())
+}
+
+fn equals_member(other: &str) -> ApiMember {
+    ApiMember::new(MemberKind::Method, "Equals".to_string())
+        .returns("bool".to_string())
+        .parameters(vec![ApiParameter {
+            name: "other".to_string(),
+            type_: other.to_string(),
+            default: None,
+        }])
+}
+
+fn compare_to_member(other: &str) -> ApiMember {
+    ApiMember::new(MemberKind::Method, "CompareTo".to_string())
+        .returns("int".to_string())
+        .parameters(vec![ApiParameter {
+            name: "other".to_string(),
+            type_: other.to_string(),
+            default: None,
+        }])
+}
+
+fn operator_members(operators: &[&str], operand: &str) -> Vec<ApiMember> {
+    operators
+        .iter()
+        .map(|op| {
+            ApiMember::new(MemberKind::Operator, format!("operator {op}"))
+                .returns("bool".to_string())
+                .parameters(
+                    ["left", "right"]
+                        .into_iter()
+                        .map(|name| ApiParameter {
+                            name: name.to_string(),
+                            type_: operand.to_string(),
+                            default: None,
+                        })
+                        .collect(),
+                )
+                .is_static(true)
+        })
+        .collect()
+}
+
+// A difference between two API reports. Following the versioning rules of the README, a change is
+// breaking when consumers of the bindings have to change their code to keep it compiling and
+// working.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiChange {
+    pub breaking: bool,
+    pub description: String,
+}
+
+impl ApiChange {
+    fn breaking(description: String) -> Self {
+        Self {
+            breaking: true,
+            description,
+        }
+    }
+
+    fn non_breaking(description: String) -> Self {
+        Self {
+            breaking: false,
+            description,
+        }
+    }
+}
+
+impl fmt::Display for ApiChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = if self.breaking {
+            "breaking"
+        } else {
+            "non-breaking"
+        };
+        write!(f, "{severity}: {}", self.description)
+    }
+}
+
+// Compare two API reports, breaking changes first.
+pub fn diff(old: &ApiReport, new: &ApiReport) -> Vec<ApiChange> {
+    let mut changes = Vec::new();
+    if old.namespace != new.namespace {
+        changes.push(ApiChange::breaking(format!(
+            "namespace changed from `{}` to `{}`",
+            old.namespace, new.namespace
+        )));
+    }
+
+    let old_types = old
+        .types
+        .iter()
+        .map(|t| (t.name.as_str(), t))
+        .collect::<BTreeMap<_, _>>();
+    let new_types = new
+        .types
+        .iter()
+        .map(|t| (t.name.as_str(), t))
+        .collect::<BTreeMap<_, _>>();
+
+    for (name, old_type) in &old_types {
+        match new_types.get(name) {
+            None => changes.push(ApiChange::breaking(format!(
+                "removed {} `{name}`",
+                old_type.kind
+            ))),
+            Some(new_type) => diff_type(old_type, new_type, &mut changes),
+        }
+    }
+    for (name, new_type) in &new_types {
+        if !old_types.contains_key(name) {
+            changes.push(ApiChange::non_breaking(format!(
+                "added {} `{name}`",
+                new_type.kind
+            )));
+        }
+    }
+
+    changes.sort_by(|a, b| b.breaking.cmp(&a.breaking));
+    changes
+}
+
+fn diff_type(old: &ApiType, new: &ApiType, changes: &mut Vec<ApiChange>) {
+    let name = &old.name;
+    if old.kind != new.kind {
+        changes.push(ApiChange::breaking(format!(
+            "`{name}` changed from {} to {}",
+            old.kind, new.kind
+        )));
+    }
+    if old.access != new.access {
+        let description = format!("`{name}` changed from {} to {}", old.access, new.access);
+        changes.push(if old.access == "public" {
+            ApiChange::breaking(description)
+        } else {
+            ApiChange::non_breaking(description)
+        });
+    }
+    if !old.implementable && new.implementable {
+        changes.push(ApiChange::breaking(format!(
+            "`{name}` is now implemented by consumers"
+        )));
+    }
+
+    let old_members = old
+        .members
+        .iter()
+        .map(|m| (m.key(), m))
+        .collect::<BTreeMap<_, _>>();
+    let new_members = new
+        .members
+        .iter()
+        .map(|m| (m.key(), m))
+        .collect::<BTreeMap<_, _>>();
+    let mut extended = BTreeSet::new();
+
+    for (key, old_member) in &old_members {
+        if let Some(new_member) = new_members.get(key) {
+            diff_member(name, old_member, new_member, changes);
+            continue;
+        }
+        let extension = new_members.iter().find(|(new_key, m)| {
+            !old_members.contains_key(*new_key)
+                && !extended.contains(*new_key)
+                && m.extends(old_member)
+        });
+        match extension {
+            Some((new_key, _)) => {
+                extended.insert(new_key.clone());
+                changes.push(ApiChange::non_breaking(format!(
+                    "added parameters with default values to {} `{name}.{}`",
+                    old_member.kind, old_member.name
+                )));
+            }
+            None => changes.push(ApiChange::breaking(format!("removed `{name}` {key}"))),
+        }
+    }
+    for (key, new_member) in &new_members {
+        if old_members.contains_key(key) || extended.contains(key) {
+            continue;
+        }
+        let description = format!("added `{name}` {key}");
+        // Implementations of the interface in consumer code don't compile without the new member.
+        changes.push(
+            if new.implementable && new_member.kind == MemberKind::Method {
+                ApiChange::breaking(description)
+            } else {
+                ApiChange::non_breaking(description)
+            },
+        );
+    }
+}
+
+fn diff_member(type_name: &str, old: &ApiMember, new: &ApiMember, changes: &mut Vec<ApiChange>) {
+    let member = format!("`{type_name}` {}", old.key());
+    if old.returns != new.returns {
+        changes.push(ApiChange::breaking(format!(
+            "{member} changed type from `{}` to `{}`",
+            old.returns.as_deref().unwrap_or_default(),
+            new.returns.as_deref().unwrap_or_default(),
+        )));
+    }
+    if old.settable && !new.settable {
+        changes.push(ApiChange::breaking(format!("{member} removed the setter")));
+    } else if !old.settable && new.settable {
+        changes.push(ApiChange::non_breaking(format!("{member} added a setter")));
+    }
+    if old.is_static != new.is_static {
+        changes.push(ApiChange::breaking(format!(
+            "{member} changed from {} to {}",
+            if old.is_static { "static" } else { "instance" },
+            if new.is_static { "static" } else { "instance" },
+        )));
+    }
+    for (old_param, new_param) in old.parameters.iter().zip(&new.parameters) {
+        // Named arguments stop compiling when parameters are renamed.
+        if old_param.name != new_param.name {
+            changes.push(ApiChange::breaking(format!(
+                "{member} renamed parameter `{}` to `{}`",
+                old_param.name, new_param.name
+            )));
+        }
+        match (&old_param.default, &new_param.default) {
+            (Some(_), None) => changes.push(ApiChange::breaking(format!(
+                "{member} removed the default value of parameter `{}`",
+                new_param.name
+            ))),
+            (old_default, Some(new_default)) if old_default.as_ref() != Some(new_default) => {
+                changes.push(ApiChange::non_breaking(format!(
+                    "{member} changed the default value of parameter `{}` to `{new_default}`",
+                    new_param.name
+                )))
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UDL: &str = r#"
+        namespace example {
+            string greet(string name);
+            [Async] u32 count();
+        };
+
+        dictionary Point {
+            i32 x;
+            i32 y;
+        };
+
+        [Trait, WithForeign]
+        interface Handler {
+            [Async] string handle(string value);
+        };
+
+        callback interface Listener {
+            void on_event(string name);
+        };
+    "#;
+
+    fn report(udl: &str) -> ApiReport {
+        let ci = ComponentInterface::from_webidl(udl, "example").unwrap();
+        let config = Config {
+            namespace: Some("uniffi.example".to_string()),
+            ..Default::default()
+        };
+        generate_api_report(&config, &ci).unwrap()
+    }
+
+    fn api_type<'a>(report: &'a ApiReport, name: &str) -> &'a ApiType {
+        report
+            .types
+            .iter()
+            .find(|t| t.name == name)
+            .unwrap_or_else(|| panic!("no type `{name}` in {report:#?}"))
+    }
+
+    fn member<'a>(api_type: &'a ApiType, key: &str) -> &'a ApiMember {
+        api_type
+            .members
+            .iter()
+            .find(|m| m.key() == key)
+            .unwrap_or_else(|| panic!("no member `{key}` in {api_type:#?}"))
+    }
+
+    fn parameter(name: &str, type_: &str, default: Option<&str>) -> ApiParameter {
+        ApiParameter {
+            name: name.to_string(),
+            type_: type_.to_string(),
+            default: default.map(str::to_string),
+        }
+    }
+
+    fn method(name: &str, returns: &str, parameters: Vec<ApiParameter>) -> ApiMember {
+        ApiMember::new(MemberKind::Method, name.to_string())
+            .returns(returns.to_string())
+            .parameters(parameters)
+    }
+
+    fn single_type(api_type: ApiType) -> ApiReport {
+        ApiReport {
+            namespace: "uniffi.example".to_string(),
+            types: vec![api_type],
+        }
+    }
+
+    fn class(members: Vec<ApiMember>) -> ApiType {
+        let mut class = ApiType::new("Greeter".to_string(), TypeKind::Class, "public".into());
+        class.members = members;
+        class
+    }
+
+    #[test]
+    fn reports_functions_and_records() {
+        let report = report(UDL);
+        assert_eq!(report.namespace, "uniffi.example");
+
+        let methods = api_type(&report, "ExampleMethods");
+        assert_eq!(methods.kind, TypeKind::StaticClass);
+        let greet = member(methods, "method Greet(string)");
+        assert_eq!(greet.returns.as_deref(), Some("string"));
+        assert!(greet.is_static);
+
+        let point = api_type(&report, "Point");
+        assert_eq!(point.kind, TypeKind::Record);
+        let constructor = member(point, "constructor Point(int, int)");
+        assert_eq!(constructor.parameters[0].name, "X");
+        assert_eq!(member(point, "property Y").returns.as_deref(), Some("int"));
+    }
+
+    #[test]
+    fn async_methods_calling_into_rust_take_optional_token() {
+        let report = report(UDL);
+        let count = member(
+            api_type(&report, "ExampleMethods"),
+            "method Count(CancellationToken)",
+        );
+        assert_eq!(count.returns.as_deref(), Some("Task<uint>"));
+        assert_eq!(
+            count.parameters,
+            vec![parameter(
+                "cancellationToken",
+                "CancellationToken",
+                Some("default")
+            )]
+        );
+    }
+
+    #[test]
+    fn interfaces_implemented_in_csharp_do_not_take_token() {
+        let report = report(UDL);
+
+        let handler = api_type(&report, "Handler");
+        assert!(handler.implementable);
+        assert_eq!(handler.members.len(), 1);
+        let handle = member(handler, "method Handle(string)");
+        assert_eq!(handle.returns.as_deref(), Some("Task<string>"));
+
+        let handler_impl = api_type(&report, "HandlerImpl");
+        assert_eq!(
+            member(handler_impl, "method Handle(string)").parameters,
+            vec![parameter("@value", "string", None)]
+        );
+        let with_token = member(handler_impl, "method Handle(string, CancellationToken)");
+        assert_eq!(with_token.returns.as_deref(), Some("Task<string>"));
+        assert_eq!(with_token.parameters[1].default, None);
+
+        let listener = api_type(&report, "Listener");
+        assert!(listener.implementable);
+        assert_eq!(
+            member(listener, "method OnEvent(string)")
+                .returns
+                .as_deref(),
+            Some("void")
+        );
+    }
+
+    #[test]
+    fn identical_reports_have_no_changes() {
+        assert_eq!(diff(&report(UDL), &report(UDL)), Vec::new());
+    }
+
+    #[test]
+    fn diffs_generated_reports() {
+        let new_udl = UDL
+            .replace(
+                "string greet(string name);",
+                r#"string greet(string name, optional string greeting = "Hello");"#,
+            )
+            .replace("[Async] u32 count();", "")
+            .replace("i32 y;", "i32 y;\n            i32 z = 0;");
+        let changes = diff(&report(UDL), &report(&new_udl));
+        let descriptions = changes.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            descriptions,
+            vec![
+                "breaking: removed `ExampleMethods` method Count(CancellationToken)",
+                "non-breaking: added parameters with default values to method `ExampleMethods.Greet`",
+                "non-breaking: added parameters with default values to constructor `Point.Point`",
+                "non-breaking: added `Point` property Z",
+            ]
+        );
+    }
+
+    #[test]
+    fn removing_members_is_breaking() {
+        let old = single_type(class(vec![
+            method("Greet", "string", vec![]),
+            method("Wave", "void", vec![]),
+        ]));
+        let new = single_type(class(vec![method("Greet", "string", vec![])]));
+        assert_eq!(
+            diff(&old, &new),
+            vec![ApiChange::breaking(
+                "removed `Greeter` method Wave()".to_string()
+            )]
+        );
+        assert_eq!(
+            diff(&new, &old),
+            vec![ApiChange::non_breaking(
+                "added `Greeter` method Wave()".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn adding_members_to_implementable_interfaces_is_breaking() {
+        let interface = |members| {
+            let mut interface = ApiType::new(
+                "Listener".to_string(),
+                TypeKind::Interface,
+                "public".to_string(),
+            );
+            interface.implementable = true;
+            interface.members = members;
+            single_type(interface)
+        };
+        let old = interface(vec![method("OnEvent", "void", vec![])]);
+        let new = interface(vec![
+            method("OnEvent", "void", vec![]),
+            method("OnError", "void", vec![]),
+        ]);
+        assert_eq!(
+            diff(&old, &new),
+            vec![ApiChange::breaking(
+                "added `Listener` method OnError()".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn classifies_parameter_changes() {
+        let greet = |parameters| single_type(class(vec![method("Greet", "string", parameters)]));
+        let old = greet(vec![parameter("name", "string", Some("\"you\""))]);
+
+        // Appending parameters with default values keeps calls compiling.
+        let extended = greet(vec![
+            parameter("name", "string", Some("\"you\"")),
+            parameter("loud", "bool", Some("false")),
+        ]);
+        assert_eq!(
+            diff(&old, &extended),
+            vec![ApiChange::non_breaking(
+                "added parameters with default values to method `Greeter.Greet`".to_string()
+            )]
+        );
+
+        // Appending a required parameter doesn't.
+        let required = greet(vec![
+            parameter("name", "string", Some("\"you\"")),
+            parameter("loud", "bool", None),
+        ]);
+        assert_eq!(
+            diff(&old, &required),
+            vec![
+                ApiChange::breaking("removed `Greeter` method Greet(string)".to_string()),
+                ApiChange::non_breaking("added `Greeter` method Greet(string, bool)".to_string()),
+            ]
+        );
+
+        let renamed = greet(vec![parameter("who", "string", Some("\"you\""))]);
+        assert_eq!(
+            diff(&old, &renamed),
+            vec![ApiChange::breaking(
+                "`Greeter` method Greet(string) renamed parameter `name` to `who`".to_string()
+            )]
+        );
+
+        let without_default = greet(vec![parameter("name", "string", None)]);
+        assert_eq!(
+            diff(&old, &without_default),
+            vec![ApiChange::breaking(
+                "`Greeter` method Greet(string) removed the default value of parameter `name`"
+                    .to_string()
+            )]
+        );
+        assert_eq!(
+            diff(&without_default, &old),
+            vec![ApiChange::non_breaking(
+                "`Greeter` method Greet(string) changed the default value of parameter `name` to `\"you\"`"
+                    .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn classifies_type_changes() {
+        let old = single_type(class(vec![]));
+
+        let mut internal = class(vec![]);
+        internal.access = "internal".to_string();
+        assert_eq!(
+            diff(&old, &single_type(internal.clone())),
+            vec![ApiChange::breaking(
+                "`Greeter` changed from public to internal".to_string()
+            )]
+        );
+        assert_eq!(
+            diff(&single_type(internal), &old),
+            vec![ApiChange::non_breaking(
+                "`Greeter` changed from internal to public".to_string()
+            )]
+        );
+
+        let mut record = class(vec![]);
+        record.kind = TypeKind::Record;
+        assert_eq!(
+            diff(&old, &single_type(record)),
+            vec![ApiChange::breaking(
+                "`Greeter` changed from class to record".to_string()
+            )]
+        );
+
+        let renamed = ApiReport {
+            namespace: "uniffi.greeter".to_string(),
+            types: Vec::new(),
+        };
+        assert_eq!(
+            diff(&old, &renamed),
+            vec![
+                ApiChange::breaking(
+                    "namespace changed from `uniffi.example` to `uniffi.greeter`".to_string()
+                ),
+                ApiChange::breaking("removed class `Greeter`".to_string()),
+            ]
+        );
+    }
+}
@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use super::CodeType;
+use super::{CodeType, Config};
 use uniffi_bindgen::{interface::Literal, ComponentInterface};
 
 #[derive(Debug)]
@@ -17,15 +17,15 @@ impl CallbackInterfaceCodeType {
 }
 
 impl CodeType for CallbackInterfaceCodeType {
-    fn type_label(&self, ci: &ComponentInterface) -> String {
-        super::CsCodeOracle.class_name(&self.id, ci)
+    fn type_label(&self, ci: &ComponentInterface, config: &Config) -> String {
+        super::CsCodeOracle.class_name(&self.id, ci, config)
     }
 
     fn canonical_name(&self) -> String {
         format!("Type{}", self.id)
     }
 
-    fn literal(&self, _literal: &Literal, _ci: &ComponentInterface) -> String {
+    fn literal(&self, _literal: &Literal, _ci: &ComponentInterface, _config: &Config) -> String {
         unreachable!();
     }
 
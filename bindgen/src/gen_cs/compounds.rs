@@ -2,21 +2,25 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use super::CodeType;
-use paste::paste;
+use super::{CodeType, Config};
 use uniffi_bindgen::{
     interface::{DefaultValue, Literal, Type},
     ComponentInterface,
 };
 
-fn render_literal(literal: &Literal, inner: &Type, ci: &ComponentInterface) -> String {
+fn render_literal(
+    literal: &Literal,
+    inner: &Type,
+    ci: &ComponentInterface,
+    config: &Config,
+) -> String {
     match literal {
         Literal::None => "null".into(),
         Literal::Some {
             inner: default_meta,
         } => match default_meta.as_ref() {
-            DefaultValue::Default => super::CsCodeOracle.find(inner).default_value(ci),
-            DefaultValue::Literal(lit) => super::CsCodeOracle.find(inner).literal(lit, ci),
+            DefaultValue::Default => super::CsCodeOracle.find(inner).default_value(ci, config),
+            DefaultValue::Literal(lit) => super::CsCodeOracle.find(inner).literal(lit, ci, config),
         },
 
         // details/1-empty-list-as-default-method-parameter.md
@@ -24,50 +28,107 @@ fn render_literal(literal: &Literal, inner: &Type, ci: &ComponentInterface) -> S
         Literal::EmptyMap => "null".into(),
 
         // For optionals
-        _ => super::CsCodeOracle.find(inner).literal(literal, ci),
+        _ => super::CsCodeOracle.find(inner).literal(literal, ci, config),
     }
 }
 
-macro_rules! impl_code_type_for_compound {
-     ($T:ty, $type_label_pattern:literal, $canonical_name_pattern: literal, $default_pattern:literal) => {
-        paste! {
-            #[derive(Debug)]
-            pub struct $T {
-                inner: Type,
-            }
-
-            impl $T {
-                pub fn new(inner: Type) -> Self {
-                    Self { inner }
-                }
-                fn inner(&self) -> &Type {
-                    &self.inner
-                }
-            }
-
-            impl CodeType for $T  {
-                fn type_label(&self, ci: &ComponentInterface) -> String {
-                    format!($type_label_pattern, super::CsCodeOracle.find(self.inner()).type_label(ci))
-                }
-
-                fn canonical_name(&self) -> String {
-                    format!($canonical_name_pattern, super::CsCodeOracle.find(self.inner()).canonical_name())
-                }
-
-                fn literal(&self, literal: &Literal, ci: &ComponentInterface) -> String {
-                    render_literal(literal, self.inner(), ci)
-                }
-
-                fn default_value(&self, _ci: &ComponentInterface) -> String {
-                    $default_pattern.into()
-                }
-            }
+#[derive(Debug)]
+pub struct OptionalCodeType {
+    inner: Type,
+}
+
+impl OptionalCodeType {
+    pub fn new(inner: Type) -> Self {
+        Self { inner }
+    }
+
+    fn inner(&self) -> &Type {
+        &self.inner
+    }
+
+    /// `T??` is the same type as `T?` in C#, so an optional of an optional is a
+    /// `UniffiOptional<T?>` instead, to tell `None` from `Some(None)`.
+    fn is_nested(&self) -> bool {
+        matches!(self.inner, Type::Optional { .. })
+    }
+}
+
+impl CodeType for OptionalCodeType {
+    fn type_label(&self, ci: &ComponentInterface, config: &Config) -> String {
+        let inner = super::CsCodeOracle
+            .find(self.inner())
+            .type_label(ci, config);
+        if self.is_nested() {
+            format!("UniffiOptional<{inner}>")
+        } else {
+            format!("{inner}?")
+        }
+    }
+
+    fn canonical_name(&self) -> String {
+        format!(
+            "Optional{}",
+            super::CsCodeOracle.find(self.inner()).canonical_name()
+        )
+    }
+
+    fn literal(&self, literal: &Literal, ci: &ComponentInterface, config: &Config) -> String {
+        match literal {
+            // Nested optionals can't default to `Some`, see `Config::check_optional_defaults`.
+            Literal::None if self.is_nested() => "default".into(),
+            _ => render_literal(literal, self.inner(), ci, config),
         }
     }
- }
 
-impl_code_type_for_compound!(OptionalCodeType, "{}?", "Optional{}", "null");
-impl_code_type_for_compound!(SequenceCodeType, "{}[]", "Sequence{}", "null");
+    fn default_value(&self, _ci: &ComponentInterface, _config: &Config) -> String {
+        if self.is_nested() {
+            "default".into()
+        } else {
+            "null".into()
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SequenceCodeType {
+    inner: Type,
+}
+
+impl SequenceCodeType {
+    pub fn new(inner: Type) -> Self {
+        Self { inner }
+    }
+
+    fn inner(&self) -> &Type {
+        &self.inner
+    }
+}
+
+impl CodeType for SequenceCodeType {
+    fn type_label(&self, ci: &ComponentInterface, config: &Config) -> String {
+        config.collections.sequence.type_label(
+            &super::CsCodeOracle
+                .find(self.inner())
+                .type_label(ci, config),
+        )
+    }
+
+    fn canonical_name(&self) -> String {
+        format!(
+            "Sequence{}",
+            super::CsCodeOracle.find(self.inner()).canonical_name()
+        )
+    }
+
+    // `ImmutableArray<T>` sequences can't have default values, see `Config::check_collections`.
+    fn literal(&self, literal: &Literal, ci: &ComponentInterface, config: &Config) -> String {
+        render_literal(literal, self.inner(), ci, config)
+    }
+
+    fn default_value(&self, _ci: &ComponentInterface, _config: &Config) -> String {
+        "null".into()
+    }
+}
 
 #[derive(Debug)]
 pub struct MapCodeType {
@@ -90,11 +151,12 @@ impl MapCodeType {
 }
 
 impl CodeType for MapCodeType {
-    fn type_label(&self, ci: &ComponentInterface) -> String {
-        format!(
-            "Dictionary<{}, {}>",
-            super::CsCodeOracle.find(self.key()).type_label(ci),
-            super::CsCodeOracle.find(self.value()).type_label(ci),
+    fn type_label(&self, ci: &ComponentInterface, config: &Config) -> String {
+        config.collections.map.type_label(
+            &super::CsCodeOracle.find(self.key()).type_label(ci, config),
+            &super::CsCodeOracle
+                .find(self.value())
+                .type_label(ci, config),
         )
     }
 
@@ -106,11 +168,11 @@ impl CodeType for MapCodeType {
         )
     }
 
-    fn literal(&self, literal: &Literal, ci: &ComponentInterface) -> String {
-        render_literal(literal, &self.value, ci)
+    fn literal(&self, literal: &Literal, ci: &ComponentInterface, config: &Config) -> String {
+        render_literal(literal, &self.value, ci, config)
     }
 
-    fn default_value(&self, _ci: &ComponentInterface) -> String {
+    fn default_value(&self, _ci: &ComponentInterface, _config: &Config) -> String {
         "null".into()
     }
 }
@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use super::CodeType;
+use super::{CodeType, Config};
 use uniffi_bindgen::{interface::Literal, ComponentInterface};
 
 #[derive(Debug)]
@@ -18,7 +18,7 @@ impl CustomCodeType {
 }
 
 impl CodeType for CustomCodeType {
-    fn type_label(&self, _ci: &ComponentInterface) -> String {
+    fn type_label(&self, _ci: &ComponentInterface, _config: &Config) -> String {
         self.name.clone()
     }
 
@@ -26,11 +26,11 @@ impl CodeType for CustomCodeType {
         format!("Type{}", self.name)
     }
 
-    fn literal(&self, _literal: &Literal, _ci: &ComponentInterface) -> String {
+    fn literal(&self, _literal: &Literal, _ci: &ComponentInterface, _config: &Config) -> String {
         unreachable!("Can't have a literal of a custom type");
     }
 
-    fn default_value(&self, ci: &ComponentInterface) -> String {
-        self.builtin.default_value(ci)
+    fn default_value(&self, ci: &ComponentInterface, config: &Config) -> String {
+        self.builtin.default_value(ci, config)
     }
 }
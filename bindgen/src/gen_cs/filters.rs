@@ -7,13 +7,53 @@ fn oracle() -> &'static CsCodeOracle {
 pub(super) fn type_name(
     as_ct: &impl AsCodeType,
     ci: &ComponentInterface,
+    config: &Config,
 ) -> Result<String, askama::Error> {
-    Ok(as_ct.as_codetype().type_label(ci))
+    Ok(as_ct.as_codetype().type_label(ci, config))
+}
+
+/// C# type of a record field, see `collections.overrides`
+pub(super) fn field_type_name(
+    field: &Field,
+    record_name: &str,
+    ci: &ComponentInterface,
+    config: &Config,
+) -> Result<String, askama::Error> {
+    let key = format!("{record_name}.{}", field.name());
+    Ok(oracle().member_type_label(&field.as_type(), &key, ci, config))
+}
+
+/// C# type of an argument, see `collections.overrides`
+pub(super) fn argument_type_name(
+    arg: &Argument,
+    callable: &impl ArgumentsKey,
+    ci: &ComponentInterface,
+    config: &Config,
+) -> Result<String, askama::Error> {
+    Ok(match callable.arguments_key() {
+        Some(prefix) => {
+            let key = format!("{prefix}.{}", arg.name());
+            oracle().member_type_label(&arg.as_type(), &key, ci, config)
+        }
+        None => type_name(arg, ci, config)?,
+    })
+}
+
+/// Read a record field from `stream`, converted to the collection type configured for it
+pub(super) fn read_field(
+    field: &Field,
+    record_name: &str,
+    config: &Config,
+) -> Result<String, askama::Error> {
+    let key = format!("{record_name}.{}", field.name());
+    let value = format!("{}(stream)", read_fn(field)?);
+    Ok(oracle().member_lift(&field.as_type(), &key, &value, config))
 }
 
 pub(super) fn type_name_custom(
     typ: &Type,
     ci: &ComponentInterface,
+    config: &Config,
 ) -> Result<String, askama::Error> {
     // Lowercasing numeric types introduces a problem. In C# custom types are
     // implemented with `using` directive, and the `using` directive expects
@@ -35,7 +75,7 @@ pub(super) fn type_name_custom(
         Type::Float32 => Ok("Single".to_string()),
         Type::Float64 => Ok("Double".to_string()),
         Type::String => Ok("String".to_string()),
-        _ => type_name(typ, ci),
+        _ => type_name(typ, ci, config),
     }
 }
 
@@ -90,10 +130,11 @@ pub(super) fn render_literal(
     default: &DefaultValue,
     as_ct: &impl AsCodeType,
     ci: &ComponentInterface,
+    config: &Config,
 ) -> Result<String, askama::Error> {
     match default {
-        DefaultValue::Default => Ok(as_ct.as_codetype().default_value(ci)),
-        DefaultValue::Literal(literal) => Ok(as_ct.as_codetype().literal(literal, ci)),
+        DefaultValue::Default => Ok(as_ct.as_codetype().default_value(ci, config)),
+        DefaultValue::Literal(literal) => Ok(as_ct.as_codetype().literal(literal, ci, config)),
     }
 }
 
@@ -110,9 +151,38 @@ pub(super) fn arg_type_name(type_: &FfiType) -> Result<String, askama::Error> {
     Ok(oracle().ffi_type_label(type_, true))
 }
 
+/// Whether a type is `bytes`, which can also be passed as a span on .NET 8+.
+pub(super) fn is_bytes(as_type: &impl AsType) -> Result<bool, askama::Error> {
+    Ok(matches!(as_type.as_type(), Type::Bytes))
+}
+
+/// Whether the type is an optional, which an outer optional wraps in `UniffiOptional<T>`.
+pub(super) fn is_optional(as_type: &impl AsType) -> Result<bool, askama::Error> {
+    Ok(matches!(as_type.as_type(), Type::Optional { .. }))
+}
+
+/// Whether any of the arguments is `bytes`, see `is_bytes`.
+pub(super) fn has_bytes_args(args: &[&Argument]) -> Result<bool, askama::Error> {
+    Ok(args.iter().any(|arg| matches!(arg.as_type(), Type::Bytes)))
+}
+
+/// Whether any of the arguments from `index` on is `bytes`. Overloads taking `bytes` as a span
+/// drop the default values of all arguments up to the last `bytes` one, because optional
+/// parameters must come last.
+pub(super) fn has_bytes_args_from(
+    args: &[&Argument],
+    index: &usize,
+) -> Result<bool, askama::Error> {
+    has_bytes_args(&args[*index..])
+}
+
 /// Get the idiomatic C# rendering of a class name (for enums, records, errors, etc).
-pub(super) fn class_name(nm: &str, ci: &ComponentInterface) -> Result<String, askama::Error> {
-    Ok(oracle().class_name(nm, ci))
+pub(super) fn class_name(
+    nm: &str,
+    ci: &ComponentInterface,
+    config: &Config,
+) -> Result<String, askama::Error> {
+    Ok(oracle().class_name(nm, ci, config))
 }
 
 /// Get the idiomatic C# rendering of a function name.
@@ -120,12 +190,26 @@ pub(super) fn fn_name(nm: &str) -> Result<String, askama::Error> {
     Ok(oracle().fn_name(nm))
 }
 
-/// Rename a method to avoid CS0542 when the method name matches the enclosing class name.
+/// Append `Async` to the name of async functions, methods and constructors, see `async_suffix`.
+pub(super) fn async_name(
+    nm: &str,
+    is_async: &bool,
+    config: &Config,
+) -> Result<String, askama::Error> {
+    Ok(oracle().async_name(nm, *is_async, config))
+}
+
+/// Rename a method to avoid CS0542 when the method name matches the enclosing class name, see
+/// `class_method_suffix`.
 /// Applied to both interface and class method declarations using the impl class name.
-pub(super) fn method_name(nm: &str, class_name: &str) -> Result<String, askama::Error> {
+pub(super) fn method_name(
+    nm: &str,
+    class_name: &str,
+    config: &Config,
+) -> Result<String, askama::Error> {
     let method_name = oracle().fn_name(nm);
     if method_name == class_name {
-        Ok(format!("{}ClassMethod", method_name))
+        Ok(oracle().class_method_name(&method_name, config))
     } else {
         Ok(method_name)
     }
@@ -150,6 +234,11 @@ pub(super) fn property_name(nm: impl AsRef<str>) -> Result<String, askama::Error
     Ok(oracle().property_name(nm.as_ref()))
 }
 
+/// Get the C# rendering of the private field backing a property.
+pub(super) fn backing_field_name(nm: &str) -> Result<String, askama::Error> {
+    Ok(oracle().backing_field_name(nm))
+}
+
 /// Get the idiomatic C# rendering of an individual enum variant.
 pub(super) fn enum_variant(nm: &str) -> Result<String, askama::Error> {
     Ok(oracle().enum_variant_name(nm))
@@ -157,9 +246,9 @@ pub(super) fn enum_variant(nm: &str) -> Result<String, askama::Error> {
 
 /// Get the idiomatic C# rendering of an exception name, replacing
 /// `Error` with `Exception`.
-pub fn error_variant_name(v: &Variant) -> Result<String, askama::Error> {
+pub fn error_variant_name(v: &Variant, config: &Config) -> Result<String, askama::Error> {
     let name = v.name().to_string().to_upper_camel_case();
-    Ok(oracle().convert_error_suffix(&name))
+    Ok(oracle().convert_error_suffix(&name, config))
 }
 
 /// Get the idiomatic C# rendering of an FFI callback function name
@@ -193,8 +282,9 @@ pub(super) fn ffi_struct_name(nm: &str) -> Result<String, askama::Error> {
 pub(super) fn object_names(
     obj: &Object,
     ci: &ComponentInterface,
+    config: &Config,
 ) -> Result<(String, String), askama::Error> {
-    Ok(oracle().object_names(obj, ci))
+    Ok(oracle().object_names(obj, ci, config))
 }
 
 /// Get the idiomatic C# rendering of docstring
@@ -217,6 +307,18 @@ pub(super) fn order_fields(fields: &[Field]) -> Result<(Vec<Field>, bool), askam
     Ok((fields, is_reordered))
 }
 
+/// Whether any field is a sequence, map or bytes, which C# compares by reference
+pub(super) fn has_compound_fields(fields: &[Field]) -> Result<bool, askama::Error> {
+    fn is_compound(type_: &Type) -> bool {
+        match type_ {
+            Type::Sequence { .. } | Type::Map { .. } | Type::Bytes => true,
+            Type::Optional { inner_type } => is_compound(inner_type),
+            _ => false,
+        }
+    }
+    Ok(fields.iter().any(|field| is_compound(&field.as_type())))
+}
+
 /// If the name is empty create one based on position of the variable
 pub(super) fn or_pos_var(nm: &str, pos: &usize) -> Result<String, askama::Error> {
     if nm.is_empty() {
@@ -257,3 +359,55 @@ pub(super) fn variant_discr_type_name(typ: &Type) -> Result<String, askama::Erro
         _ => Err(askama::Error::Fmt),
     }
 }
+
+/// C# statements disposing the object references held by `value`, generated from the static type
+/// instead of inspecting the value with reflection at runtime like `FFIObjectUtil`. Empty when the
+/// type holds no object references.
+pub(super) fn dispose_value(
+    as_type: &impl AsType,
+    value: &str,
+    ci: &ComponentInterface,
+) -> Result<String, askama::Error> {
+    fn dispose(type_: &Type, value: &str, ci: &ComponentInterface, depth: usize) -> String {
+        if !ci.item_contains_object_references(type_) {
+            return String::new();
+        }
+        match type_ {
+            Type::Object { .. } | Type::Record { .. } | Type::Enum { .. } => {
+                format!("{value}?.Dispose();")
+            }
+            Type::Optional { inner_type } if matches!(**inner_type, Type::Optional { .. }) => {
+                format!(
+                    "if ({value}.HasValue) {{ {} }}",
+                    dispose(inner_type, &format!("{value}.Value"), ci, depth)
+                )
+            }
+            Type::Optional { inner_type } => format!(
+                "if ({value} != null) {{ {} }}",
+                dispose(inner_type, value, ci, depth)
+            ),
+            Type::Sequence { inner_type } => {
+                let item = format!("item{depth}");
+                format!(
+                    "foreach (var {item} in {value}) {{ {} }}",
+                    dispose(inner_type, &item, ci, depth + 1)
+                )
+            }
+            Type::Map {
+                key_type,
+                value_type,
+            } => {
+                let entry = format!("entry{depth}");
+                format!(
+                    "foreach (var {entry} in {value}) {{ {} {} }}",
+                    dispose(key_type, &format!("{entry}.Key"), ci, depth + 1),
+                    dispose(value_type, &format!("{entry}.Value"), ci, depth + 1)
+                )
+            }
+            // The C# type of custom types is configurable, so check for IDisposable without
+            // reflection instead.
+            _ => format!("({value} as IDisposable)?.Dispose();"),
+        }
+    }
+    Ok(dispose(&as_type.as_type(), value, ci, 0))
+}
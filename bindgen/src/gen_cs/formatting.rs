@@ -1,7 +1,7 @@
-use camino::Utf8PathBuf;
+use camino::Utf8Path;
 use std::process::Command;
 
-pub fn format(path: &Utf8PathBuf) -> Result<(), anyhow::Error> {
+pub fn format(path: &Utf8Path) -> Result<(), anyhow::Error> {
     let csharpier = Command::new("csharpier")
         .arg("format")
         .arg("--include-generated")
@@ -2,47 +2,48 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use super::CodeType;
-use paste::paste;
+use super::{CodeType, Config};
 use uniffi_bindgen::{interface::Literal, ComponentInterface};
 
-macro_rules! impl_code_type_for_miscellany {
-    ($T:ty, $class_name:literal, $canonical_name:literal, $default:literal) => {
-        paste! {
-            #[derive(Debug)]
-            pub struct $T;
-
-            impl CodeType for $T  {
-                fn type_label(&self, _ci: &ComponentInterface) -> String {
-                    $class_name.into()
-                }
-
-                fn canonical_name(&self) -> String {
-                   $canonical_name.into()
-               }
-
-                fn literal(&self, _literal: &Literal, _ci: &ComponentInterface) -> String {
-                    unreachable!()
-                }
-
-                fn default_value(&self, _ci: &ComponentInterface) -> String {
-                    $default.into()
-                }
-            }
-        }
-    };
+// The C# types are configurable, see `time` in `Config`.
+#[derive(Debug)]
+pub struct TimestampCodeType;
+
+impl CodeType for TimestampCodeType {
+    fn type_label(&self, _ci: &ComponentInterface, config: &Config) -> String {
+        config.time.timestamp.type_label().into()
+    }
+
+    fn canonical_name(&self) -> String {
+        "Timestamp".into()
+    }
+
+    fn literal(&self, _literal: &Literal, _ci: &ComponentInterface, _config: &Config) -> String {
+        unreachable!()
+    }
+
+    fn default_value(&self, ci: &ComponentInterface, config: &Config) -> String {
+        format!("default({})", self.type_label(ci, config))
+    }
 }
 
-impl_code_type_for_miscellany!(
-    TimestampCodeType,
-    "DateTime",
-    "Timestamp",
-    "default(DateTime)"
-);
-
-impl_code_type_for_miscellany!(
-    DurationCodeType,
-    "TimeSpan",
-    "Duration",
-    "default(TimeSpan)"
-);
+#[derive(Debug)]
+pub struct DurationCodeType;
+
+impl CodeType for DurationCodeType {
+    fn type_label(&self, _ci: &ComponentInterface, config: &Config) -> String {
+        config.time.duration.type_label().into()
+    }
+
+    fn canonical_name(&self) -> String {
+        "Duration".into()
+    }
+
+    fn literal(&self, _literal: &Literal, _ci: &ComponentInterface, _config: &Config) -> String {
+        unreachable!()
+    }
+
+    fn default_value(&self, ci: &ComponentInterface, config: &Config) -> String {
+        format!("default({})", self.type_label(ci, config))
+    }
+}
@@ -7,7 +7,7 @@ use std::cell::RefCell;
 use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fmt::Debug;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use askama::Template;
 use heck::{ToLowerCamelCase, ToUpperCamelCase};
 use serde::{Deserialize, Serialize};
@@ -15,6 +15,7 @@ use serde::{Deserialize, Serialize};
 use uniffi_bindgen::interface::*;
 use uniffi_bindgen::ComponentInterface;
 
+pub mod api;
 mod callback_interface;
 mod compounds;
 mod custom;
@@ -24,12 +25,13 @@ pub mod formatting;
 mod miscellany;
 mod object;
 mod primitives;
+pub mod project;
 mod record;
 
 trait CodeType: Debug {
     /// The language specific label used to reference this type. This will be used in
     /// method signatures and property declarations.
-    fn type_label(&self, ci: &ComponentInterface) -> String;
+    fn type_label(&self, ci: &ComponentInterface, config: &Config) -> String;
 
     /// A representation of this type label that can be used as part of another
     /// identifier. e.g. `read_foo()`, or `FooInternals`.
@@ -38,12 +40,12 @@ trait CodeType: Debug {
     /// with this type only.
     fn canonical_name(&self) -> String;
 
-    fn literal(&self, _literal: &Literal, ci: &ComponentInterface) -> String {
-        unimplemented!("Unimplemented for {}", self.type_label(ci))
+    fn literal(&self, _literal: &Literal, ci: &ComponentInterface, config: &Config) -> String {
+        unimplemented!("Unimplemented for {}", self.type_label(ci, config))
     }
 
-    fn default_value(&self, ci: &ComponentInterface) -> String {
-        unimplemented!("Unimplemented for {}", self.type_label(ci))
+    fn default_value(&self, ci: &ComponentInterface, config: &Config) -> String {
+        unimplemented!("Unimplemented for {}", self.type_label(ci, config))
     }
 
     /// Name of the FfiConverter
@@ -84,6 +86,335 @@ pub struct Config {
     null_string_to_empty: Option<bool>,
     #[serde(default)]
     omit_checksums: bool,
+    #[serde(default)]
+    split_files: bool,
+    #[serde(default)]
+    shared_runtime: bool,
+    runtime_namespace: Option<String>,
+    #[serde(default)]
+    project: ProjectConfig,
+    #[serde(default)]
+    async_surface: AsyncSurface,
+    #[serde(default)]
+    async_enumerables: HashMap<String, String>,
+    #[serde(default)]
+    aot_compatible: bool,
+    #[serde(default)]
+    sealed_enums: bool,
+    #[serde(default)]
+    reference_equality_operators: bool,
+    #[serde(default)]
+    visibility: HashMap<String, VisibilityConfig>,
+    #[serde(default)]
+    naming: NamingConfig,
+    #[serde(default)]
+    object_properties: ObjectProperties,
+    #[serde(default)]
+    record_style: RecordStyle,
+    #[serde(default)]
+    record_styles: HashMap<String, RecordStyle>,
+    #[serde(default)]
+    primitive_record_structs: bool,
+    #[serde(default)]
+    collections: CollectionsConfig,
+    #[serde(default)]
+    time: TimeConfig,
+}
+
+/// Object method exposed as `IAsyncEnumerable<T>`, see `async_enumerables` in `Config`.
+pub struct AsyncEnumerable<'a> {
+    pub method: &'a Method,
+    pub item_type: &'a Type,
+}
+
+// Shape of the generated API for async functions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AsyncSurface {
+    /// Async functions return `Task` / `Task<T>`.
+    #[default]
+    Task,
+    /// Async functions return `ValueTask` / `ValueTask<T>`.
+    ValueTask,
+    /// Async functions return `Task` / `Task<T>`, and each one gets a synchronous `*Blocking` twin.
+    TaskAndBlocking,
+}
+
+// Shape of the C# types generated for records.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordStyle {
+    /// Immutable positional `record`.
+    #[default]
+    Record,
+    /// Class with mutable properties.
+    Class,
+    /// Class with mutable properties implementing `INotifyPropertyChanged`.
+    Observable,
+    /// Immutable positional `readonly record struct`, lifted without allocating.
+    Struct,
+}
+
+// Object accessors rendered as C# properties.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectProperties {
+    /// Accessors are methods.
+    #[default]
+    None,
+    /// `get_x` and `is_x` accessors are properties `X` and `IsX`.
+    Prefixed,
+    /// All accessors are properties, `get_x` accessors without the prefix.
+    All,
+}
+
+/// Object accessor exposed as a C# property, see `object_properties` in `Config`.
+pub struct ObjectProperty<'a> {
+    // Rust name of the property, without the `get_` prefix.
+    pub name: String,
+    pub value_type: &'a Type,
+    pub getter: &'a Method,
+    // `set_x` method taking a value of the same type.
+    pub setter: Option<&'a Method>,
+}
+
+// C# types of sequences and maps, see `collections` in `Config`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CollectionsConfig {
+    #[serde(default)]
+    sequence: SequenceKind,
+    #[serde(default)]
+    map: MapKind,
+    // Keyed by `Record.field`, `function.arg` or `Type.method.arg`.
+    #[serde(default)]
+    overrides: HashMap<String, CollectionKind>,
+}
+
+impl CollectionsConfig {
+    fn uses_immutable_arrays(&self) -> bool {
+        self.sequence == SequenceKind::ImmutableArray
+            || self
+                .overrides
+                .values()
+                .any(|kind| *kind == CollectionKind::Sequence(SequenceKind::ImmutableArray))
+    }
+
+    fn uses_immutable_collections(&self) -> bool {
+        self.uses_immutable_arrays()
+            || self.map == MapKind::ImmutableDictionary
+            || self
+                .overrides
+                .values()
+                .any(|kind| *kind == CollectionKind::Map(MapKind::ImmutableDictionary))
+    }
+}
+
+// C# type of sequences.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SequenceKind {
+    /// `T[]`.
+    #[default]
+    Array,
+    /// `List<T>`.
+    List,
+    /// `IReadOnlyList<T>`, backed by an array.
+    ReadOnlyList,
+    /// `ImmutableArray<T>`.
+    ImmutableArray,
+}
+
+impl SequenceKind {
+    fn type_label(self, item: &str) -> String {
+        match self {
+            Self::Array => format!("{item}[]"),
+            Self::List => format!("List<{item}>"),
+            Self::ReadOnlyList => format!("IReadOnlyList<{item}>"),
+            Self::ImmutableArray => format!("ImmutableArray<{item}>"),
+        }
+    }
+
+    // C# expression converting `value`, a sequence of kind `from`, to this kind.
+    fn convert(self, from: Self, value: &str) -> String {
+        match self {
+            _ if self == from => value.to_string(),
+            Self::Array => format!("{value}.ToArray()"),
+            Self::List => format!("{value}.ToList()"),
+            Self::ReadOnlyList => value.to_string(),
+            Self::ImmutableArray => format!("ImmutableArray.CreateRange({value})"),
+        }
+    }
+}
+
+// C# type of maps.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MapKind {
+    /// `Dictionary<K, V>`.
+    #[default]
+    Dictionary,
+    /// `IReadOnlyDictionary<K, V>`, backed by a `Dictionary<K, V>`.
+    ReadOnlyDictionary,
+    /// `ImmutableDictionary<K, V>`.
+    ImmutableDictionary,
+}
+
+impl MapKind {
+    fn type_label(self, key: &str, value: &str) -> String {
+        match self {
+            Self::Dictionary => format!("Dictionary<{key}, {value}>"),
+            Self::ReadOnlyDictionary => format!("IReadOnlyDictionary<{key}, {value}>"),
+            Self::ImmutableDictionary => format!("ImmutableDictionary<{key}, {value}>"),
+        }
+    }
+
+    // C# expression converting `value`, a map of kind `from`, to this kind.
+    fn convert(self, from: Self, value: &str) -> String {
+        match self {
+            _ if self == from => value.to_string(),
+            Self::Dictionary => {
+                format!("{value}.ToDictionary(entry => entry.Key, entry => entry.Value)")
+            }
+            Self::ReadOnlyDictionary => value.to_string(),
+            Self::ImmutableDictionary => format!("ImmutableDictionary.CreateRange({value})"),
+        }
+    }
+}
+
+// C# type of a single record field or argument, see `collections.overrides` in `Config`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CollectionKind {
+    Sequence(SequenceKind),
+    Map(MapKind),
+}
+
+// C# types of timestamps and durations, see `time` in `Config`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TimeConfig {
+    #[serde(default)]
+    timestamp: TimestampKind,
+    #[serde(default)]
+    duration: DurationKind,
+    #[serde(default)]
+    overflow: TimeOverflow,
+}
+
+// C# type of timestamps.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampKind {
+    /// `DateTime`, in UTC.
+    #[default]
+    DateTime,
+    /// `DateTimeOffset`, with a zero offset.
+    DateTimeOffset,
+    /// `UniffiTimestamp`, seconds and nanoseconds since the Unix epoch.
+    UniffiTimestamp,
+    /// `NodaTime.Instant`.
+    NodaTime,
+}
+
+impl TimestampKind {
+    fn type_label(self) -> &'static str {
+        match self {
+            Self::DateTime => "DateTime",
+            Self::DateTimeOffset => "DateTimeOffset",
+            Self::UniffiTimestamp => "UniffiTimestamp",
+            Self::NodaTime => "NodaTime.Instant",
+        }
+    }
+}
+
+// C# type of durations.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DurationKind {
+    /// `TimeSpan`.
+    #[default]
+    TimeSpan,
+    /// `UniffiDuration`, seconds and nanoseconds.
+    UniffiDuration,
+    /// `NodaTime.Duration`.
+    NodaTime,
+}
+
+impl DurationKind {
+    fn type_label(self) -> &'static str {
+        match self {
+            Self::TimeSpan => "TimeSpan",
+            Self::UniffiDuration => "UniffiDuration",
+            Self::NodaTime => "NodaTime.Duration",
+        }
+    }
+}
+
+// What converters do with timestamps and durations outside the range of their C# type.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeOverflow {
+    /// Throw `UniffiTimeOverflowException`.
+    #[default]
+    Throw,
+    /// Clamp to the minimum or maximum value of the C# type.
+    Clamp,
+}
+
+// Naming rules applied by `CsCodeOracle`, see `naming` in `Config`. Patterns replace `{}` with the
+// name of the type.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NamingConfig {
+    error_suffix: Option<String>,
+    interface_name: Option<String>,
+    impl_name: Option<String>,
+    class_method_suffix: Option<String>,
+    #[serde(default)]
+    async_suffix: bool,
+}
+
+impl NamingConfig {
+    // Replaces the `Error` suffix of error types and their variants.
+    fn error_suffix(&self) -> &str {
+        self.error_suffix.as_deref().unwrap_or("Exception")
+    }
+
+    // Interface of an object, implemented by the class wrapping the Rust object.
+    fn interface_name(&self, nm: &str) -> String {
+        self.interface_name
+            .as_deref()
+            .unwrap_or("I{}")
+            .replace("{}", nm)
+    }
+
+    // Class wrapping the Rust implementation of a trait interface.
+    fn impl_name(&self, nm: &str) -> String {
+        self.impl_name
+            .as_deref()
+            .unwrap_or("{}Impl")
+            .replace("{}", nm)
+    }
+
+    // Appended to methods named like their class, which C# doesn't allow (CS0542).
+    fn class_method_suffix(&self) -> &str {
+        self.class_method_suffix.as_deref().unwrap_or("ClassMethod")
+    }
+
+    // Appends `Async` to async functions, methods and constructors, following the .NET guidelines.
+    fn async_name(&self, nm: &str) -> String {
+        if self.async_suffix && !nm.ends_with("Async") {
+            format!("{nm}Async")
+        } else {
+            nm.to_string()
+        }
+    }
+}
+
+// Per item overrides of the generated API, see `visibility` in `Config`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct VisibilityConfig {
+    access_modifier: Option<String>,
+    #[serde(default)]
+    exclude: bool,
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -103,6 +434,42 @@ impl CustomTypeConfig {
     }
 }
 
+// config options for the project generated with `--emit-project`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ProjectConfig {
+    package_id: Option<String>,
+    target_frameworks: Option<Vec<String>>,
+    runtime_identifier: Option<String>,
+}
+
+impl ProjectConfig {
+    fn target_frameworks(&self) -> Vec<String> {
+        match self.target_frameworks.as_ref() {
+            Some(value) => value.clone(),
+            None => vec!["netstandard2.0".to_string(), "net8.0".to_string()],
+        }
+    }
+
+    // Runtime identifier of the native library, defaults to the RID of the host
+    // https://learn.microsoft.com/en-us/dotnet/core/rid-catalog
+    pub fn runtime_identifier(&self) -> String {
+        if let Some(value) = self.runtime_identifier.as_ref() {
+            return value.clone();
+        }
+        let os = match std::env::consts::OS {
+            "windows" => "win",
+            "macos" => "osx",
+            os => os,
+        };
+        let arch = match std::env::consts::ARCH {
+            "x86_64" => "x64",
+            "aarch64" => "arm64",
+            arch => arch,
+        };
+        format!("{os}-{arch}")
+    }
+}
+
 impl Config {
     pub fn namespace(&self) -> String {
         self.namespace
@@ -125,6 +492,717 @@ impl Config {
         }
     }
 
+    pub fn methods_class_name(&self, ci: &ComponentInterface) -> String {
+        match self.global_methods_class_name.as_ref() {
+            Some(value) => value.clone(),
+            None => format!("{}Methods", ci.namespace().to_upper_camel_case()),
+        }
+    }
+
+    pub fn split_files(&self) -> bool {
+        self.split_files
+    }
+
+    pub fn shared_runtime(&self) -> bool {
+        self.shared_runtime
+    }
+
+    pub fn aot_compatible(&self) -> bool {
+        self.aot_compatible
+    }
+
+    pub fn sealed_enums(&self) -> bool {
+        self.sealed_enums
+    }
+
+    pub fn reference_equality_operators(&self) -> bool {
+        self.reference_equality_operators
+    }
+
+    pub fn record_style(&self, rec: &Record) -> RecordStyle {
+        if let Some(style) = self.record_styles.get(rec.name()) {
+            return *style;
+        }
+        if self.primitive_record_structs
+            && rec
+                .fields()
+                .iter()
+                .all(|f| is_fixed_size_primitive(&f.as_type()))
+            && struct_record_conflict(rec).is_none()
+        {
+            return RecordStyle::Struct;
+        }
+        self.record_style
+    }
+
+    // Records generated as classes rather than positional `record` types.
+    pub fn is_class_record(&self, rec: &Record) -> bool {
+        matches!(
+            self.record_style(rec),
+            RecordStyle::Class | RecordStyle::Observable
+        )
+    }
+
+    pub fn is_observable_record(&self, rec: &Record) -> bool {
+        self.record_style(rec) == RecordStyle::Observable
+    }
+
+    pub fn is_struct_record(&self, rec: &Record) -> bool {
+        self.record_style(rec) == RecordStyle::Struct
+    }
+
+    // Access modifier of a type, or of the static class holding the extension methods of a flat enum.
+    pub fn type_access_modifier(&self, name: &str) -> String {
+        match self.visibility_of(name).access_modifier.as_ref() {
+            Some(value) => value.clone(),
+            None => self.access_modifier(),
+        }
+    }
+
+    // Access modifier of a top level function.
+    pub fn function_access_modifier(&self, name: &str) -> String {
+        self.visibility_of(name)
+            .access_modifier
+            .clone()
+            .unwrap_or_else(|| "public".to_string())
+    }
+
+    // Access modifier of a constructor or method of a type.
+    pub fn member_access_modifier(&self, type_name: &str, member_name: &str) -> String {
+        self.function_access_modifier(&format!("{type_name}.{member_name}"))
+    }
+
+    // Whether a constructor or method is emitted as a public member. Object interfaces only declare
+    // these methods.
+    pub fn is_public_member(&self, type_name: &str, member_name: &str) -> bool {
+        !self.is_member_excluded(type_name, member_name)
+            && self.member_access_modifier(type_name, member_name) == "public"
+    }
+
+    // Whether a type or top level function is left out of the bindings.
+    pub fn is_excluded(&self, name: &str) -> bool {
+        self.visibility_of(name).exclude
+    }
+
+    pub fn is_member_excluded(&self, type_name: &str, member_name: &str) -> bool {
+        self.is_excluded(&format!("{type_name}.{member_name}"))
+    }
+
+    // Whether the type refers to an excluded type, in which case its converter is left out too.
+    pub fn is_type_excluded(&self, type_: &Type) -> bool {
+        match type_ {
+            Type::Optional { inner_type } | Type::Sequence { inner_type } => {
+                self.is_type_excluded(inner_type)
+            }
+            Type::Map {
+                key_type,
+                value_type,
+            } => self.is_type_excluded(key_type) || self.is_type_excluded(value_type),
+            _ => type_.name().is_some_and(|name| self.is_excluded(name)),
+        }
+    }
+
+    fn visibility_of(&self, key: &str) -> &VisibilityConfig {
+        static DEFAULT: VisibilityConfig = VisibilityConfig {
+            access_modifier: None,
+            exclude: false,
+        };
+        self.visibility.get(key).unwrap_or(&DEFAULT)
+    }
+
+    // `visibility`, `record_styles` and `collections.overrides` are keyed by Rust names like
+    // `rename`, but they are looked up after renaming.
+    pub(crate) fn rename_config_keys(&mut self) {
+        let renamed = |key: &str| {
+            self.rename
+                .get(key)
+                .and_then(|table| table.get("name"))
+                .and_then(|name| name.as_str())
+                .map(str::to_string)
+        };
+        // Renames the type and member of `Type.member` keys, arguments keep their names.
+        let renamed_key = |key: String| {
+            let mut parts = key.splitn(3, '.');
+            let type_name = parts.next().unwrap_or_default();
+            let Some(member_name) = parts.next() else {
+                return renamed(&key).unwrap_or(key);
+            };
+            let mut renamed_key = format!(
+                "{}.{}",
+                renamed(type_name).unwrap_or_else(|| type_name.to_string()),
+                renamed(&format!("{type_name}.{member_name}"))
+                    .unwrap_or_else(|| member_name.to_string())
+            );
+            if let Some(arg_name) = parts.next() {
+                renamed_key = format!("{renamed_key}.{arg_name}");
+            }
+            renamed_key
+        };
+        self.visibility = self
+            .visibility
+            .drain()
+            .map(|(key, value)| (renamed_key(key), value))
+            .collect();
+        self.record_styles = self
+            .record_styles
+            .drain()
+            .map(|(key, value)| (renamed(&key).unwrap_or(key), value))
+            .collect();
+        self.collections.overrides = self
+            .collections
+            .overrides
+            .drain()
+            .map(|(key, value)| (renamed_key(key), value))
+            .collect();
+    }
+
+    fn check_naming(&self) -> Result<()> {
+        // Objects get both an interface and a class, which must have distinct names for each object.
+        for (key, pattern) in [
+            ("interface_name", &self.naming.interface_name),
+            ("impl_name", &self.naming.impl_name),
+        ] {
+            if let Some(pattern) = pattern {
+                if !pattern.contains("{}") || pattern == "{}" {
+                    bail!("naming: `{key}` must contain `{{}}` and other text, got `{pattern}`");
+                }
+            }
+        }
+        if self.naming.class_method_suffix().is_empty() {
+            bail!("naming: `class_method_suffix` must not be empty");
+        }
+        Ok(())
+    }
+
+    // Async members renamed by `async_suffix` must not take the name of another member.
+    fn check_async_names(&self, ci: &ComponentInterface) -> Result<()> {
+        if !self.naming.async_suffix {
+            return Ok(());
+        }
+        let oracle = CsCodeOracle;
+        let check_scope = |scope: &str, members: Vec<(String, String)>| -> Result<()> {
+            let mut names = HashMap::new();
+            for (rust_name, cs_name) in members {
+                if let Some(other) = names.insert(cs_name.clone(), rust_name.clone()) {
+                    bail!(
+                        "naming: with `async_suffix`, `{other}` and `{rust_name}` of `{scope}` are both \
+                         named `{cs_name}`, rename one of them"
+                    );
+                }
+            }
+            Ok(())
+        };
+        let method_names = |owner: &str, class_name: &str, methods: Vec<&Method>| {
+            methods
+                .into_iter()
+                .filter(|meth| !self.is_member_excluded(owner, meth.name()))
+                .map(|meth| {
+                    let name = oracle.fn_name(meth.name());
+                    let name = if name == class_name {
+                        oracle.class_method_name(&name, self)
+                    } else {
+                        name
+                    };
+                    (
+                        meth.name().to_string(),
+                        oracle.async_name(&name, meth.is_async(), self),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let functions = ci
+            .function_definitions()
+            .iter()
+            .filter(|func| !self.is_excluded(func.name()))
+            .map(|func| {
+                (
+                    func.name().to_string(),
+                    oracle.async_name(&oracle.fn_name(func.name()), func.is_async(), self),
+                )
+            })
+            .collect();
+        check_scope(ci.namespace(), functions)?;
+
+        for obj in ci.object_definitions() {
+            if self.is_excluded(obj.name()) {
+                continue;
+            }
+            let (_, impl_name) = oracle.object_names(obj, ci, self);
+            let mut members = method_names(obj.name(), &impl_name, obj.methods());
+            for cons in obj.constructors() {
+                if self.is_member_excluded(obj.name(), cons.name()) {
+                    continue;
+                }
+                let name = if cons.is_primary_constructor() {
+                    if !cons.is_async() {
+                        continue;
+                    }
+                    format!("{impl_name}Async")
+                } else {
+                    let name = oracle.fn_name(cons.name());
+                    let name = if name == impl_name {
+                        oracle.class_method_name(&name, self)
+                    } else {
+                        name
+                    };
+                    oracle.async_name(&name, cons.is_async(), self)
+                };
+                members.push((cons.name().to_string(), name));
+            }
+            check_scope(obj.name(), members)?;
+        }
+        for rec in ci.record_definitions() {
+            if !self.is_excluded(rec.name()) {
+                check_scope(rec.name(), method_names(rec.name(), "", rec.methods()))?;
+            }
+        }
+        for e in ci.enum_definitions() {
+            if !self.is_excluded(e.name()) {
+                check_scope(e.name(), method_names(e.name(), "", e.methods()))?;
+            }
+        }
+        Ok(())
+    }
+
+    // Sealed enums generate `Match`, `Switch`, `Is<Variant>` and `As<Variant>` members next to the
+    // variant records and the exported methods, which C# can't declare twice.
+    fn check_sealed_enums(&self, ci: &ComponentInterface) -> Result<()> {
+        if !self.sealed_enums {
+            return Ok(());
+        }
+        let oracle = CsCodeOracle;
+        for e in ci.enum_definitions() {
+            if e.is_flat() || ci.is_name_used_as_error(e.name()) || self.is_excluded(e.name()) {
+                continue;
+            }
+            let mut members = vec![
+                ("helper".to_string(), "Match".to_string()),
+                ("helper".to_string(), "Switch".to_string()),
+            ];
+            for variant in e.variants() {
+                let class_name = oracle.class_name(variant.name(), ci, self);
+                members.push((format!("variant `{}`", variant.name()), class_name.clone()));
+                members.push(("helper".to_string(), format!("Is{class_name}")));
+                members.push(("helper".to_string(), format!("As{class_name}")));
+            }
+            for meth in e.methods() {
+                if self.is_member_excluded(e.name(), meth.name()) {
+                    continue;
+                }
+                let name = oracle.fn_name(meth.name());
+                let description = format!("method `{}`", meth.name());
+                if meth.is_async() && self.async_blocking() {
+                    members.push((description.clone(), format!("{name}Blocking")));
+                }
+                members.push((description, oracle.async_name(&name, meth.is_async(), self)));
+            }
+
+            let mut names = HashMap::new();
+            for (description, cs_name) in members {
+                if let Some(other) = names.insert(cs_name.clone(), description.clone()) {
+                    bail!(
+                        "sealed_enums: the {other} and the {description} of `{}` are both named \
+                         `{cs_name}`, rename one of them",
+                        e.name()
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_visibility(&self, ci: &ComponentInterface) -> Result<()> {
+        // Items that can be configured. Methods of trait interfaces implemented in C# can't be,
+        // because they are declared by the interface implemented by foreign code too.
+        let mut keys = HashSet::new();
+        // Types used by each item that is generated.
+        let mut used_types = Vec::new();
+
+        for func in ci.function_definitions() {
+            keys.insert(func.name().to_string());
+            if !self.is_excluded(func.name()) {
+                used_types.push((func.name().to_string(), callable_types(func)));
+            }
+        }
+        for obj in ci.object_definitions() {
+            keys.insert(obj.name().to_string());
+            if obj.has_callback_interface() {
+                continue;
+            }
+            for cons in obj.constructors() {
+                keys.insert(format!("{}.{}", obj.name(), cons.name()));
+                if !self.is_excluded(obj.name())
+                    && !self.is_member_excluded(obj.name(), cons.name())
+                {
+                    used_types.push((
+                        format!("{}.{}", obj.name(), cons.name()),
+                        callable_types(cons),
+                    ));
+                }
+            }
+            for meth in obj.methods() {
+                keys.insert(format!("{}.{}", obj.name(), meth.name()));
+                if !self.is_excluded(obj.name())
+                    && !self.is_member_excluded(obj.name(), meth.name())
+                {
+                    used_types.push((
+                        format!("{}.{}", obj.name(), meth.name()),
+                        callable_types(meth),
+                    ));
+                }
+            }
+        }
+        for rec in ci.record_definitions() {
+            keys.insert(rec.name().to_string());
+            if !self.is_excluded(rec.name()) {
+                used_types.push((
+                    rec.name().to_string(),
+                    rec.fields().iter().map(|f| f.as_type()).collect(),
+                ));
+            }
+            for meth in rec.methods() {
+                keys.insert(format!("{}.{}", rec.name(), meth.name()));
+                if !self.is_excluded(rec.name())
+                    && !self.is_member_excluded(rec.name(), meth.name())
+                {
+                    used_types.push((
+                        format!("{}.{}", rec.name(), meth.name()),
+                        callable_types(meth),
+                    ));
+                }
+            }
+        }
+        for e in ci.enum_definitions() {
+            keys.insert(e.name().to_string());
+            if !self.is_excluded(e.name()) {
+                used_types.push((
+                    e.name().to_string(),
+                    e.variants()
+                        .iter()
+                        .flat_map(|v| v.fields())
+                        .map(|f| f.as_type())
+                        .collect(),
+                ));
+            }
+            for meth in e.methods() {
+                keys.insert(format!("{}.{}", e.name(), meth.name()));
+                if !self.is_excluded(e.name()) && !self.is_member_excluded(e.name(), meth.name()) {
+                    used_types.push((
+                        format!("{}.{}", e.name(), meth.name()),
+                        callable_types(meth),
+                    ));
+                }
+            }
+        }
+        for cbi in ci.callback_interface_definitions() {
+            keys.insert(cbi.name().to_string());
+            if !self.is_excluded(cbi.name()) {
+                used_types.push((
+                    cbi.name().to_string(),
+                    cbi.methods().into_iter().flat_map(callable_types).collect(),
+                ));
+            }
+        }
+
+        for key in self.visibility.keys() {
+            if !keys.contains(key) {
+                bail!(
+                    "visibility: `{key}` is not a type, function, constructor or method that can be \
+                     configured"
+                );
+            }
+        }
+        for (item, types) in used_types {
+            if let Some(type_) = types.iter().find(|t| self.is_type_excluded(t)) {
+                bail!(
+                    "visibility: `{item}` uses `{}`, which is excluded",
+                    CsCodeOracle.find(type_).type_label(ci, self)
+                );
+            }
+        }
+        Ok(())
+    }
+
+    // Type returned by async functions, without the generic argument.
+    pub fn async_type_name(&self) -> &str {
+        match self.async_surface {
+            AsyncSurface::ValueTask => "ValueTask",
+            AsyncSurface::Task | AsyncSurface::TaskAndBlocking => "Task",
+        }
+    }
+
+    pub fn async_blocking(&self) -> bool {
+        self.async_surface == AsyncSurface::TaskAndBlocking
+    }
+
+    // Method configured to be exposed as `IAsyncEnumerable<T>` for the given object. The method must
+    // be async, take no arguments and return `Option<T>`; `None` ends the enumeration.
+    pub fn async_enumerable<'a>(&self, obj: &'a Object) -> Option<AsyncEnumerable<'a>> {
+        let method_name = self.async_enumerables.get(obj.name())?;
+        let method = obj
+            .methods()
+            .into_iter()
+            .find(|m| m.name() == method_name)?;
+        match method.return_type()? {
+            Type::Optional { inner_type } if method.is_async() && method.arguments().is_empty() => {
+                Some(AsyncEnumerable {
+                    method,
+                    item_type: inner_type,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    // Accessors are synchronous methods without arguments that don't throw and return a value.
+    // Trait interfaces implemented in C# keep their methods.
+    pub fn object_properties<'a>(&self, obj: &'a Object) -> Vec<ObjectProperty<'a>> {
+        if self.object_properties == ObjectProperties::None || obj.has_callback_interface() {
+            return Vec::new();
+        }
+        let methods = obj.methods();
+        let is_plain = |meth: &Method| {
+            !meth.is_async()
+                && meth.throws_type().is_none()
+                && !self.is_member_excluded(obj.name(), meth.name())
+        };
+        let mut properties = Vec::new();
+        for getter in methods.iter().copied() {
+            let Some(value_type) = getter.return_type() else {
+                continue;
+            };
+            if !getter.arguments().is_empty() || !is_plain(getter) {
+                continue;
+            }
+            let name = match getter.name().strip_prefix("get_") {
+                Some(stripped) => stripped,
+                None if self.object_properties == ObjectProperties::All
+                    || getter.name().starts_with("is_") =>
+                {
+                    getter.name()
+                }
+                None => continue,
+            };
+            // `get_x` stays a method if there is an `x` method too.
+            if name != getter.name() && methods.iter().any(|meth| meth.name() == name) {
+                continue;
+            }
+            let access = self.member_access_modifier(obj.name(), getter.name());
+            let setter = methods.iter().copied().find(|setter| {
+                setter.name() == format!("set_{name}")
+                    && setter.return_type().is_none()
+                    && is_plain(setter)
+                    && self.member_access_modifier(obj.name(), setter.name()) == access
+                    && matches!(setter.arguments().as_slice(), [arg] if arg.as_type() == *value_type)
+            });
+            properties.push(ObjectProperty {
+                name: name.to_string(),
+                value_type,
+                getter,
+                setter,
+            });
+        }
+        properties
+    }
+
+    pub fn is_property_accessor(&self, obj: &Object, meth: &Method) -> bool {
+        self.object_properties(obj).iter().any(|property| {
+            property.getter.name() == meth.name()
+                || property
+                    .setter
+                    .is_some_and(|setter| setter.name() == meth.name())
+        })
+    }
+
+    pub fn has_async_enumerables(&self) -> bool {
+        !self.async_enumerables.is_empty()
+    }
+
+    fn check_async_enumerables(&self, ci: &ComponentInterface) -> Result<()> {
+        for (obj_name, method_name) in &self.async_enumerables {
+            let Some(obj) = ci.get_object_definition(obj_name) else {
+                bail!("async_enumerables: object `{obj_name}` not found");
+            };
+            if self.is_excluded(obj_name) || self.is_member_excluded(obj_name, method_name) {
+                bail!(
+                    "async_enumerables: `{obj_name}.{method_name}` is excluded with `visibility`"
+                );
+            }
+            if self.async_enumerable(obj).is_none() {
+                bail!(
+                    "async_enumerables: `{obj_name}.{method_name}` must be an async method without \
+                     arguments returning `Option<T>`"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn check_record_styles(&self, ci: &ComponentInterface) -> Result<()> {
+        for rec_name in self.record_styles.keys() {
+            if ci.get_record_definition(rec_name).is_none() {
+                bail!("record_styles: record `{rec_name}` not found");
+            }
+        }
+        for rec in ci.record_definitions() {
+            if !self.is_struct_record(rec) {
+                continue;
+            }
+            if let Some(conflict) = struct_record_conflict(rec) {
+                bail!(
+                    "record_style: `{}` {conflict}, so it can't be a struct",
+                    rec.name()
+                );
+            }
+            if ci.item_contains_object_references(&rec.as_type()) {
+                bail!(
+                    "record_style: `{}` contains objects, so it can't be a struct",
+                    rec.name()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    pub fn sequence_kind(&self) -> SequenceKind {
+        self.collections.sequence
+    }
+
+    pub fn map_kind(&self) -> MapKind {
+        self.collections.map
+    }
+
+    // Whether the bindings use `System.Collections.Immutable`.
+    pub fn uses_immutable_collections(&self) -> bool {
+        self.collections.uses_immutable_collections()
+    }
+
+    pub fn uses_immutable_arrays(&self) -> bool {
+        self.collections.uses_immutable_arrays()
+    }
+
+    pub fn timestamp_kind(&self) -> TimestampKind {
+        self.time.timestamp
+    }
+
+    pub fn duration_kind(&self) -> DurationKind {
+        self.time.duration
+    }
+
+    // Whether converters clamp timestamps and durations outside the range of their C# type.
+    pub fn clamps_time(&self) -> bool {
+        self.time.overflow == TimeOverflow::Clamp
+    }
+
+    // Whether the bindings use the `NodaTime` package.
+    pub fn uses_noda_time(&self) -> bool {
+        self.time.timestamp == TimestampKind::NodaTime
+            || self.time.duration == DurationKind::NodaTime
+    }
+
+    fn check_collections(&self, ci: &ComponentInterface) -> Result<()> {
+        let members = fields_and_arguments(ci);
+
+        for (key, kind) in &self.collections.overrides {
+            let Some((_, _, type_, _)) = members
+                .iter()
+                .find(|(member, configurable, ..)| *configurable && member == key)
+            else {
+                bail!(
+                    "collections: `{key}` is not a record field, or an argument of a function or \
+                     method"
+                );
+            };
+            match (type_, kind) {
+                (Type::Sequence { .. }, CollectionKind::Sequence(_))
+                | (Type::Map { .. }, CollectionKind::Map(_)) => {}
+                (Type::Sequence { .. }, _) => {
+                    bail!("collections: `{key}` is a sequence, not a map")
+                }
+                (Type::Map { .. }, _) => bail!("collections: `{key}` is a map, not a sequence"),
+                _ => bail!("collections: `{key}` is not a sequence or map"),
+            }
+        }
+
+        // `ImmutableArray<T>` is a struct, so the only constant a default value can be is
+        // `default`, which isn't an empty array but one that throws when used.
+        for (key, configurable, type_, default) in &members {
+            let Some(default) = default else {
+                continue;
+            };
+            let is_immutable_array = match type_ {
+                Type::Sequence { .. } => {
+                    let kind = match self.collections.overrides.get(key) {
+                        Some(CollectionKind::Sequence(kind)) if *configurable => *kind,
+                        _ => self.collections.sequence,
+                    };
+                    kind == SequenceKind::ImmutableArray
+                }
+                Type::Optional { inner_type } => {
+                    matches!(**inner_type, Type::Sequence { .. })
+                        && matches!(default, DefaultValue::Literal(Literal::Some { .. }))
+                        && self.collections.sequence == SequenceKind::ImmutableArray
+                }
+                _ => false,
+            };
+            if is_immutable_array {
+                bail!(
+                    "collections: `{key}` is an `ImmutableArray`, so it can't have a default value"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    // `UniffiOptional<T>` is a struct, so a default value can only be `None`, which is `default`.
+    fn check_optional_defaults(&self, ci: &ComponentInterface) -> Result<()> {
+        for (key, _, type_, default) in fields_and_arguments(ci) {
+            let Type::Optional { inner_type } = type_ else {
+                continue;
+            };
+            if matches!(*inner_type, Type::Optional { .. })
+                && matches!(default, Some(DefaultValue::Literal(Literal::Some { .. })))
+            {
+                bail!(
+                    "`{key}` is an optional of an optional, so its default value can only be None"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    pub fn runtime_namespace(&self) -> &str {
+        self.runtime_namespace
+            .as_deref()
+            .unwrap_or("Uniffi.Runtime")
+    }
+
+    // The FFI runtime is internal to the bindings of each component, unless it is generated once
+    // and shared by bindings of multiple components, possibly living in a separate assembly.
+    pub fn runtime_access_modifier(&self) -> String {
+        if self.shared_runtime {
+            self.access_modifier()
+        } else {
+            "internal".to_string()
+        }
+    }
+
+    // Imports required by custom type aliases. In split mode these are added to every file,
+    // because `using` aliases are file scoped and are emitted in every file.
+    fn custom_type_imports(&self) -> BTreeSet<String> {
+        self.custom_types
+            .values()
+            .filter_map(|custom_type| custom_type.imports.as_ref())
+            .flatten()
+            .cloned()
+            .collect()
+    }
+
+    pub fn project(&self) -> &ProjectConfig {
+        &self.project
+    }
+
     pub fn rename(&self) -> &HashMap<String, toml::value::Table> {
         &self.rename
     }
@@ -142,6 +1220,53 @@ impl Config {
     }
 }
 
+// Types of the arguments, return value and error of a function, method or constructor.
+fn callable_types(callable: &impl Callable) -> Vec<Type> {
+    callable
+        .arguments()
+        .into_iter()
+        .map(|arg| arg.as_type())
+        .chain(callable.return_type())
+        .chain(callable.throws_type())
+        .collect()
+}
+
+fn is_fixed_size_primitive(type_: &Type) -> bool {
+    matches!(
+        type_,
+        Type::Boolean
+            | Type::Int8
+            | Type::UInt8
+            | Type::Int16
+            | Type::UInt16
+            | Type::Int32
+            | Type::UInt32
+            | Type::Int64
+            | Type::UInt64
+            | Type::Float32
+            | Type::Float64
+    )
+}
+
+// Struct records can't have members calling into Rust, because lambdas in structs can't capture
+// `this` (CS1673). Copies of structs containing objects would share the objects they dispose, which
+// is checked separately.
+fn struct_record_conflict(rec: &Record) -> Option<&'static str> {
+    let traits = rec.uniffi_trait_methods();
+    if !rec.methods().is_empty() {
+        Some("has methods")
+    } else if traits.display_fmt.is_some()
+        || traits.debug_fmt.is_some()
+        || traits.eq_eq.is_some()
+        || traits.hash_hash.is_some()
+        || traits.ord_cmp.is_some()
+    {
+        Some("exports traits")
+    } else {
+        None
+    }
+}
+
 // Generate C# bindings for the given ComponentInterface, as a string.
 pub fn generate_bindings(config: &Config, ci: &ComponentInterface) -> Result<String> {
     CsWrapper::new(config.clone(), ci)?
@@ -149,6 +1274,216 @@ pub fn generate_bindings(config: &Config, ci: &ComponentInterface) -> Result<Str
         .context("failed to render C# bindings")
 }
 
+// Generate the FFI runtime shared by bindings of components configured with `shared_runtime`.
+//
+// The runtime uses the `RustBuffer` allocation functions of the given component, so all components
+// sharing the runtime must be linked into the same native library.
+pub fn generate_runtime(config: &Config, ci: &ComponentInterface) -> Result<String> {
+    CsRuntime::new(config.clone(), ci)?
+        .render()
+        .context("failed to render C# runtime")
+}
+
+/// A single generated C# source file.
+pub struct BindingsFile {
+    pub name: String,
+    pub contents: String,
+}
+
+// Generate C# bindings for the given ComponentInterface, split into multiple files.
+//
+// The first file contains the FFI runtime, builtin type converters and `_UniFFILib`. Each record,
+// enum, error, object and callback interface is written to a file of its own, and top level
+// functions are written into the file named after the global methods class.
+pub fn generate_split_bindings(
+    config: &Config,
+    ci: &ComponentInterface,
+) -> Result<Vec<BindingsFile>> {
+    let wrapper = CsWrapper::new(config.clone(), ci)?;
+    let mut include_once_names = wrapper.include_once_names.take();
+    let mut type_aliases = wrapper.type_aliases.clone();
+
+    let mut type_files = Vec::new();
+    for type_ in ci
+        .iter_local_types()
+        .filter(|t| has_own_file(t) && !config.is_type_excluded(t))
+    {
+        let type_renderer =
+            TypeRenderer::for_types(config, ci, vec![type_.clone()], false, include_once_names);
+        let type_code = type_renderer
+            .render()
+            .with_context(|| format!("failed to render type {type_:?}"))?;
+        include_once_names = type_renderer.include_once_names.into_inner();
+        type_aliases.extend(type_renderer.type_aliases.into_inner());
+        type_files.push((
+            CsCodeOracle.find(type_).type_label(ci, config),
+            type_code,
+            type_renderer.imports.into_inner(),
+        ));
+    }
+
+    let functions_file = CsFunctionsFile {
+        config,
+        ci,
+        imports: file_imports(config, ci, BTreeSet::new()),
+        type_aliases: type_aliases.iter().cloned().collect(),
+    };
+    let mut files = vec![
+        BindingsFile {
+            name: format!("{}.cs", ci.namespace()),
+            contents: wrapper.render().context("failed to render C# bindings")?,
+        },
+        BindingsFile {
+            name: format!("{}.cs", functions_file.class_name()),
+            contents: functions_file
+                .render()
+                .context("failed to render C# functions")?,
+        },
+    ];
+
+    for (type_label, type_code, imports) in type_files {
+        let type_file = CsTypeFile {
+            config,
+            imports: file_imports(config, ci, imports),
+            type_aliases: type_aliases.iter().cloned().collect(),
+            type_code,
+        };
+        files.push(BindingsFile {
+            name: format!("{type_label}.cs"),
+            contents: type_file
+                .render()
+                .with_context(|| format!("failed to render {type_label}.cs"))?,
+        });
+    }
+    Ok(files)
+}
+
+// Record fields and arguments with their default values, keyed by their Rust names like
+// `collections.overrides`, and whether their collection type can be configured, which it can't be
+// for constructors. Methods of trait interfaces implemented in C# are left out, because their
+// arguments are lifted from Rust.
+fn fields_and_arguments(
+    ci: &ComponentInterface,
+) -> Vec<(String, bool, Type, Option<&DefaultValue>)> {
+    let mut members = Vec::new();
+    let mut add_arguments = |prefix: String, configurable: bool, args: Vec<&Argument>| {
+        for arg in args {
+            let key = format!("{prefix}.{}", arg.name());
+            members.push((key, configurable, arg.as_type(), arg.default_value()));
+        }
+    };
+    for func in ci.function_definitions() {
+        add_arguments(func.name().to_string(), true, func.arguments());
+    }
+    for obj in ci.object_definitions() {
+        if obj.has_callback_interface() {
+            continue;
+        }
+        for cons in obj.constructors() {
+            let prefix = format!("{}.{}", obj.name(), cons.name());
+            add_arguments(prefix, false, cons.arguments());
+        }
+        for meth in obj.methods() {
+            let prefix = format!("{}.{}", obj.name(), meth.name());
+            add_arguments(prefix, true, meth.arguments());
+        }
+    }
+    for rec in ci.record_definitions() {
+        for meth in rec.methods() {
+            let prefix = format!("{}.{}", rec.name(), meth.name());
+            add_arguments(prefix, true, meth.arguments());
+        }
+    }
+    for e in ci.enum_definitions() {
+        for meth in e.methods() {
+            let prefix = format!("{}.{}", e.name(), meth.name());
+            add_arguments(prefix, true, meth.arguments());
+        }
+    }
+    for rec in ci.record_definitions() {
+        for field in rec.fields() {
+            let key = format!("{}.{}", rec.name(), field.name());
+            members.push((key, true, field.as_type(), field.default_value()));
+        }
+    }
+    members
+}
+
+// Whether the type is generated as part of the shared runtime when `shared_runtime` is enabled.
+fn is_runtime_type(type_: &Type) -> bool {
+    RUNTIME_TYPES.contains(type_)
+}
+
+const RUNTIME_TYPES: [Type; 13] = [
+    Type::Boolean,
+    Type::Int8,
+    Type::Int16,
+    Type::Int32,
+    Type::Int64,
+    Type::UInt8,
+    Type::UInt16,
+    Type::UInt32,
+    Type::UInt64,
+    Type::Float32,
+    Type::Float64,
+    Type::String,
+    Type::Bytes,
+];
+
+// Templates included by the shared runtime, which must not be included again by components.
+fn runtime_include_once_names(config: &Config) -> HashSet<String> {
+    if config.shared_runtime() {
+        HashSet::from([
+            "ConcurrentHandleMap.cs".to_string(),
+            "CallbackResponseStatus.cs".to_string(),
+        ])
+    } else {
+        HashSet::new()
+    }
+}
+
+// Whether the type is written to a file of its own when splitting bindings into multiple files.
+fn has_own_file(type_: &Type) -> bool {
+    matches!(
+        type_,
+        Type::Record { .. }
+            | Type::Enum { .. }
+            | Type::Object { .. }
+            | Type::CallbackInterface { .. }
+    )
+}
+
+// Imports of a single file in split mode: the base imports every file relies on, imports required
+// by custom type aliases, and imports added while rendering the file itself.
+fn file_imports(
+    config: &Config,
+    ci: &ComponentInterface,
+    mut imports: BTreeSet<String>,
+) -> Vec<String> {
+    for import in [
+        "System",
+        "System.Collections.Generic",
+        "System.IO",
+        "System.Linq",
+        "System.Runtime.CompilerServices",
+        "System.Runtime.InteropServices",
+    ] {
+        imports.insert(import.to_string());
+    }
+    if ci.has_async_fns() {
+        imports.insert("System.Threading".to_string());
+        imports.insert("System.Threading.Tasks".to_string());
+    }
+    if config.uses_immutable_collections() {
+        imports.insert("System.Collections.Immutable".to_string());
+    }
+    if config.shared_runtime() {
+        imports.insert(config.runtime_namespace().to_string());
+    }
+    imports.extend(config.custom_type_imports());
+    imports.into_iter().collect()
+}
+
 /// Renders C# helper code for all types
 ///
 /// This template is a bit different than others in that it stores internal state from the render
@@ -158,6 +1493,10 @@ pub fn generate_bindings(config: &Config, ci: &ComponentInterface) -> Result<Str
 pub struct TypeRenderer<'a> {
     config: &'a Config,
     ci: &'a ComponentInterface,
+    // Local types rendered by this instance
+    local_types: Vec<Type>,
+    // Whether to render external types and async support code
+    include_support_code: bool,
     // Track included modules for the `include_once()` macro
     include_once_names: RefCell<HashSet<String>>,
     // Track imports added with the `add_import()` macro
@@ -174,10 +1513,35 @@ pub struct TypeAlias {
 
 impl<'a> TypeRenderer<'a> {
     fn new(config: &'a Config, ci: &'a ComponentInterface) -> Self {
+        let local_types = ci
+            .iter_local_types()
+            .filter(|t| !config.split_files() || !has_own_file(t))
+            .filter(|t| !config.shared_runtime() || !is_runtime_type(t))
+            .filter(|t| !config.is_type_excluded(t))
+            .cloned()
+            .collect();
+        Self::for_types(
+            config,
+            ci,
+            local_types,
+            true,
+            runtime_include_once_names(config),
+        )
+    }
+
+    fn for_types(
+        config: &'a Config,
+        ci: &'a ComponentInterface,
+        local_types: Vec<Type>,
+        include_support_code: bool,
+        include_once_names: HashSet<String>,
+    ) -> Self {
         Self {
             config,
             ci,
-            include_once_names: RefCell::new(HashSet::new()),
+            local_types,
+            include_support_code,
+            include_once_names: RefCell::new(include_once_names),
             imports: RefCell::new(BTreeSet::new()),
             type_aliases: RefCell::new(BTreeSet::new()),
         }
@@ -233,28 +1597,40 @@ pub struct CsWrapper<'a> {
     type_helper_code: String,
     type_imports: RefCell<BTreeSet<String>>,
     type_aliases: BTreeSet<TypeAlias>,
+    include_once_names: RefCell<HashSet<String>>,
 }
 
 impl<'a> CsWrapper<'a> {
     pub fn new(config: Config, ci: &'a ComponentInterface) -> Result<Self> {
+        config.check_async_enumerables(ci)?;
+        config.check_visibility(ci)?;
+        config.check_naming()?;
+        config.check_async_names(ci)?;
+        config.check_record_styles(ci)?;
+        config.check_sealed_enums(ci)?;
+        config.check_collections(ci)?;
+        config.check_optional_defaults(ci)?;
         let type_renderer = TypeRenderer::new(&config, ci);
         let type_helper_code = type_renderer
             .render()
             .context("failed to render type helpers")?;
         let type_imports = type_renderer.imports.clone();
         let type_aliases = type_renderer.type_aliases.into_inner();
+        let include_once_names = type_renderer.include_once_names;
         Ok(Self {
             config,
             ci,
             type_helper_code,
             type_imports,
             type_aliases,
+            include_once_names,
         })
     }
 
     pub fn initialization_fns(&self) -> Vec<String> {
         self.ci
             .iter_local_types()
+            .filter(|t| !self.config.is_type_excluded(t))
             .map(|t| CsCodeOracle.find(t))
             .filter_map(|ct| ct.initialization_fn())
             .collect()
@@ -280,6 +1656,107 @@ impl<'a> CsWrapper<'a> {
     }
 }
 
+/// Renders the FFI runtime shared by bindings of multiple components.
+#[derive(Template)]
+#[template(syntax = "cs", escape = "none", path = "RuntimeWrapper.cs")]
+pub struct CsRuntime<'a> {
+    config: Config,
+    ci: &'a ComponentInterface,
+    type_helper_code: String,
+    type_imports: RefCell<BTreeSet<String>>,
+}
+
+impl<'a> CsRuntime<'a> {
+    pub fn new(config: Config, ci: &'a ComponentInterface) -> Result<Self> {
+        let type_renderer =
+            TypeRenderer::for_types(&config, ci, RUNTIME_TYPES.to_vec(), false, HashSet::new());
+        let type_helper_code = type_renderer
+            .render()
+            .context("failed to render runtime type helpers")?;
+        let type_imports = type_renderer.imports.clone();
+        Ok(Self {
+            config,
+            ci,
+            type_helper_code,
+            type_imports,
+        })
+    }
+
+    // Native functions called by the runtime itself. Everything else is declared by the bindings
+    // of each component.
+    pub fn ffi_functions(&self) -> Vec<FfiFunction> {
+        vec![
+            self.ci.ffi_rustbuffer_alloc(),
+            self.ci.ffi_rustbuffer_free(),
+        ]
+    }
+
+    fn add_import(&self, name: &str) -> &str {
+        self.type_imports.borrow_mut().insert(name.to_owned());
+        ""
+    }
+
+    pub fn imports(&self) -> Vec<String> {
+        self.type_imports.borrow().iter().cloned().collect()
+    }
+}
+
+/// Renders a single type into its own file when splitting bindings into multiple files.
+#[derive(Template)]
+#[template(syntax = "cs", escape = "none", path = "TypeFile.cs")]
+pub struct CsTypeFile<'a> {
+    config: &'a Config,
+    imports: Vec<String>,
+    type_aliases: Vec<TypeAlias>,
+    type_code: String,
+}
+
+/// Renders the class containing top level functions when splitting bindings into multiple files.
+#[derive(Template)]
+#[template(syntax = "cs", escape = "none", path = "FunctionsFile.cs")]
+pub struct CsFunctionsFile<'a> {
+    config: &'a Config,
+    ci: &'a ComponentInterface,
+    imports: Vec<String>,
+    type_aliases: Vec<TypeAlias>,
+}
+
+impl CsFunctionsFile<'_> {
+    fn class_name(&self) -> String {
+        self.config.methods_class_name(self.ci)
+    }
+}
+
+// Prefix of the `collections.overrides` keys of the arguments of a callable.
+trait ArgumentsKey {
+    fn arguments_key(&self) -> Option<String>;
+}
+
+impl ArgumentsKey for Function {
+    fn arguments_key(&self) -> Option<String> {
+        Some(self.name().to_string())
+    }
+}
+
+impl ArgumentsKey for Method {
+    fn arguments_key(&self) -> Option<String> {
+        Some(format!("{}.{}", self.object_name(), self.name()))
+    }
+}
+
+// Constructors take their arguments as declared in Rust.
+impl ArgumentsKey for Constructor {
+    fn arguments_key(&self) -> Option<String> {
+        None
+    }
+}
+
+impl<T: ArgumentsKey> ArgumentsKey for &T {
+    fn arguments_key(&self) -> Option<String> {
+        (*self).arguments_key()
+    }
+}
+
 trait AsCodeType {
     fn as_codetype(&self) -> Box<dyn CodeType>;
 }
@@ -343,20 +1820,20 @@ impl CsCodeOracle {
     }
 
     /// Get the idiomatic C# rendering of a class name (for enums, records, errors, etc).
-    fn class_name(&self, nm: &str, ci: &ComponentInterface) -> String {
+    fn class_name(&self, nm: &str, ci: &ComponentInterface, config: &Config) -> String {
         let name = nm.to_string().to_upper_camel_case();
         // fixup errors.
         if ci.is_name_used_as_error(nm) {
-            self.convert_error_suffix(&name)
+            self.convert_error_suffix(&name, config)
         } else {
             name
         }
     }
 
-    fn convert_error_suffix(&self, nm: &str) -> String {
+    fn convert_error_suffix(&self, nm: &str, config: &Config) -> String {
         match nm.strip_suffix("Error") {
             None => nm.to_string(),
-            Some(stripped) => format!("{stripped}Exception"),
+            Some(stripped) => format!("{stripped}{}", config.naming.error_suffix()),
         }
     }
 
@@ -365,6 +1842,11 @@ impl CsCodeOracle {
         nm.to_string().to_upper_camel_case()
     }
 
+    /// Get the C# rendering of a private backing field name.
+    fn backing_field_name(&self, nm: &str) -> String {
+        format!("_{}", nm.to_string().to_lower_camel_case())
+    }
+
     /// Get the idiomatic C# rendering of a variable name.
     fn var_name(&self, nm: &str) -> String {
         format!("@{}", nm.to_string().to_lower_camel_case())
@@ -397,25 +1879,82 @@ impl CsCodeOracle {
         format!("Uniffi{}", nm.to_upper_camel_case())
     }
 
-    fn interface_name(&self, nm: &str) -> String {
-        format!("I{nm}")
+    fn interface_name(&self, nm: &str, config: &Config) -> String {
+        config.naming.interface_name(nm)
     }
 
-    fn impl_name(&self, nm: &str) -> String {
-        format!("{nm}Impl")
+    fn impl_name(&self, nm: &str, config: &Config) -> String {
+        config.naming.impl_name(nm)
+    }
+
+    fn class_method_name(&self, nm: &str, config: &Config) -> String {
+        format!("{nm}{}", config.naming.class_method_suffix())
+    }
+
+    fn async_name(&self, nm: &str, is_async: bool, config: &Config) -> String {
+        if is_async {
+            config.naming.async_name(nm)
+        } else {
+            nm.to_string()
+        }
+    }
+
+    // C# type of a record field or argument, with the collection type configured for it in
+    // `collections.overrides`.
+    fn member_type_label(
+        &self,
+        type_: &Type,
+        key: &str,
+        ci: &ComponentInterface,
+        config: &Config,
+    ) -> String {
+        match (type_, config.collections.overrides.get(key).copied()) {
+            (Type::Sequence { inner_type }, Some(CollectionKind::Sequence(kind))) => {
+                kind.type_label(&self.find(inner_type).type_label(ci, config))
+            }
+            (
+                Type::Map {
+                    key_type,
+                    value_type,
+                },
+                Some(CollectionKind::Map(kind)),
+            ) => kind.type_label(
+                &self.find(key_type).type_label(ci, config),
+                &self.find(value_type).type_label(ci, config),
+            ),
+            _ => self.find(type_).type_label(ci, config),
+        }
+    }
+
+    // Converts `value`, lifted by the converter of `type_`, to the C# type of a record field.
+    fn member_lift(&self, type_: &Type, key: &str, value: &str, config: &Config) -> String {
+        match (type_, config.collections.overrides.get(key).copied()) {
+            (Type::Sequence { .. }, Some(CollectionKind::Sequence(kind))) => {
+                kind.convert(config.collections.sequence, value)
+            }
+            (Type::Map { .. }, Some(CollectionKind::Map(kind))) => {
+                kind.convert(config.collections.map, value)
+            }
+            _ => value.to_string(),
+        }
     }
 
-    fn object_names(&self, obj: &Object, ci: &ComponentInterface) -> (String, String) {
-        let class_name = self.class_name(obj.name(), ci);
+    fn object_names(
+        &self,
+        obj: &Object,
+        ci: &ComponentInterface,
+        config: &Config,
+    ) -> (String, String) {
+        let class_name = self.class_name(obj.name(), ci, config);
         if obj.has_callback_interface() {
             // If the object has callback interface we will generate
-            // An interface Object and an implementation ObjectImpl
-            let impl_name = self.impl_name(&class_name);
+            // An interface Object and an implementation ObjectImpl (see `impl_name`)
+            let impl_name = self.impl_name(&class_name, config);
             (class_name, impl_name)
         } else {
             // In regular cases we will use C# convention
-            // An interface IObject and an implementation Object
-            (self.interface_name(&class_name), class_name)
+            // An interface IObject (see `interface_name`) and an implementation Object
+            (self.interface_name(&class_name, config), class_name)
         }
     }
 
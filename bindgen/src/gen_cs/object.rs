@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use super::CodeType;
+use super::{CodeType, Config};
 use uniffi_bindgen::{
     interface::{Literal, ObjectImpl},
     ComponentInterface,
@@ -21,8 +21,8 @@ impl ObjectCodeType {
 }
 
 impl CodeType for ObjectCodeType {
-    fn type_label(&self, ci: &ComponentInterface) -> String {
-        super::CsCodeOracle.class_name(&self.id, ci)
+    fn type_label(&self, ci: &ComponentInterface, config: &Config) -> String {
+        super::CsCodeOracle.class_name(&self.id, ci, config)
     }
 
     fn canonical_name(&self) -> String {
@@ -33,7 +33,7 @@ impl CodeType for ObjectCodeType {
         format!("{}ErrorHandler", self.ffi_converter_name())
     }
 
-    fn literal(&self, _literal: &Literal, _ci: &ComponentInterface) -> String {
+    fn literal(&self, _literal: &Literal, _ci: &ComponentInterface, _config: &Config) -> String {
         unreachable!();
     }
 
@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use super::CodeType;
+use super::{CodeType, Config};
 use paste::paste;
 use uniffi_bindgen::interface::{Literal, Radix, Type};
 use uniffi_bindgen::ComponentInterface;
@@ -59,7 +59,7 @@ macro_rules! impl_code_type_for_primitive {
             pub struct $T;
 
             impl CodeType for $T  {
-                fn type_label(&self, _ci: &ComponentInterface) -> String {
+                fn type_label(&self, _ci: &ComponentInterface, _config: &Config) -> String {
                     $type_label.into()
                 }
 
@@ -67,11 +67,11 @@ macro_rules! impl_code_type_for_primitive {
                     $canonical_name.into()
                 }
 
-                fn literal(&self, literal: &Literal, _ci: &ComponentInterface) -> String {
+                fn literal(&self, literal: &Literal, _ci: &ComponentInterface, _config: &Config) -> String {
                     render_literal(&literal)
                 }
 
-                fn default_value(&self, _ci: &ComponentInterface) -> String {
+                fn default_value(&self, _ci: &ComponentInterface, _config: &Config) -> String {
                     $default.into()
                 }
             }
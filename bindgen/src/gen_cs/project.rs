@@ -0,0 +1,290 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+use askama::Template;
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::Deserialize;
+use uniffi_bindgen::ComponentInterface;
+
+use super::Config;
+
+// Subset of `cargo metadata` output used to fill in the package metadata.
+#[derive(Deserialize)]
+struct CargoMetadata {
+    packages: Vec<CargoPackage>,
+}
+
+#[derive(Deserialize)]
+struct CargoPackage {
+    version: String,
+    #[serde(default)]
+    authors: Vec<String>,
+    description: Option<String>,
+    license: Option<String>,
+    repository: Option<String>,
+    homepage: Option<String>,
+    targets: Vec<CargoTarget>,
+}
+
+#[derive(Deserialize)]
+struct CargoTarget {
+    name: String,
+    kind: Vec<String>,
+}
+
+impl CargoPackage {
+    fn has_lib_target(&self, crate_name: &str) -> bool {
+        self.targets.iter().any(|t| {
+            t.name.replace('-', "_") == crate_name.replace('-', "_")
+                && t.kind.iter().any(|k| k == "lib" || k == "cdylib")
+        })
+    }
+}
+
+/// Finds the `Cargo.toml` that `cargo` would use in the directory of the native library, usually
+/// the manifest of the workspace whose `target` directory contains the library.
+pub fn find_manifest(library: &Utf8Path) -> Result<Utf8PathBuf> {
+    let library = library
+        .canonicalize_utf8()
+        .with_context(|| format!("failed to resolve library path {library}"))?;
+    library
+        .ancestors()
+        .skip(1)
+        .map(|dir| dir.join("Cargo.toml"))
+        .find(|manifest| manifest.is_file())
+        .with_context(|| {
+            format!("no `Cargo.toml` found above {library}, pass it with `--manifest-path`")
+        })
+}
+
+fn cargo_package(manifest_path: &Utf8Path, crate_name: &str) -> Result<CargoPackage> {
+    let output = Command::new("cargo")
+        .arg("metadata")
+        .arg("--format-version")
+        .arg("1")
+        .arg("--no-deps")
+        .arg("--manifest-path")
+        .arg(manifest_path)
+        .output()
+        .context("failed to run `cargo metadata`")?;
+    if !output.status.success() {
+        bail!(
+            "`cargo metadata` returned non-zero exit status: {:?}\n{}",
+            output.status.code(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    let metadata: CargoMetadata = serde_json::from_slice(&output.stdout)?;
+    metadata.into_package(crate_name)
+}
+
+impl CargoMetadata {
+    fn into_package(self, crate_name: &str) -> Result<CargoPackage> {
+        self.packages
+            .into_iter()
+            .find(|p| p.has_lib_target(crate_name))
+            .with_context(|| format!("crate `{crate_name}` not found in `cargo metadata`"))
+    }
+}
+
+/// Renders a `.csproj` packaging the bindings and the native library as a NuGet package.
+#[derive(Template)]
+#[template(syntax = "cs", escape = "html", path = "Project.csproj")]
+struct CsProject<'a> {
+    config: &'a Config,
+    package: CargoPackage,
+    package_id: String,
+}
+
+impl CsProject<'_> {
+    fn generator_version(&self) -> &str {
+        env!("CARGO_PKG_VERSION")
+    }
+
+    fn target_frameworks(&self) -> String {
+        self.config.project().target_frameworks().join(";")
+    }
+
+    fn license_expression(&self) -> Option<String> {
+        license_expression(self.package.license.as_deref()?)
+    }
+
+    fn authors(&self) -> String {
+        // Cargo authors are formatted as `Name <email>`, NuGet only expects names
+        self.package
+            .authors
+            .iter()
+            .map(|a| a.split('<').next().unwrap_or(a).trim())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+// `PackageLicenseExpression` is an SPDX expression. Cargo used to accept `/` for `OR`, as in
+// `MIT/Apache-2.0`, so that is converted. Anything else NuGet can't parse is left out, because
+// `dotnet pack` fails on it.
+fn license_expression(license: &str) -> Option<String> {
+    let expression = license
+        .split('/')
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(" OR ");
+    let is_valid = !expression.is_empty()
+        && expression
+            .split(|c: char| c.is_whitespace() || c == '(' || c == ')')
+            .filter(|token| !token.is_empty())
+            .all(|token| {
+                token
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '+'))
+            });
+    is_valid.then_some(expression)
+}
+
+/// Name of the generated project file, without the directory.
+pub fn project_file_name(config: &Config) -> String {
+    format!("{}.csproj", package_id(config))
+}
+
+fn package_id(config: &Config) -> String {
+    match config.project().package_id.as_ref() {
+        Some(value) => value.clone(),
+        None => config.namespace(),
+    }
+}
+
+// Generate a `.csproj` for the bindings of the given ComponentInterface, with package metadata
+// taken from `Cargo.toml` of the crate. `manifest_path` is the manifest of the crate, or of the
+// workspace containing it.
+pub fn generate_project(
+    config: &Config,
+    ci: &ComponentInterface,
+    manifest_path: &Utf8Path,
+) -> Result<String> {
+    CsProject {
+        config,
+        package: cargo_package(manifest_path, ci.crate_name())?,
+        package_id: package_id(config),
+    }
+    .render()
+    .context("failed to render C# project")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(license: Option<&str>) -> CargoMetadata {
+        serde_json::from_value(serde_json::json!({
+            "packages": [
+                {
+                    "version": "0.1.0",
+                    "authors": [],
+                    "license": null,
+                    "targets": [{ "name": "greeter-cli", "kind": ["bin"] }],
+                },
+                {
+                    "version": "1.2.3",
+                    "authors": ["Jane Doe <jane@example.com>", "John Doe"],
+                    "description": "Greets people",
+                    "license": license,
+                    "repository": "https://example.com/greeter",
+                    "targets": [{ "name": "greeter-lib", "kind": ["lib", "cdylib"] }],
+                },
+            ],
+        }))
+        .unwrap()
+    }
+
+    fn config() -> Config {
+        Config {
+            namespace: Some("Example.Greeter".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn render(package: CargoPackage) -> String {
+        CsProject {
+            config: &config(),
+            package,
+            package_id: package_id(&config()),
+        }
+        .render()
+        .unwrap()
+    }
+
+    #[test]
+    fn finds_package_by_lib_target() {
+        let package = metadata(None).into_package("greeter_lib").unwrap();
+        assert_eq!(package.version, "1.2.3");
+        assert!(metadata(None).into_package("greeter_cli").is_err());
+        assert!(metadata(None).into_package("other").is_err());
+    }
+
+    #[test]
+    fn converts_license_to_expression() {
+        assert_eq!(license_expression("MIT").as_deref(), Some("MIT"));
+        assert_eq!(
+            license_expression("MIT OR Apache-2.0").as_deref(),
+            Some("MIT OR Apache-2.0")
+        );
+        assert_eq!(
+            license_expression("MIT/Apache-2.0").as_deref(),
+            Some("MIT OR Apache-2.0")
+        );
+        assert_eq!(
+            license_expression("(MIT OR Apache-2.0) AND GPL-2.0+").as_deref(),
+            Some("(MIT OR Apache-2.0) AND GPL-2.0+")
+        );
+        assert_eq!(license_expression("Custom, see LICENSE.txt"), None);
+        assert_eq!(license_expression(""), None);
+    }
+
+    #[test]
+    fn renders_package_metadata() {
+        let project = render(
+            metadata(Some("MIT/Apache-2.0"))
+                .into_package("greeter_lib")
+                .unwrap(),
+        );
+        assert!(project.contains("<PackageId>Example.Greeter</PackageId>"));
+        assert!(project.contains("<Version>1.2.3</Version>"));
+        assert!(project.contains("<Authors>Jane Doe, John Doe</Authors>"));
+        assert!(project.contains("<Description>Greets people</Description>"));
+        assert!(project
+            .contains("<PackageLicenseExpression>MIT OR Apache-2.0</PackageLicenseExpression>"));
+        assert!(project.contains("<RepositoryUrl>https://example.com/greeter</RepositoryUrl>"));
+        assert!(!project.contains("<PackageProjectUrl>"));
+        assert!(project.contains("<TargetFrameworks>netstandard2.0;net8.0</TargetFrameworks>"));
+    }
+
+    #[test]
+    fn omits_invalid_license() {
+        let project = render(
+            metadata(Some("see LICENSE.txt"))
+                .into_package("greeter_lib")
+                .unwrap(),
+        );
+        assert!(!project.contains("<PackageLicenseExpression>"));
+    }
+
+    #[test]
+    fn finds_manifest_above_library() {
+        let root = Utf8PathBuf::from_path_buf(std::env::temp_dir())
+            .unwrap()
+            .join(format!("uniffi-bindgen-cs-project-{}", std::process::id()));
+        let library_dir = root.join("target").join("debug");
+        std::fs::create_dir_all(&library_dir).unwrap();
+        std::fs::write(root.join("Cargo.toml"), "").unwrap();
+        std::fs::write(library_dir.join("libgreeter.so"), "").unwrap();
+
+        let expected = root.canonicalize_utf8().unwrap().join("Cargo.toml");
+        let manifest = find_manifest(&library_dir.join("libgreeter.so"));
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(manifest.unwrap(), expected);
+    }
+}
@@ -6,9 +6,10 @@ pub mod gen_cs;
 
 use anyhow::Result;
 use camino::{Utf8Path, Utf8PathBuf};
-use clap::Parser;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand};
 use fs_err::File;
-pub use gen_cs::generate_bindings;
+pub use gen_cs::{generate_bindings, generate_split_bindings};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io::Write;
@@ -18,7 +19,11 @@ use uniffi_bindgen::{Component, GenerationSettings};
 #[clap(name = "uniffi-bindgen")]
 #[clap(version = clap::crate_version!())]
 #[clap(propagate_version = true)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Cli {
+    #[clap(subcommand)]
+    command: Option<Command>,
+
     /// Directory in which to write generated files. Default is same folder as .udl file.
     #[clap(long, short)]
     out_dir: Option<Utf8PathBuf>,
@@ -36,11 +41,64 @@ struct Cli {
     crate_name: Option<String>,
 
     /// Path to the UDL file, or cdylib if `library-mode` is specified
-    source: Utf8PathBuf,
+    #[clap(required = true)]
+    source: Option<Utf8PathBuf>,
 
     /// Do not try to format the generated bindings.
     #[clap(long, short)]
     no_format: bool,
+
+    /// Generate the FFI runtime shared by components configured with `shared_runtime`, instead of
+    /// the bindings. When `--library` is passed, use `--crate` to choose the component whose
+    /// configuration is used for the runtime.
+    #[clap(long)]
+    runtime: bool,
+
+    /// Generate a `.csproj` next to the bindings and copy the cdylib into `runtimes/<rid>/native/`,
+    /// so that `dotnet pack` produces a NuGet package. Package metadata is read from `Cargo.toml`.
+    #[clap(long, requires = "library-mode")]
+    emit_project: bool,
+
+    /// `Cargo.toml` to read the package metadata from with `--emit-project`. Default is the
+    /// manifest found in the directory of the library or its parents.
+    #[clap(long, requires = "emit-project")]
+    manifest_path: Option<Utf8PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Write a JSON description of the public C# API of the bindings to `<namespace>.api.json`,
+    /// instead of the bindings. Names and signatures are the generated ones, after `rename`.
+    ApiReport {
+        /// Directory in which to write the reports. Default is same folder as .udl file.
+        #[clap(long, short)]
+        out_dir: Option<Utf8PathBuf>,
+
+        /// Path to the optional uniffi config file.
+        #[clap(long, short)]
+        config: Option<Utf8PathBuf>,
+
+        /// Pass in a cdylib path rather than a UDL file
+        #[clap(long = "library", requires = "out-dir")]
+        library_mode: bool,
+
+        /// When `--library` is passed, only write the report of one crate
+        #[clap(long = "crate", requires = "library-mode")]
+        crate_name: Option<String>,
+
+        /// Path to the UDL file, or cdylib if `library-mode` is specified
+        source: Utf8PathBuf,
+    },
+
+    /// Compare two reports written by `api-report` and classify the changes as breaking or
+    /// non-breaking. Exits with an error if any change is breaking.
+    ApiDiff {
+        /// Report of the previous version of the bindings
+        old: Utf8PathBuf,
+
+        /// Report of the new version of the bindings
+        new: Utf8PathBuf,
+    },
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -57,6 +115,63 @@ pub struct ConfigBindings {
 
 struct BindingGenerator {
     try_format_code: bool,
+    runtime: bool,
+    /// Native library to package, when `--emit-project` is passed.
+    project_library: Option<Utf8PathBuf>,
+    /// Manifest to read the package metadata from, see `--manifest-path`.
+    project_manifest: Option<Utf8PathBuf>,
+    /// Write API reports instead of the bindings, see `api-report`.
+    api_report: bool,
+}
+
+impl BindingGenerator {
+    fn write_bindings_file(&self, bindings_file: &Utf8Path, bindings: String) -> Result<()> {
+        println!("Writing bindings file {bindings_file}");
+        let mut f = File::create(bindings_file)?;
+
+        let bindings = gen_cs::formatting::add_header(bindings);
+        write!(f, "{bindings}")?;
+
+        if self.try_format_code {
+            let _ = gen_cs::formatting::format(bindings_file)
+                .map_err(|e| println!(
+                    "Warning: Unable to auto-format {} using CSharpier (hint: 'dotnet tool install -g csharpier'): {e:?}",
+                    bindings_file.file_name().unwrap(),
+                ));
+        }
+        Ok(())
+    }
+
+    fn write_project(
+        &self,
+        out_dir: &Utf8Path,
+        component: &Component<gen_cs::Config>,
+        library: &Utf8Path,
+    ) -> Result<()> {
+        let Component { ci, config, .. } = component;
+
+        let manifest_path = match &self.project_manifest {
+            Some(path) => path.clone(),
+            None => gen_cs::project::find_manifest(library)?,
+        };
+        let project_file = out_dir.join(gen_cs::project::project_file_name(config));
+        println!("Writing project file {project_file}");
+        fs_err::write(
+            &project_file,
+            gen_cs::project::generate_project(config, ci, &manifest_path)?,
+        )?;
+
+        let native_dir = out_dir
+            .join("runtimes")
+            .join(config.project().runtime_identifier())
+            .join("native");
+        fs_err::create_dir_all(&native_dir)?;
+        let library_name = library
+            .file_name()
+            .ok_or_else(|| anyhow::anyhow!("invalid library path {library}"))?;
+        fs_err::copy(library, native_dir.join(library_name))?;
+        Ok(())
+    }
 }
 
 impl uniffi_bindgen::BindingGenerator for BindingGenerator {
@@ -76,23 +191,54 @@ impl uniffi_bindgen::BindingGenerator for BindingGenerator {
         settings: &GenerationSettings,
         components: &[Component<Self::Config>],
     ) -> anyhow::Result<()> {
+        if self.runtime {
+            let Component { ci, config, .. } = components
+                .first()
+                .ok_or_else(|| anyhow::anyhow!("no component to generate the runtime from"))?;
+            let runtime_file = settings
+                .out_dir
+                .join(format!("{}.cs", config.runtime_namespace()));
+            return self.write_bindings_file(&runtime_file, gen_cs::generate_runtime(config, ci)?);
+        }
+
+        if self.api_report {
+            for Component { ci, config, .. } in components {
+                let report_file = settings
+                    .out_dir
+                    .join(format!("{}.api.json", ci.namespace()));
+                println!("Writing API report {report_file}");
+                let report = gen_cs::api::generate_api_report(config, ci)?;
+                fs_err::write(&report_file, serde_json::to_string_pretty(&report)?)?;
+            }
+            return Ok(());
+        }
+
         for Component { ci, config, .. } in components {
-            let bindings_file = settings.out_dir.join(format!("{}.cs", ci.namespace()));
-            println!("Writing bindings file {bindings_file}");
-            let mut f = File::create(&bindings_file)?;
-
-            let mut bindings = generate_bindings(config, ci)?;
-            bindings = gen_cs::formatting::add_header(bindings);
-            write!(f, "{bindings}")?;
-
-            if self.try_format_code {
-                let _ = gen_cs::formatting::format(&bindings_file)
-                    .map_err(|e| println!(
-                        "Warning: Unable to auto-format {} using CSharpier (hint: 'dotnet tool install -g csharpier'): {e:?}",
-                        bindings_file.file_name().unwrap(),
-                    ));
+            let files = if config.split_files() {
+                // Split bindings are written into a directory per component, so that type names
+                // from different components don't clash.
+                let out_dir = settings.out_dir.join(ci.namespace());
+                fs_err::create_dir_all(&out_dir)?;
+                generate_split_bindings(config, ci)?
+                    .into_iter()
+                    .map(|file| (out_dir.join(file.name), file.contents))
+                    .collect()
+            } else {
+                vec![(
+                    settings.out_dir.join(format!("{}.cs", ci.namespace())),
+                    generate_bindings(config, ci)?,
+                )]
+            };
+
+            for (bindings_file, bindings) in files {
+                self.write_bindings_file(&bindings_file, bindings)?;
             }
         }
+
+        // All components of the library end up in a single project, configured by the first one
+        if let (Some(library), Some(component)) = (&self.project_library, components.first()) {
+            self.write_project(&settings.out_dir, component, library)?;
+        }
         Ok(())
     }
 
@@ -114,6 +260,7 @@ impl uniffi_bindgen::BindingGenerator for BindingGenerator {
             });
 
             if !c.config.rename().is_empty() {
+                c.config.rename_config_keys();
                 uniffi_bindgen::interface::rename(&mut c.ci, c.config.rename());
             }
         }
@@ -137,13 +284,16 @@ impl uniffi_bindgen::BindingGenerator for BindingGenerator {
     }
 }
 
-pub fn main() -> Result<()> {
-    let cli = Cli::parse();
-
-    if cli.library_mode {
-        let out_dir = cli
-            .out_dir
-            .expect("--out-dir is required when using --library");
+fn generate(
+    generator: &BindingGenerator,
+    source: &Utf8Path,
+    config: Option<&Utf8Path>,
+    library_mode: bool,
+    crate_name: Option<String>,
+    out_dir: Option<&Utf8Path>,
+) -> Result<()> {
+    if library_mode {
+        let out_dir = out_dir.expect("--out-dir is required when using --library");
 
         let config_supplier = {
             use uniffi_bindgen::cargo_metadata::CrateConfigSupplier;
@@ -151,28 +301,91 @@ pub fn main() -> Result<()> {
         };
 
         uniffi_bindgen::library_mode::generate_bindings(
-            &cli.source,
-            cli.crate_name,
-            &BindingGenerator {
-                try_format_code: !cli.no_format,
-            },
+            source,
+            crate_name,
+            generator,
             &config_supplier,
-            cli.config.as_deref(),
-            &out_dir,
-            !cli.no_format,
+            config,
+            out_dir,
+            generator.try_format_code,
         )
         .map(|_| ())
     } else {
         uniffi_bindgen::generate_external_bindings(
-            &BindingGenerator {
-                try_format_code: !cli.no_format,
-            },
-            &cli.source,
-            cli.config.as_deref(),
-            cli.out_dir.as_deref(),
+            generator,
+            source,
+            config,
+            out_dir,
             None::<&Utf8Path>,
-            cli.crate_name.as_deref(),
-            !cli.no_format,
+            crate_name.as_deref(),
+            generator.try_format_code,
         )
     }
 }
+
+fn read_api_report(path: &Utf8Path) -> Result<gen_cs::api::ApiReport> {
+    let report = fs_err::read_to_string(path)?;
+    serde_json::from_str(&report).map_err(|e| anyhow::anyhow!("invalid API report {path}: {e}"))
+}
+
+pub fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Some(Command::ApiReport {
+            out_dir,
+            config,
+            library_mode,
+            crate_name,
+            source,
+        }) => {
+            return generate(
+                &BindingGenerator {
+                    try_format_code: false,
+                    runtime: false,
+                    project_library: None,
+                    project_manifest: None,
+                    api_report: true,
+                },
+                &source,
+                config.as_deref(),
+                library_mode,
+                crate_name,
+                out_dir.as_deref(),
+            );
+        }
+        Some(Command::ApiDiff { old, new }) => {
+            let changes = gen_cs::api::diff(&read_api_report(&old)?, &read_api_report(&new)?);
+            for change in &changes {
+                println!("{change}");
+            }
+            let breaking = changes.iter().filter(|c| c.breaking).count();
+            if breaking > 0 {
+                anyhow::bail!("{breaking} breaking change(s) between {old} and {new}");
+            }
+            return Ok(());
+        }
+        None => {}
+    }
+
+    // `source` is required unless a subcommand is given, which returned above.
+    let Some(source) = cli.source else {
+        Cli::command()
+            .error(ErrorKind::MissingRequiredArgument, "<SOURCE> is required")
+            .exit();
+    };
+    generate(
+        &BindingGenerator {
+            try_format_code: !cli.no_format,
+            runtime: cli.runtime,
+            project_library: (cli.library_mode && cli.emit_project).then(|| source.clone()),
+            project_manifest: cli.manifest_path,
+            api_report: false,
+        },
+        &source,
+        cli.config.as_deref(),
+        cli.library_mode,
+        cli.crate_name,
+        cli.out_dir.as_deref(),
+    )
+}
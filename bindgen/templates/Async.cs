@@ -3,8 +3,10 @@
 
 {% if self.include_once_check("ConcurrentHandleMap.cs") %}{% include "ConcurrentHandleMap.cs" %}{% endif %}
 
+#if !NET8_0_OR_GREATER
 [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
 delegate void UniFfiFutureCallback(ulong continuationHandle, byte pollResult);
+#endif
 
 internal sealed class UniffiForeignFutureHandle : System.IDisposable {
     internal CancellationTokenSource Cts { get; } = new CancellationTokenSource();
@@ -46,8 +48,14 @@ internal static class _UniFFIAsync {
     // FFI type for Rust future continuations
     internal class UniffiRustFutureContinuationCallback
     {
-        public static UniFfiFutureCallback callback = Callback;
+#if NET8_0_OR_GREATER
+        public static unsafe IntPtr FunctionPointer => (IntPtr)(delegate* unmanaged[Cdecl]<ulong, byte, void>)&Callback;
 
+        [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
+#else
+        public static UniFfiFutureCallback callback = Callback;
+        public static IntPtr FunctionPointer => Marshal.GetFunctionPointerForDelegate(callback);
+#endif
         public static void Callback(ulong continuationHandle, byte pollResult)
         {
             if (_async_handle_map.Remove(continuationHandle, out TaskCompletionSource<byte>? task))
@@ -60,8 +68,14 @@ internal static class _UniFFIAsync {
 
     public class UniffiForeignFutureDroppedCallbackImpl
     {
-        public static _UniFFILib.UniffiForeignFutureDroppedCallback callback = Callback;
+#if NET8_0_OR_GREATER
+        public static unsafe IntPtr FunctionPointer => (IntPtr)(delegate* unmanaged[Cdecl]<ulong, void>)&Callback;
 
+        [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
+#else
+        public static _UniFFILib.UniffiForeignFutureDroppedCallback callback = Callback;
+        public static IntPtr FunctionPointer => Marshal.GetFunctionPointerForDelegate(callback);
+#endif
         public static void Callback(ulong handle)
         {
             if (_foreign_futures_map.Remove(handle, out UniffiForeignFutureHandle? futureHandle) && futureHandle is not null) {
@@ -74,18 +88,31 @@ internal static class _UniFFIAsync {
 
     public delegate void CompleteActionDelegate(ulong handle, ref UniffiRustCallStatus status);
 
-    private static async Task PollFuture(ulong rustFuture, Action<ulong, IntPtr, ulong> pollFunc)
+    private static async Task PollFuture(
+        ulong rustFuture,
+        Action<ulong, IntPtr, ulong> pollFunc,
+        Action<ulong> cancelFunc,
+        CancellationToken cancellationToken
+    )
     {
-        byte pollResult;
-        do
+        // Cancelling the Rust future wakes up the pending continuation, so the loop always
+        // terminates. Disposing the registration waits for a running cancel callback, so the
+        // future can't be cancelled after it was freed.
+        using (cancellationToken.Register(() => cancelFunc(rustFuture)))
         {
-            var tcs = new TaskCompletionSource<byte>(TaskCreationOptions.RunContinuationsAsynchronously);
-            IntPtr callback = Marshal.GetFunctionPointerForDelegate(UniffiRustFutureContinuationCallback.callback);
-            ulong mapEntry = _async_handle_map.Insert(tcs);
-            pollFunc(rustFuture, callback, mapEntry);
-            pollResult = await tcs.Task;
+            byte pollResult;
+            do
+            {
+                var tcs = new TaskCompletionSource<byte>(TaskCreationOptions.RunContinuationsAsynchronously);
+                IntPtr callback = UniffiRustFutureContinuationCallback.FunctionPointer;
+                ulong mapEntry = _async_handle_map.Insert(tcs);
+                pollFunc(rustFuture, callback, mapEntry);
+                pollResult = await tcs.Task.ConfigureAwait(false);
+            }
+            while(pollResult != UNIFFI_RUST_FUTURE_POLL_READY);
         }
-        while(pollResult != UNIFFI_RUST_FUTURE_POLL_READY);
+        // A cancelled future completes with a "cancelled" call status, don't try to complete it
+        cancellationToken.ThrowIfCancellationRequested();
     }
 
     public static async Task<T> UniffiRustCallAsync<T, F, E>(
@@ -93,12 +120,14 @@ internal static class _UniFFIAsync {
         Action<ulong, IntPtr, ulong> pollFunc,
         CompleteFuncDelegate<F> completeFunc,
         Action<ulong> freeFunc,
+        Action<ulong> cancelFunc,
         Func<F, T> liftFunc,
-        CallStatusErrorHandler<E> errorHandler
+        CallStatusErrorHandler<E> errorHandler,
+        CancellationToken cancellationToken
     ) where E : System.Exception
     {
         try {
-            await PollFuture(rustFuture, pollFunc);
+            await PollFuture(rustFuture, pollFunc, cancelFunc, cancellationToken).ConfigureAwait(false);
             var result = _UniffiHelpers.RustCallWithError(errorHandler, (ref UniffiRustCallStatus status) => completeFunc(rustFuture, ref status));
             return liftFunc(result);
         }
@@ -113,11 +142,13 @@ internal static class _UniFFIAsync {
         Action<ulong, IntPtr, ulong> pollFunc,
         CompleteActionDelegate completeFunc,
         Action<ulong> freeFunc,
-        CallStatusErrorHandler<E> errorHandler
+        Action<ulong> cancelFunc,
+        CallStatusErrorHandler<E> errorHandler,
+        CancellationToken cancellationToken
     ) where E : System.Exception
     {
          try {
-            await PollFuture(rustFuture, pollFunc);
+            await PollFuture(rustFuture, pollFunc, cancelFunc, cancellationToken).ConfigureAwait(false);
             _UniffiHelpers.RustCallWithError(errorHandler, (ref UniffiRustCallStatus status) => completeFunc(rustFuture, ref status));
 
         }
@@ -126,4 +157,38 @@ internal static class _UniFFIAsync {
             freeFunc(rustFuture);
         }
     }
+
+    // Synchronous counterparts of `UniffiRustCallAsync`, used by `*Blocking` functions. All awaits
+    // above use `ConfigureAwait(false)`, so blocking a thread with a `SynchronizationContext`
+    // (e.g. a UI thread) doesn't deadlock.
+    public static T UniffiRustCallBlocking<T, F, E>(
+        ulong rustFuture,
+        Action<ulong, IntPtr, ulong> pollFunc,
+        CompleteFuncDelegate<F> completeFunc,
+        Action<ulong> freeFunc,
+        Action<ulong> cancelFunc,
+        Func<F, T> liftFunc,
+        CallStatusErrorHandler<E> errorHandler,
+        CancellationToken cancellationToken
+    ) where E : System.Exception
+    {
+        return UniffiRustCallAsync(rustFuture, pollFunc, completeFunc, freeFunc, cancelFunc, liftFunc, errorHandler, cancellationToken)
+            .GetAwaiter()
+            .GetResult();
+    }
+
+    public static void UniffiRustCallBlocking<E>(
+        ulong rustFuture,
+        Action<ulong, IntPtr, ulong> pollFunc,
+        CompleteActionDelegate completeFunc,
+        Action<ulong> freeFunc,
+        Action<ulong> cancelFunc,
+        CallStatusErrorHandler<E> errorHandler,
+        CancellationToken cancellationToken
+    ) where E : System.Exception
+    {
+        UniffiRustCallAsync(rustFuture, pollFunc, completeFunc, freeFunc, cancelFunc, errorHandler, cancellationToken)
+            .GetAwaiter()
+            .GetResult();
+    }
 }
@@ -5,171 +5,442 @@
 // Big endian streams are not yet available in dotnet :'(
 // https://github.com/dotnet/runtime/issues/26904
 
-class StreamUnderflowException: System.Exception {
+{{ config.runtime_access_modifier() }} class StreamUnderflowException: System.Exception {
     public StreamUnderflowException() {
     }
 }
 
-static class BigEndianStreamExtensions
-{
-    public static void WriteInt32(this Stream stream, int value, int bytesToWrite = 4)
-    {
-#if NET8_0_OR_GREATER
-        Span<byte> buffer = stackalloc byte[bytesToWrite];
-#else
-        byte[] buffer = new byte[bytesToWrite];
-#endif
-        var posByte = bytesToWrite;
-        while (posByte != 0)
-        {
-            posByte--;
-            buffer[posByte] = (byte)(value);
-            value >>= 8;
+{{ config.runtime_access_modifier() }} class StreamOverflowException: System.Exception {
+    public StreamOverflowException() {
+    }
+}
+
+// Streams over a `RustBuffer` read and write its memory directly, without allocating. Streams
+// over a `Stream` are only used by tests and by external types generated with older versions,
+// see `InnerStream`.
+{{ config.runtime_access_modifier() }} class BigEndianStream {
+    // Longest .NET array, in items, and string, in UTF-16 code units. `Array.MaxLength` isn't
+    // available before .NET 6.
+    public const int MaxArrayLength = 0x7FFFFFC7;
+    public const int MaxStringLength = 0x3FFFFFDF;
+
+    // Strings over 2 GiB are encoded and decoded in chunks, because `Encoding` counts with `int`.
+    const int Utf8ChunkLength = 1 << 28;
+
+    readonly Stream? stream;
+    IntPtr data;
+    long length;
+    long position;
+
+    [ThreadStatic]
+    static BigEndianStream? pooled;
+
+    public BigEndianStream(Stream stream) {
+        this.stream = stream;
+    }
+
+    public BigEndianStream(IntPtr data, long length) {
+        this.data = data;
+        this.length = length;
+    }
+
+    // Take the stream cached for this thread, or allocate a new one when it's in use. Rust calls
+    // can be nested (e.g. custom type converters calling into Rust), so the cached stream is
+    // removed from the cache until it's returned with `Return()`.
+    public static BigEndianStream Rent(IntPtr data, long length) {
+        var rented = pooled;
+        if (rented == null) {
+            return new BigEndianStream(data, length);
         }
+        pooled = null;
+        return rented.Reset(data, length);
+    }
 
-#if NET8_0_OR_GREATER
-        stream.Write(buffer);
-#else
-        stream.Write(buffer, 0, buffer.Length);
-#endif
+    public void Return() {
+        if (stream == null) {
+            pooled = this;
+        }
     }
 
-    public static void WriteInt64(this Stream stream, long value)
-    {
-        int bytesToWrite = 8;
-#if NET8_0_OR_GREATER
-         Span<byte> buffer = stackalloc byte[bytesToWrite];
- #else
-         byte[] buffer = new byte[bytesToWrite];
- #endif
-        var posByte = bytesToWrite;
-        while (posByte != 0)
-        {
-            posByte--;
-            buffer[posByte] = (byte)(value);
-            value >>= 8;
+    BigEndianStream Reset(IntPtr data, long length) {
+        this.data = data;
+        this.length = length;
+        position = 0;
+        return this;
+    }
+
+    public Stream InnerStream => stream ?? new BigEndianInnerStream(this);
+
+    public bool HasRemaining() {
+        return (Length - Position) > 0;
+    }
+
+    long Length => stream?.Length ?? length;
+
+    public long Position {
+        get => stream?.Position ?? position;
+        set {
+            if (stream != null) {
+                stream.Position = value;
+            } else {
+                position = value;
+            }
+        }
+    }
+
+    // Pointer to the next `count` bytes, advancing the position past them.
+    unsafe byte* Advance(int count, bool write) {
+        if (length - position < count) {
+            if (write) {
+                throw new StreamOverflowException();
+            }
+            throw new StreamUnderflowException();
+        }
+        var result = (byte*)data.ToPointer() + position;
+        position += count;
+        return result;
+    }
+
+    public void WriteBytes(byte[] buffer) {
+        if (stream != null) {
+            stream.Write(buffer, 0, buffer.Length);
+            return;
+        }
+        unsafe {
+            var destination = Advance(buffer.Length, write: true);
+            fixed (byte* source = buffer) {
+                System.Buffer.MemoryCopy(source, destination, buffer.Length, buffer.Length);
+            }
         }
+    }
 
+    // Write a length prefixed UTF-8 string.
+    public void WriteString(string value) {
+        var byteCount = Utf8ByteCount(value);
+        if (byteCount > int.MaxValue) {
+            throw new UniffiSizeLimitException(
+                $"String of {byteCount} UTF-8 bytes is longer than the {int.MaxValue} bytes of its length prefix",
+                (ulong)byteCount,
+                int.MaxValue
+            );
+        }
+        if (stream != null) {
+            var bytes = System.Text.Encoding.UTF8.GetBytes(value);
+            WriteInt(bytes.Length);
+            WriteBytes(bytes);
+            return;
+        }
+        unsafe {
+            WriteInt((int)byteCount);
+            EncodeUtf8(value, Advance((int)byteCount, write: true), byteCount);
+        }
+    }
+
+    public void WriteByte(byte value) {
+        if (stream != null) {
+            stream.WriteByte(value);
+            return;
+        }
+        unsafe {
+            *Advance(1, write: true) = value;
+        }
+    }
+
+    public void WriteSByte(sbyte value) => WriteByte((byte)value);
+
+    public void WriteUShort(ushort value) {
+        if (stream != null) {
+            WriteSlow(value, 2);
+            return;
+        }
+        unsafe {
 #if NET8_0_OR_GREATER
-        stream.Write(buffer);
+            System.Buffers.Binary.BinaryPrimitives.WriteUInt16BigEndian(new Span<byte>(Advance(2, write: true), 2), value);
 #else
-        stream.Write(buffer, 0, buffer.Length);
+            var destination = Advance(2, write: true);
+            destination[0] = (byte)(value >> 8);
+            destination[1] = (byte)value;
 #endif
+        }
     }
 
-    public static uint ReadUint32(this Stream stream, int bytesToRead = 4) {
-        CheckRemaining(stream, bytesToRead);
+    public void WriteShort(short value) => WriteUShort((ushort)value);
+
+    public void WriteUInt(uint value) {
+        if (stream != null) {
+            WriteSlow(value, 4);
+            return;
+        }
+        unsafe {
 #if NET8_0_OR_GREATER
-         Span<byte> buffer = stackalloc byte[bytesToRead];
-         stream.Read(buffer);
+            System.Buffers.Binary.BinaryPrimitives.WriteUInt32BigEndian(new Span<byte>(Advance(4, write: true), 4), value);
 #else
-        byte[] buffer = new byte[bytesToRead];
-        stream.Read(buffer, 0, bytesToRead);
+            var destination = Advance(4, write: true);
+            destination[0] = (byte)(value >> 24);
+            destination[1] = (byte)(value >> 16);
+            destination[2] = (byte)(value >> 8);
+            destination[3] = (byte)value;
 #endif
-        uint result = 0;
-        uint digitMultiplier = 1;
-        int posByte = bytesToRead;
-        while (posByte != 0)
-        {
-            posByte--;
-            result |= buffer[posByte]*digitMultiplier;
-            digitMultiplier <<= 8;
         }
-
-        return result;
     }
 
-    public static ulong ReadUInt64(this Stream stream) {
-        int bytesToRead = 8;
-        CheckRemaining(stream, bytesToRead);
+    public void WriteInt(int value) => WriteUInt((uint)value);
+
+    public void WriteULong(ulong value) {
+        if (stream != null) {
+            WriteSlow(value, 8);
+            return;
+        }
+        unsafe {
 #if NET8_0_OR_GREATER
-         Span<byte> buffer = stackalloc byte[bytesToRead];
-         stream.Read(buffer);
+            System.Buffers.Binary.BinaryPrimitives.WriteUInt64BigEndian(new Span<byte>(Advance(8, write: true), 8), value);
 #else
-        byte[] buffer = new byte[bytesToRead];
-        stream.Read(buffer, 0, bytesToRead);
+            var destination = Advance(8, write: true);
+            for (var i = 7; i >= 0; i--) {
+                destination[i] = (byte)value;
+                value >>= 8;
+            }
 #endif
-        ulong result = 0;
-        ulong digitMultiplier = 1;
-        int posByte = bytesToRead;
-        while (posByte != 0)
-        {
-            posByte--;
-            result |= buffer[posByte]*digitMultiplier;
-            digitMultiplier <<= 8;
         }
+    }
 
-        return result;
+    public void WriteLong(long value) => WriteULong((ulong)value);
+
+    public void WriteFloat(float value) {
+        unsafe {
+            WriteInt(*((int*)&value));
+        }
     }
+    public void WriteDouble(double value) => WriteLong(BitConverter.DoubleToInt64Bits(value));
 
-    public static void CheckRemaining(this Stream stream, int length) {
-        if (stream.Length - stream.Position < length) {
-            throw new StreamUnderflowException();
+    void WriteSlow(ulong value, int bytesToWrite) {
+        for (var i = bytesToWrite - 1; i >= 0; i--) {
+            stream!.WriteByte((byte)(value >> (i * 8)));
         }
     }
 
-    public static void ForEach<T>(this T[] items, Action<T> action){
-        foreach (var item in items) {
-            action(item);
+    public byte[] ReadBytes(int length) {
+        if (length < 0 || length > MaxArrayLength) {
+            throw new UniffiSizeLimitException(
+                $"Length of {(uint)length} bytes is more than the {MaxArrayLength} bytes an array can hold",
+                (uint)length,
+                MaxArrayLength
+            );
+        }
+        if (stream != null) {
+            CheckRemaining(length);
+            byte[] result = new byte[length];
+            stream.Read(result, 0, length);
+            return result;
+        }
+        unsafe {
+            var source = Advance(length, write: false);
+            var result = new byte[length];
+            fixed (byte* destination = result) {
+                System.Buffer.MemoryCopy(source, destination, length, length);
+            }
+            return result;
         }
     }
-}
 
-class BigEndianStream {
-    Stream stream;
-    public BigEndianStream(Stream stream) {
-        this.stream = stream;
+    // Read a length prefixed UTF-8 string.
+    public string ReadString() {
+        var length = ReadLength();
+        if (length == 0) {
+            return "";
+        }
+        if (stream != null) {
+            return System.Text.Encoding.UTF8.GetString(ReadBytes(length));
+        }
+        unsafe {
+            return DecodeUtf8(Advance(length, write: false), length);
+        }
     }
 
-    public Stream InnerStream => stream;
+    // Read the length prefix of a byte array, sequence or map, which must fit in an array.
+    public int ReadLength() {
+        var length = ReadUInt();
+        if (length > MaxArrayLength) {
+            throw new UniffiSizeLimitException(
+                $"Length of {length} items is more than the {MaxArrayLength} items an array can hold",
+                length,
+                MaxArrayLength
+            );
+        }
+        return (int)length;
+    }
 
-    public bool HasRemaining() {
-        return (stream.Length - Position) > 0;
+    // Number of UTF-8 bytes of `value`, which can be more than `int.MaxValue`.
+    public static long Utf8ByteCount(string value) {
+        return Utf8ByteCount(value, Utf8ChunkLength);
     }
 
-    public long Position {
-        get => stream.Position;
-        set => stream.Position = value;
+    // Encode `value` into `destination`, which has room for the `byteCount` from `Utf8ByteCount`.
+    public static unsafe void EncodeUtf8(string value, byte* destination, long byteCount) {
+        EncodeUtf8(value, destination, byteCount, Utf8ChunkLength);
     }
 
-    public void WriteBytes(byte[] buffer) {
-#if NET8_0_OR_GREATER
-        stream.Write(buffer);
-#else
-        stream.Write(buffer, 0, buffer.Length);
-#endif
+    // Decode `length` UTF-8 bytes, which can be more than `int.MaxValue` when they aren't length
+    // prefixed, as long as the string fits in a .NET string.
+    public static unsafe string DecodeUtf8(byte* bytes, long length) {
+        return DecodeUtf8(bytes, length, Utf8ChunkLength);
     }
 
-    public void WriteByte(byte value) => stream.WriteInt32(value, bytesToWrite: 1);
-    public void WriteSByte(sbyte value) => stream.WriteInt32(value, bytesToWrite: 1);
+    // The chunked implementations take the chunk length, so tests can cross chunk boundaries with
+    // short strings. It must be at least 4, so that a chunk can hold any UTF-8 sequence or
+    // surrogate pair.
+    internal static long Utf8ByteCount(string value, int chunkLength) {
+        // UTF-16 code units take at most 3 bytes in UTF-8, so a chunk can't overflow `int`.
+        if (value.Length <= chunkLength) {
+            return System.Text.Encoding.UTF8.GetByteCount(value);
+        }
+        long byteCount = 0;
+        unsafe {
+            fixed (char* chars = value) {
+                for (var offset = 0; offset < value.Length;) {
+                    var count = CharChunk(chars, value.Length, offset, chunkLength);
+                    byteCount += System.Text.Encoding.UTF8.GetByteCount(chars + offset, count);
+                    offset += count;
+                }
+            }
+        }
+        return byteCount;
+    }
 
-    public void WriteUShort(ushort value) => stream.WriteInt32(value, bytesToWrite: 2);
-    public void WriteShort(short value) => stream.WriteInt32(value, bytesToWrite: 2);
+    internal static unsafe void EncodeUtf8(string value, byte* destination, long byteCount, int chunkLength) {
+        fixed (char* chars = value) {
+            if (byteCount <= chunkLength) {
+                System.Text.Encoding.UTF8.GetBytes(chars, value.Length, destination, (int)byteCount);
+                return;
+            }
+            for (var offset = 0; offset < value.Length;) {
+                var count = CharChunk(chars, value.Length, offset, chunkLength);
+                var written = System.Text.Encoding.UTF8.GetBytes(chars + offset, count, destination, (int)Math.Min(3L * count, byteCount));
+                destination += written;
+                byteCount -= written;
+                offset += count;
+            }
+        }
+    }
 
-    public void WriteUInt(uint value) => stream.WriteInt32((int)value);
-    public void WriteInt(int value) => stream.WriteInt32(value);
+    internal static unsafe string DecodeUtf8(byte* bytes, long length, int chunkLength) {
+        // Strings have at most as many UTF-16 code units as UTF-8 bytes, so a chunk fits a string.
+        if (length <= chunkLength) {
+            return System.Text.Encoding.UTF8.GetString(bytes, (int)length);
+        }
+        long charCount = 0;
+        for (long offset = 0; offset < length;) {
+            var count = ByteChunk(bytes, length, offset, chunkLength);
+            charCount += System.Text.Encoding.UTF8.GetCharCount(bytes + offset, count);
+            offset += count;
+        }
+        if (charCount > MaxStringLength) {
+            throw new UniffiSizeLimitException(
+                $"String of {length} UTF-8 bytes has {charCount} UTF-16 code units, more than the {MaxStringLength} a string can hold",
+                (ulong)charCount,
+                MaxStringLength
+            );
+        }
+        var result = new char[charCount];
+        fixed (char* destination = result) {
+            long written = 0;
+            for (long offset = 0; offset < length;) {
+                var count = ByteChunk(bytes, length, offset, chunkLength);
+                written += System.Text.Encoding.UTF8.GetChars(bytes + offset, count, destination + written, (int)Math.Min(charCount - written, int.MaxValue));
+                offset += count;
+            }
+        }
+        return new string(result);
+    }
 
-    public void WriteULong(ulong value) => stream.WriteInt64((long)value);
-    public void WriteLong(long value) => stream.WriteInt64(value);
+    // Up to `chunkLength` chars from `offset`, without splitting a surrogate pair.
+    static unsafe int CharChunk(char* chars, int length, int offset, int chunkLength) {
+        var count = Math.Min(chunkLength, length - offset);
+        if (offset + count < length && char.IsHighSurrogate(chars[offset + count - 1])) {
+            count -= 1;
+        }
+        return count;
+    }
 
-    public void WriteFloat(float value) {
+    // Up to `chunkLength` bytes from `offset`, without splitting a UTF-8 sequence.
+    static unsafe int ByteChunk(byte* bytes, long length, long offset, int chunkLength) {
+        var count = (int)Math.Min(chunkLength, length - offset);
+        // Continuation bytes are 0b10xxxxxx, and sequences have at most 3 of them.
+        for (var i = 0; i < 3 && offset + count < length && (bytes[offset + count] & 0xC0) == 0x80; i++) {
+            count -= 1;
+        }
+        return count;
+    }
+
+    public byte ReadByte() {
+        if (stream != null) {
+            return (byte)ReadSlow(1);
+        }
         unsafe {
-            WriteInt(*((int*)&value));
+            return *Advance(1, write: false);
         }
     }
-    public void WriteDouble(double value) => stream.WriteInt64(BitConverter.DoubleToInt64Bits(value));
 
-    public byte[] ReadBytes(int length) {
-        stream.CheckRemaining(length);
-        byte[] result = new byte[length];
-        stream.Read(result, 0, length);
+    public ushort ReadUShort() {
+        if (stream != null) {
+            return (ushort)ReadSlow(2);
+        }
+        unsafe {
+#if NET8_0_OR_GREATER
+            return System.Buffers.Binary.BinaryPrimitives.ReadUInt16BigEndian(new ReadOnlySpan<byte>(Advance(2, write: false), 2));
+#else
+            var source = Advance(2, write: false);
+            return (ushort)((source[0] << 8) | source[1]);
+#endif
+        }
+    }
+
+    public uint ReadUInt() {
+        if (stream != null) {
+            return (uint)ReadSlow(4);
+        }
+        unsafe {
+#if NET8_0_OR_GREATER
+            return System.Buffers.Binary.BinaryPrimitives.ReadUInt32BigEndian(new ReadOnlySpan<byte>(Advance(4, write: false), 4));
+#else
+            var source = Advance(4, write: false);
+            return ((uint)source[0] << 24) | ((uint)source[1] << 16) | ((uint)source[2] << 8) | source[3];
+#endif
+        }
+    }
+
+    public ulong ReadULong() {
+        if (stream != null) {
+            return ReadSlow(8);
+        }
+        unsafe {
+#if NET8_0_OR_GREATER
+            return System.Buffers.Binary.BinaryPrimitives.ReadUInt64BigEndian(new ReadOnlySpan<byte>(Advance(8, write: false), 8));
+#else
+            var source = Advance(8, write: false);
+            ulong result = 0;
+            for (var i = 0; i < 8; i++) {
+                result = (result << 8) | source[i];
+            }
+            return result;
+#endif
+        }
+    }
+
+    ulong ReadSlow(int bytesToRead) {
+        CheckRemaining(bytesToRead);
+        ulong result = 0;
+        for (var i = 0; i < bytesToRead; i++) {
+            result = (result << 8) | (byte)stream!.ReadByte();
+        }
         return result;
     }
 
-    public byte ReadByte() => (byte)stream.ReadUint32(bytesToRead: 1);
-    public ushort ReadUShort() => (ushort)stream.ReadUint32(bytesToRead: 2);
-    public uint ReadUInt() => (uint)stream.ReadUint32(bytesToRead: 4);
-    public ulong ReadULong() => stream.ReadUInt64();
+    void CheckRemaining(int length) {
+        if (Length - Position < length) {
+            throw new StreamUnderflowException();
+        }
+    }
 
     public sbyte ReadSByte() => (sbyte)ReadByte();
     public short ReadShort() => (short)ReadUShort();
@@ -184,4 +455,81 @@ class BigEndianStream {
 
     public long ReadLong() => (long)ReadULong();
     public double ReadDouble() => BitConverter.Int64BitsToDouble(ReadLong());
+
+    // Adapts a stream over a `RustBuffer` to `Stream`, sharing its position. External types
+    // generated by other packages have their own `BigEndianStream`, which wraps this.
+    sealed class BigEndianInnerStream: Stream {
+        readonly BigEndianStream owner;
+
+        public BigEndianInnerStream(BigEndianStream owner) {
+            this.owner = owner;
+        }
+
+        public override bool CanRead => true;
+        public override bool CanSeek => true;
+        public override bool CanWrite => true;
+        public override long Length => owner.length;
+
+        public override long Position {
+            get => owner.position;
+            set => owner.position = value;
+        }
+
+        public override int Read(byte[] buffer, int offset, int count) {
+            count = (int)Math.Min(count, owner.length - owner.position);
+            if (count <= 0) {
+                return 0;
+            }
+            unsafe {
+                var source = owner.Advance(count, write: false);
+                fixed (byte* destination = &buffer[offset]) {
+                    System.Buffer.MemoryCopy(source, destination, count, count);
+                }
+            }
+            return count;
+        }
+
+        public override int ReadByte() {
+            if (owner.position >= owner.length) {
+                return -1;
+            }
+            unsafe {
+                return *owner.Advance(1, write: false);
+            }
+        }
+
+        public override void WriteByte(byte value) {
+            unsafe {
+                *owner.Advance(1, write: true) = value;
+            }
+        }
+
+        public override void Write(byte[] buffer, int offset, int count) {
+            if (count == 0) {
+                return;
+            }
+            unsafe {
+                var destination = owner.Advance(count, write: true);
+                fixed (byte* source = &buffer[offset]) {
+                    System.Buffer.MemoryCopy(source, destination, count, count);
+                }
+            }
+        }
+
+        public override long Seek(long offset, SeekOrigin origin) {
+            owner.position = origin switch {
+                SeekOrigin.Begin => offset,
+                SeekOrigin.Current => owner.position + offset,
+                _ => owner.length + offset,
+            };
+            return owner.position;
+        }
+
+        public override void Flush() {
+        }
+
+        public override void SetLength(long value) {
+            throw new NotSupportedException();
+        }
+    }
 }
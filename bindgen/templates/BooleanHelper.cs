@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
 
-class FfiConverterBoolean: FfiConverter<bool, sbyte> {
+{{ config.runtime_access_modifier() }} class FfiConverterBoolean: FfiConverter<bool, sbyte> {
     public static FfiConverterBoolean INSTANCE = new FfiConverterBoolean();
 
     public override bool Lift(sbyte value) {
@@ -17,7 +17,7 @@ class FfiConverterBoolean: FfiConverter<bool, sbyte> {
         return value ? (sbyte)1 : (sbyte)0;
     }
 
-    public override int AllocationSize(bool value) {
+    public override long AllocationSize(bool value) {
         return (sbyte)1;
     }
 
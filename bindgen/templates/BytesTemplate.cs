@@ -2,16 +2,54 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
 
-class {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
+{{ config.runtime_access_modifier() }} class {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
     public static {{ ffi_converter_name }} INSTANCE = new {{ ffi_converter_name }}();
 
+    // Note: `bytes` arguments can't be borrowed by Rust, the scaffolding always takes ownership of
+    // a `RustBuffer`. Lowering copies the bytes straight into the `RustBuffer` instead of going
+    // through a `BigEndianStream`, so the only copy is the one Rust requires.
+    public override RustBuffer Lower({{ type_name }} value) {
+        var rbuf = RustBuffer.Alloc(4 + value.Length);
+        unsafe {
+            fixed (byte* source = value) {
+                WriteInto(rbuf, source, value.Length);
+            }
+        }
+        return rbuf;
+    }
+
+#if NET8_0_OR_GREATER
+    public RustBuffer Lower(ReadOnlySpan<byte> value) {
+        var rbuf = RustBuffer.Alloc(4L + value.Length);
+        unsafe {
+            fixed (byte* source = value) {
+                WriteInto(rbuf, source, value.Length);
+            }
+        }
+        return rbuf;
+    }
+
+    public RustBuffer Lower(ReadOnlyMemory<byte> value) {
+        return Lower(value.Span);
+    }
+#endif
+
+    static unsafe void WriteInto(RustBuffer rbuf, byte* source, int length) {
+        var destination = (byte*)rbuf.data.ToPointer();
+        destination[0] = (byte)(length >> 24);
+        destination[1] = (byte)(length >> 16);
+        destination[2] = (byte)(length >> 8);
+        destination[3] = (byte)length;
+        System.Buffer.MemoryCopy(source, destination + 4, length, length);
+    }
+
     public override {{ type_name }} Read(BigEndianStream stream) {
-        var length = stream.ReadInt();
+        var length = stream.ReadLength();
         return stream.ReadBytes(length);
     }
 
-    public override int AllocationSize({{ type_name }} value) {
-        return 4 + value.Length;
+    public override long AllocationSize({{ type_name }} value) {
+        return 4L + value.Length;
     }
 
     public override void Write({{ type_name }} value, BigEndianStream stream) {
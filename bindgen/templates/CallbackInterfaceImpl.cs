@@ -31,7 +31,7 @@ class {{ callback_impl_name }} {
         }
         {%- match meth.throws_type() %}
         {%- when Some with (error_type) %}
-        catch ({{ error_type|type_name(ci) }} e) {
+        catch ({{ error_type|type_name(ci, config) }} e) {
             try {
                 _uniffi_out_err.code = UniffiCallbackResponseStatus.ERROR;
                 _uniffi_out_err.error_buf = {{ error_type|ffi_converter_name }}.INSTANCE.Lower(e);
@@ -55,7 +55,7 @@ class {{ callback_impl_name }} {
         var foreignHandle = _UniFFIAsync._foreign_futures_map.Insert(futureHandle);
         unsafe {
             (*(_UniFFILib.UniffiForeignFutureDroppedCallbackStruct*)@uniffiOutDroppedCallback).handle = foreignHandle;
-            (*(_UniFFILib.UniffiForeignFutureDroppedCallbackStruct*)@uniffiOutDroppedCallback).free = Marshal.GetFunctionPointerForDelegate(_UniFFIAsync.UniffiForeignFutureDroppedCallbackImpl.callback);
+            (*(_UniFFILib.UniffiForeignFutureDroppedCallbackStruct*)@uniffiOutDroppedCallback).free = _UniFFIAsync.UniffiForeignFutureDroppedCallbackImpl.FunctionPointer;
         }
         if (!{{ ffi_converter_var }}.handleMap.TryGet(handle, out var uniffiObject)) {
             var ret = new _UniFFILib.{{ meth.foreign_future_ffi_result_struct().name()|ffi_struct_name }}();
@@ -64,6 +64,13 @@ class {{ callback_impl_name }} {
             try {
                 ret.@callStatus.error_buf = FfiConverterString.INSTANCE.Lower($"No callback in handlemap '{handle}'");
             } catch { }
+#if NET8_0_OR_GREATER
+            futureHandle.InvokeCallbackOnce(() => {
+                unsafe {
+                    ((delegate* unmanaged[Cdecl]<ulong, _UniFFILib.{{ meth.foreign_future_ffi_result_struct().name()|ffi_struct_name }}, void>)@uniffiFutureCallback)(@uniffiCallbackData, ret);
+                }
+            });
+#else
             {%- match meth.return_type() %}
             {%- when Some with (return_type) %}
             {%- let complete_fn_type = return_type|ffi_foreign_future_complete %}
@@ -72,6 +79,7 @@ class {{ callback_impl_name }} {
             var earlyCb = Marshal.GetDelegateForFunctionPointer<_UniFFILib.UniffiForeignFutureCompleteVoid>(@uniffiFutureCallback);
             {%- endmatch %}
             futureHandle.InvokeCallbackOnce(() => { earlyCb(@uniffiCallbackData, ret); });
+#endif
             futureHandle.Dispose();
             return;
         }
@@ -91,10 +99,13 @@ class {{ callback_impl_name }} {
             {%- when None %}
             {%- endmatch %}
 
-            await uniffiObject.{{ meth.name()|fn_name }}(
+            await uniffiObject.{{ meth.name()|fn_name|async_name(meth.is_async(), config) }}(
                 {%- for arg in meth.arguments() %}
                 {{ arg|lift_fn }}({{ arg.name()|var_name }}){%- if !loop.last %}, {% endif -%}
                 {%- endfor %})
+            {%- if config.async_type_name() == "ValueTask" %}
+                .AsTask()
+            {%- endif %}
             #if NET6_0_OR_GREATER
                 .WaitAsync(futureHandle.Cts.Token)
             #endif
@@ -111,7 +122,7 @@ class {{ callback_impl_name }} {
             ret.@callStatus.code = UniffiCallbackResponseStatus.SUCCESS;
             {%- match meth.throws_type() %}
             {%- when Some with (error_type) %}
-            } catch ({{ error_type|type_name(ci) }} e) {
+            } catch ({{ error_type|type_name(ci, config) }} e) {
                 try {
                     ret.@callStatus.code = UniffiCallbackResponseStatus.ERROR;
                     ret.@callStatus.error_buf = {{ error_type|ffi_converter_name }}.INSTANCE.Lower(e);
@@ -147,7 +158,14 @@ class {{ callback_impl_name }} {
             }
             {%- endmatch %}
 
-            {% match meth.return_type() %}
+#if NET8_0_OR_GREATER
+            futureHandle.InvokeCallbackOnce(() => {
+                unsafe {
+                    ((delegate* unmanaged[Cdecl]<ulong, _UniFFILib.{{ meth.foreign_future_ffi_result_struct().name()|ffi_struct_name }}, void>)@uniffiFutureCallback)(@uniffiCallbackData, ret);
+                }
+            });
+#else
+            {%- match meth.return_type() %}
             {%- when Some with (return_type) %}
             {%- let complete_fn_type = return_type|ffi_foreign_future_complete %}
             var cb = Marshal.GetDelegateForFunctionPointer<_UniFFILib.{{ complete_fn_type }}>(@uniffiFutureCallback);
@@ -157,6 +175,7 @@ class {{ callback_impl_name }} {
             futureHandle.InvokeCallbackOnce(() => {
                 cb(@uniffiCallbackData, ret);
             });
+#endif
             } finally {
                 futureHandle.Dispose();
             }
@@ -180,6 +199,20 @@ class {{ callback_impl_name }} {
         }
     }
 
+#if NET8_0_OR_GREATER
+    {%- for (ffi_callback, meth) in vtable_methods.iter() %}
+    [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
+    static unsafe {% call cs::ffi_return_type(ffi_callback) %} {{ meth.name()|fn_name }}Unmanaged({% call cs::arg_list_ffi_unmanaged_decl(ffi_callback) %}) {
+        {% if ffi_callback.return_type().is_some() %}return {% endif %}{{ meth.name()|fn_name }}({% call cs::arg_list_ffi_unmanaged_forward(ffi_callback) %});
+    }
+    {%- endfor %}
+
+    [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
+    static void UniffiFreeUnmanaged(ulong @handle) => UniffiFree(@handle);
+
+    [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
+    static ulong UniffiCloneUnmanaged(ulong @handle) => UniffiClone(@handle);
+#else
     {%- for (ffi_callback, meth) in vtable_methods.iter() %}
     {%- let fn_type = format!("_UniFFILib.{}Method", callback_impl_name) %}
     static {{ fn_type }}{{ loop.index0 }} _m{{ loop.index0 }} = new {{ fn_type }}{{ loop.index0 }}({{ meth.name()|fn_name }});
@@ -187,17 +220,26 @@ class {{ callback_impl_name }} {
     static _UniFFILib.UniffiCallbackInterfaceFree _callback_interface_free = new _UniFFILib.UniffiCallbackInterfaceFree(UniffiFree);
     static _UniFFILib.UniffiCallbackInterfaceClone _callback_interface_clone = new _UniFFILib.UniffiCallbackInterfaceClone(UniffiClone);
 
+#endif
+
     private static GCHandle? _vtablePin;
 
-    public static void Register() {
+    public static unsafe void Register() {
         if (_vtablePin.HasValue) return;
         _UniFFILib.{{ vtable|ffi_type_name }} _vtable = new _UniFFILib.{{ vtable|ffi_type_name }} {
+#if NET8_0_OR_GREATER
+            {%- for (ffi_callback, meth) in vtable_methods.iter() %}
+            {{ meth.name()|var_name() }} = (IntPtr)({% call cs::ffi_fn_ptr_type(ffi_callback) %})&{{ meth.name()|fn_name }}Unmanaged,
+            {%- endfor %}
+            @uniffiFree = (IntPtr)(delegate* unmanaged[Cdecl]<ulong, void>)&UniffiFreeUnmanaged,
+            @uniffiClone = (IntPtr)(delegate* unmanaged[Cdecl]<ulong, ulong>)&UniffiCloneUnmanaged,
+#else
             {%- for (ffi_callback, meth) in vtable_methods.iter() %}
-            {%- let fn_type = format!("_UniFFILib.{}Method", callback_impl_name) %}
             {{ meth.name()|var_name() }} = Marshal.GetFunctionPointerForDelegate(_m{{ loop.index0 }}),
             {%- endfor %}
             @uniffiFree = Marshal.GetFunctionPointerForDelegate(_callback_interface_free),
             @uniffiClone = Marshal.GetFunctionPointerForDelegate(_callback_interface_clone),
+#endif
         };
 
         // Pin the vtable so the GC never moves it. The GCHandle is intentionally never freed —
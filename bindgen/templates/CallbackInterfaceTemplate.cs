@@ -3,7 +3,7 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
 
 {%- let cbi = ci.get_callback_interface_definition(name).unwrap() %}
-{%- let type_name = cbi|type_name(ci) %}
+{%- let type_name = cbi|type_name(ci, config) %}
 {%- let callback_impl_name = type_name|ffi_callback_impl %}
 
 {%- let vtable = cbi.vtable() %}
@@ -13,16 +13,11 @@
 {%- let ffi_init_callback = cbi.ffi_init_callback() %}
 
 {%- call cs::docstring(cbi, 0) %}
-{{ config.access_modifier() }} interface {{ type_name }} {
+{{ config.type_access_modifier(cbi.name()) }} interface {{ type_name }} {
     {%- for meth in cbi.methods() %}
     {%- call cs::docstring(meth, 4) %}
     {%- call cs::method_throws_annotation(meth.throws_type()) %}
-    {%- match meth.return_type() %}
-    {%- when Some with (return_type) %}
-    {{ return_type|type_name(ci) }} {{ meth.name()|fn_name }}({% call cs::arg_list_decl(meth) %});
-    {%- else %}
-    void {{ meth.name()|fn_name }}({% call cs::arg_list_decl(meth) %});
-    {%- endmatch %}
+    {%  call cs::return_type(meth) %} {{ meth.name()|fn_name|async_name(meth.is_async(), config) }}({% call cs::foreign_arg_list_decl(meth) %});
     {%- endfor %}
 }
 
@@ -50,7 +45,7 @@ class {{ ffi_converter_name }}: FfiConverter<{{ type_name }}, ulong> {
         return Lift(stream.ReadULong());
     }
 
-    public override int AllocationSize({{ type_name }} value) {
+    public override long AllocationSize({{ type_name }} value) {
         return 8;
     }
 
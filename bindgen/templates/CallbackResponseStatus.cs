@@ -1,4 +1,4 @@
-static class UniffiCallbackResponseStatus {
+{{ config.runtime_access_modifier() }} static class UniffiCallbackResponseStatus {
     public static sbyte SUCCESS = 0;
     public static sbyte ERROR = 1;
     public static sbyte UNEXPECTED_ERROR = 2;
@@ -2,7 +2,7 @@
 {{- self.add_import("System.Diagnostics.CodeAnalysis") }}
 {{- self.add_import("System.Threading") }}
 
-class ConcurrentHandleMap<T> where T: notnull {
+{{ config.runtime_access_modifier() }} class ConcurrentHandleMap<T> where T: notnull {
     readonly ConcurrentDictionary<ulong, T> _map = new();
 
     // Handles are odd numbers (1, 3, 5, ...) — the lowest bit must always be set.
@@ -10,7 +10,7 @@
  * is needed because the UDL type name is used in function/method signatures.
  * It's also what we have an external type that references a custom type.
  */
-{% let type_name_custom = builtin|type_name_custom(ci) %}
+{% let type_name_custom = builtin|type_name_custom(ci, config) %}
 {% let type_name_converter = builtin|ffi_converter_name %}
 {{- self.add_type_alias(name, type_name_custom) }}
 {{- self.add_type_alias(ffi_converter_name, type_name_converter) }}
@@ -58,7 +58,7 @@ class {{ ffi_converter_name }}: FfiConverter<{{ name }}, {{ ffi_type_name }}> {
         return {{ config.lift("builtinValue") }};
     }
 
-    public override int AllocationSize({{ name }} value) {
+    public override long AllocationSize({{ name }} value) {
         var builtinValue = {{ config.lower("value") }};
         return {{ builtin|allocation_size_fn }}(builtinValue);
     }
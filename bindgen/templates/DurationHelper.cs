@@ -2,27 +2,154 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
 
-class FfiConverterDuration: FfiConverterRustBuffer<TimeSpan> {
-    public static FfiConverterDuration INSTANCE = new FfiConverterDuration();
+{%- if self.include_once_check("UniffiTimeOverflowException.cs") %}
+{% include "UniffiTimeOverflowException.cs" %}
+{%- endif %}
+
+// Duration as seconds and nanoseconds, like Rust's `Duration`, without losing precision or range.
+{{ config.access_modifier() }} readonly struct UniffiDuration : IEquatable<UniffiDuration>, IComparable<UniffiDuration> {
+    const uint NanosecondsPerSecond = 1_000_000_000;
 
     // https://github.com/dotnet/runtime/blob/main/src/libraries/System.Private.CoreLib/src/System/TimeSpan.cs
-    private const uint NanosecondsPerTick = 100;
+    const uint NanosecondsPerTick = 100;
+
+    internal static readonly UniffiDuration MaxTimeSpan = FromTimeSpan(TimeSpan.MaxValue);
+    {%- if config.duration_kind() == DurationKind::NodaTime %}
+    internal static readonly UniffiDuration MaxNodaDuration = FromNodaDuration(NodaTime.Duration.MaxValue);
+    {%- endif %}
+
+    public UniffiDuration(ulong seconds, uint nanoseconds) {
+        if (nanoseconds >= NanosecondsPerSecond) {
+            throw new ArgumentOutOfRangeException(nameof(nanoseconds), nanoseconds, "must be less than a second");
+        }
+        Seconds = seconds;
+        Nanoseconds = nanoseconds;
+    }
+
+    public ulong Seconds { get; }
+
+    public uint Nanoseconds { get; }
+
+    public static UniffiDuration FromTimeSpan(TimeSpan value) {
+        if (value < TimeSpan.Zero) {
+            throw new UniffiTimeOverflowException($"TimeSpan {value} is negative, which a Rust Duration can't be");
+        }
+        return new UniffiDuration(
+            (ulong)(value.Ticks / TimeSpan.TicksPerSecond),
+            (uint)(value.Ticks % TimeSpan.TicksPerSecond) * NanosecondsPerTick
+        );
+    }
+
+    // Nanoseconds are truncated to ticks of 100 nanoseconds.
+    public TimeSpan ToTimeSpan() {
+        if (this > MaxTimeSpan) {
+            throw new UniffiTimeOverflowException($"{this} is outside the range of TimeSpan");
+        }
+        return new TimeSpan((long)Seconds * TimeSpan.TicksPerSecond + Nanoseconds / NanosecondsPerTick);
+    }
+    {%- if config.duration_kind() == DurationKind::NodaTime %}
+
+    public static UniffiDuration FromNodaDuration(NodaTime.Duration value) {
+        if (value < NodaTime.Duration.Zero) {
+            throw new UniffiTimeOverflowException($"Duration {value} is negative, which a Rust Duration can't be");
+        }
+        var nanoseconds = value.ToBigIntegerNanoseconds();
+        return new UniffiDuration(
+            (ulong)(nanoseconds / NanosecondsPerSecond),
+            (uint)(nanoseconds % NanosecondsPerSecond)
+        );
+    }
+
+    public NodaTime.Duration ToNodaDuration() {
+        if (this > MaxNodaDuration) {
+            throw new UniffiTimeOverflowException($"{this} is outside the range of Duration");
+        }
+        return NodaTime.Duration.FromSeconds((long)Seconds) + NodaTime.Duration.FromNanoseconds((long)Nanoseconds);
+    }
+    {%- endif %}
+
+    public int CompareTo(UniffiDuration other) {
+        var seconds = Seconds.CompareTo(other.Seconds);
+        return seconds != 0 ? seconds : Nanoseconds.CompareTo(other.Nanoseconds);
+    }
+
+    public bool Equals(UniffiDuration other) {
+        return Seconds == other.Seconds && Nanoseconds == other.Nanoseconds;
+    }
+
+    public override bool Equals(object? obj) {
+        return obj is UniffiDuration other && Equals(other);
+    }
+
+    public override int GetHashCode() {
+        return unchecked(Seconds.GetHashCode() * 31 + Nanoseconds.GetHashCode());
+    }
+
+    public override string ToString() {
+        return $"UniffiDuration {{ Seconds = {Seconds}, Nanoseconds = {Nanoseconds} }}";
+    }
+
+    public static bool operator ==(UniffiDuration left, UniffiDuration right) => left.Equals(right);
+    public static bool operator !=(UniffiDuration left, UniffiDuration right) => !left.Equals(right);
+    public static bool operator <(UniffiDuration left, UniffiDuration right) => left.CompareTo(right) < 0;
+    public static bool operator <=(UniffiDuration left, UniffiDuration right) => left.CompareTo(right) <= 0;
+    public static bool operator >(UniffiDuration left, UniffiDuration right) => left.CompareTo(right) > 0;
+    public static bool operator >=(UniffiDuration left, UniffiDuration right) => left.CompareTo(right) >= 0;
+}
+
+{%- let duration_kind = config.duration_kind() %}
+{%- let duration_type = duration_kind.type_label() %}
+
+{{ config.runtime_access_modifier() }} class FfiConverterDuration: FfiConverterRustBuffer<{{ duration_type }}> {
+    public static FfiConverterDuration INSTANCE = new FfiConverterDuration();
 
-    public override TimeSpan Read(BigEndianStream stream) {
-        var seconds = stream.ReadULong();
-        var nanoseconds = stream.ReadUInt();
-        var ticks = seconds * TimeSpan.TicksPerSecond;
-        ticks += nanoseconds / NanosecondsPerTick;
-        return new TimeSpan(Convert.ToInt64(ticks));
+    public override {{ duration_type }} Read(BigEndianStream stream) {
+        var value = new UniffiDuration(stream.ReadULong(), stream.ReadUInt());
+        {%- match duration_kind %}
+        {%- when DurationKind::TimeSpan %}
+        {%- if config.clamps_time() %}
+        if (value > UniffiDuration.MaxTimeSpan) {
+            return TimeSpan.MaxValue;
+        }
+        {%- endif %}
+        return value.ToTimeSpan();
+        {%- when DurationKind::UniffiDuration %}
+        return value;
+        {%- when DurationKind::NodaTime %}
+        {%- if config.clamps_time() %}
+        if (value > UniffiDuration.MaxNodaDuration) {
+            return NodaTime.Duration.MaxValue;
+        }
+        {%- endif %}
+        return value.ToNodaDuration();
+        {%- endmatch %}
     }
 
-    public override int AllocationSize(TimeSpan value) {
+    public override long AllocationSize({{ duration_type }} value) {
         // 8 bytes for seconds, 4 bytes for nanoseconds
         return 12;
     }
 
-    public override void Write(TimeSpan value, BigEndianStream stream) {
-        stream.WriteULong(Convert.ToUInt64(value.Ticks / TimeSpan.TicksPerSecond));
-        stream.WriteUInt(Convert.ToUInt32(value.Ticks % TimeSpan.TicksPerSecond * NanosecondsPerTick));
+    public override void Write({{ duration_type }} value, BigEndianStream stream) {
+        {%- match duration_kind %}
+        {%- when DurationKind::TimeSpan %}
+        {%- if config.clamps_time() %}
+        if (value < TimeSpan.Zero) {
+            value = TimeSpan.Zero;
+        }
+        {%- endif %}
+        var duration = UniffiDuration.FromTimeSpan(value);
+        {%- when DurationKind::UniffiDuration %}
+        var duration = value;
+        {%- when DurationKind::NodaTime %}
+        {%- if config.clamps_time() %}
+        if (value < NodaTime.Duration.Zero) {
+            value = NodaTime.Duration.Zero;
+        }
+        {%- endif %}
+        var duration = UniffiDuration.FromNodaDuration(value);
+        {%- endmatch %}
+        stream.WriteULong(duration.Seconds);
+        stream.WriteUInt(duration.Nanoseconds);
     }
 }
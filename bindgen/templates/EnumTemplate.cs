@@ -4,7 +4,8 @@
 
 {#
 // C# doesn't support enums with associated data. Use regular `enum` for flat
-// enums, and `record` for enums with associated data.
+// enums, and `record` for enums with associated data. With `sealed_enums`, the
+// base record is abstract and the variants are sealed.
 #}
 
 {%- if e.is_flat() %}
@@ -12,14 +13,14 @@
 {%- call cs::docstring(e, 0) %}
 {%- match e.variant_discr_type() %}
 {%- when None %}
-{{ config.access_modifier() }} enum {{ type_name }}: int {
+{{ config.type_access_modifier(e.name()) }} enum {{ type_name }}: int {
     {%- for variant in e.variants() %}
     {%- call cs::docstring(variant, 4) %}
     {{ variant.name()|enum_variant }}{% if !loop.last %},{% endif %}
     {%- endfor %}
 }
 {%- when Some with (discr_type) %}
-{{ config.access_modifier() }} enum {{ type_name }}: {{ discr_type|variant_discr_type_name }} {
+{{ config.type_access_modifier(e.name()) }} enum {{ type_name }}: {{ discr_type|variant_discr_type_name }} {
     {%- for variant in e.variants() %}
     {%- call cs::docstring(variant, 4) %}
     {{ variant.name()|enum_variant }} = {{ e|variant_discr_literal(loop.index0) }}{% if !loop.last %},{% endif %}
@@ -31,7 +32,7 @@
 {%- let flat_self_lower = format!("{}.INSTANCE.Lower(self_)", enum_ffi_converter) %}
 {%- let uniffi_trait_methods = e.uniffi_trait_methods() %}
 {%- if !e.methods().is_empty() || uniffi_trait_methods.display_fmt.is_some() || uniffi_trait_methods.debug_fmt.is_some() %}
-{{ config.access_modifier() }} static class {{ type_name }}Extensions {
+{{ config.type_access_modifier(e.name()) }} static class {{ type_name }}Extensions {
     {%- call cs::flat_enum_extension_methods(e.methods(), type_name, flat_self_lower) %}
     {%- call cs::flat_enum_uniffi_traits(uniffi_trait_methods, type_name, flat_self_lower) %}
 }
@@ -50,7 +51,7 @@ class {{ e|ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
         }
     }
 
-    public override int AllocationSize({{ type_name }} value) {
+    public override long AllocationSize({{ type_name }} value) {
         return 4;
     }
 
@@ -69,33 +70,114 @@ class {{ e|ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
 {%- call cs::docstring(e, 0) %}
 {%- let enum_ffi_converter = e|ffi_converter_name %}
 {%- let self_lower_prefix = format!("{}.INSTANCE.Lower(this)", enum_ffi_converter) %}
-{{ config.access_modifier() }} record {{ type_name }}{% if contains_object_references %}: IDisposable {% endif %} {
+{%- let sealed = config.sealed_enums() %}
+{%- let uniffi_trait_methods = e.uniffi_trait_methods() %}
+{{ config.type_access_modifier(e.name()) }} {% if sealed %}abstract {% endif %}record {{ type_name }} {% call cs::value_type_interfaces(type_name, contains_object_references, uniffi_trait_methods) %}{
+    {%- if sealed %}
+    // Only the variants can derive from the base record. C# doesn't know the variants are all
+    // of its subclasses, so switches over them still need a default case.
+    private {{ type_name }}() {}
+    {%- endif %}
+
     {% for variant in e.variants() -%}
     {%- call cs::docstring(variant, 4) %}
     {% if !variant.has_fields() -%}
-    public record {{ variant.name()|class_name(ci) }}: {{ type_name }} {}
+    public {% if sealed %}sealed {% endif %}record {{ variant.name()|class_name(ci, config) }}: {{ type_name }} {}
     {% else -%}
-    public record {{ variant.name()|class_name(ci) }} (
+    public {% if sealed %}sealed {% endif %}record {{ variant.name()|class_name(ci, config) }} (
         {%- for field in variant.fields() %}
         {%- let field_name = field.name()|or_pos_var(loop.index)|property_name %}
-        {% call cs::enum_parameter_type_name(field|type_name(ci), variant.name()|class_name(ci)) %} {% call cs::enum_field_name(field_name, variant.name()|class_name(ci)) %}{% if !loop.last %},{% endif %}
+        {% call cs::enum_parameter_type_name(field|type_name(ci, config), variant.name()|class_name(ci, config)) %} {% call cs::enum_field_name(field_name, variant.name()|class_name(ci, config)) %}{% if !loop.last %},{% endif %}
         {%- endfor %}
-    ) : {{ type_name }} {}
+    ) : {{ type_name }} {
+    {%- if uniffi_trait_methods.eq_eq.is_none() && variant.fields()|has_compound_fields %}
+    {%- let variant_class_name = variant.name()|class_name(ci, config) %}
+        public {% if !sealed %}virtual {% endif %}bool Equals({{ variant_class_name }}? other) {
+            if (other is null) {
+                return false;
+            }
+            return
+            {%- for field in variant.fields() %}
+            {%- let field_name = field.name()|or_pos_var(loop.index)|property_name %}
+                {% if !loop.first %}&& {% endif %}UniffiStructuralEquality.AreEqual(this.{% call cs::enum_field_name(field_name, variant_class_name) %}, other.{% call cs::enum_field_name(field_name, variant_class_name) %})
+            {%- endfor %};
+        }
+
+        public override int GetHashCode() {
+            var hash = 17;
+            {%- for field in variant.fields() %}
+            {%- let field_name = field.name()|or_pos_var(loop.index)|property_name %}
+            hash = unchecked(hash * 31 + UniffiStructuralEquality.HashOf(this.{% call cs::enum_field_name(field_name, variant_class_name) %}));
+            {%- endfor %}
+            return hash;
+        }
+    }
+    {%- else %}}
+    {%- endif %}
     {%- endif %}
     {% endfor %}
 
+    {%- if sealed %}
+    public TResult Match<TResult>(
+        {%- for variant in e.variants() %}
+        Func<{{ variant.name()|class_name(ci, config) }}, TResult> {{ variant.name()|var_name }}{% if !loop.last %},{% endif %}
+        {%- endfor %}
+    ) {
+        switch (this) {
+            {%- for variant in e.variants() %}
+            case {{ variant.name()|class_name(ci, config) }} variant_value: return {{ variant.name()|var_name }}(variant_value);
+            {%- endfor %}
+            default:
+                throw new InternalException(String.Format("invalid enum value '{0}' in {{ type_name }}.Match()", this));
+        }
+    }
+
+    public void Switch(
+        {%- for variant in e.variants() %}
+        Action<{{ variant.name()|class_name(ci, config) }}> {{ variant.name()|var_name }}{% if !loop.last %},{% endif %}
+        {%- endfor %}
+    ) {
+        switch (this) {
+            {%- for variant in e.variants() %}
+            case {{ variant.name()|class_name(ci, config) }} variant_value: {{ variant.name()|var_name }}(variant_value); break;
+            {%- endfor %}
+            default:
+                throw new InternalException(String.Format("invalid enum value '{0}' in {{ type_name }}.Switch()", this));
+        }
+    }
+    {% for variant in e.variants() %}
+    {%- let variant_class_name = variant.name()|class_name(ci, config) %}
+    public bool Is{{ variant_class_name }} => this is {{ variant_class_name }};
+    public {{ variant_class_name }}? As{{ variant_class_name }} => this as {{ variant_class_name }};
+    {%- endfor %}
+    {% endif %}
+
     {% if contains_object_references %}
     public void Dispose() {
         switch (this) {
             {%- for variant in e.variants() %}
-            case {{ type_name }}.{{ variant.name()|class_name(ci) }} variant_value:
+            case {{ type_name }}.{{ variant.name()|class_name(ci, config) }} variant_value:
                 {%- if variant.has_fields() %}
+                {%- if config.aot_compatible() %}
+                {%- for field in variant.fields() %}
+                {%- let field_name = field.name()|or_pos_var(loop.index)|property_name %}
+                {%- let variant_class_name = variant.name()|class_name(ci, config) %}
+                {%- if field_name == variant_class_name %}
+                {%- let field_value = format!("variant_value.{}Value", field_name) %}
+                {{ field|dispose_value(field_value, ci) }}
+                {%- else %}
+                {%- let field_value = format!("variant_value.{}", field_name) %}
+                {{ field|dispose_value(field_value, ci) }}
+                {%- endif %}
+                {%- endfor %}
+                {%- else %}
                 FFIObjectUtil.DisposeAll(
                     {%- for field in variant.fields() %}
                     {%- let field_name = field.name()|or_pos_var(loop.index)|property_name %}
-                    variant_value.{% call cs::enum_field_name(field_name, variant.name()|class_name(ci)) %}{% if !loop.last %},{% endif %}
+                    variant_value.{% call cs::enum_field_name(field_name, variant.name()|class_name(ci, config)) %}{% if !loop.last %},{% endif %}
                     {%- endfor %});
                 {%- endif %}
+                {%- endif %}
                 break;
             {%- endfor %}
             default:
@@ -108,7 +190,6 @@ class {{ e|ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
     {%- call cs::value_type_methods(e.methods(), self_lower_prefix) %}
     {%- endif %}
 
-    {%- let uniffi_trait_methods = e.uniffi_trait_methods() %}
     {%- call cs::value_type_uniffi_traits(uniffi_trait_methods, self_lower_prefix) %}
 }
 
@@ -120,7 +201,7 @@ class {{ e|ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }}>{
         switch (value) {
             {%- for variant in e.variants() %}
             case {{ loop.index }}:
-                return new {{ type_name }}.{{ variant.name()|class_name(ci) }}(
+                return new {{ type_name }}.{{ variant.name()|class_name(ci, config) }}(
                     {%- for field in variant.fields() %}
                     {{ field|read_fn }}(stream){% if !loop.last %},{% endif %}
                     {%- endfor %}
@@ -131,14 +212,14 @@ class {{ e|ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }}>{
         }
     }
 
-    public override int AllocationSize({{ type_name }} value) {
+    public override long AllocationSize({{ type_name }} value) {
         switch (value) {
             {%- for variant in e.variants() %}
-            case {{ type_name }}.{{ variant.name()|class_name(ci) }} variant_value:
+            case {{ type_name }}.{{ variant.name()|class_name(ci, config) }} variant_value:
                 return 4
                     {%- for field in variant.fields() %}
                     {%- let field_name = field.name()|or_pos_var(loop.index)|property_name %}
-                    + {{ field|allocation_size_fn }}(variant_value.{% call cs::enum_field_name(field_name, variant.name()|class_name(ci)) %})
+                    + {{ field|allocation_size_fn }}(variant_value.{% call cs::enum_field_name(field_name, variant.name()|class_name(ci, config)) %})
                     {%- endfor %};
             {%- endfor %}
             default:
@@ -149,11 +230,11 @@ class {{ e|ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }}>{
     public override void Write({{ type_name }} value, BigEndianStream stream) {
         switch (value) {
             {%- for variant in e.variants() %}
-            case {{ type_name }}.{{ variant.name()|class_name(ci) }} variant_value:
+            case {{ type_name }}.{{ variant.name()|class_name(ci, config) }} variant_value:
                 stream.WriteInt({{ loop.index }});
                 {%- for field in variant.fields() %}
                 {%- let field_name = field.name()|or_pos_var(loop.index)|property_name %}
-                {{ field|write_fn }}(variant_value.{% call cs::enum_field_name(field_name, variant.name()|class_name(ci)) %}, stream);
+                {{ field|write_fn }}(variant_value.{% call cs::enum_field_name(field_name, variant.name()|class_name(ci, config)) %}, stream);
                 {%- endfor %}
                 break;
             {%- endfor %}
@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
 
-{%- let type_name = type_|type_name(ci) %}
+{%- let type_name = type_|type_name(ci, config) %}
 {%- let ffi_converter_name = type_|ffi_converter_name %}
 {%- let canonical_type_name = type_|canonical_name %}
 
@@ -10,15 +10,15 @@
 {%- call cs::docstring(e, 0) %}
 {%- let error_ffi_converter = ffi_converter_name %}
 {%- let self_lower_prefix = format!("{}.INSTANCE.Lower(this)", error_ffi_converter) %}
-{{ config.access_modifier() }} class {{ type_name }}: UniffiException {
+{{ config.type_access_modifier(e.name()) }} class {{ type_name }}: UniffiException {
     {{ type_name }}(string message): base(message) {}
 
     // Each variant is a nested class
     // Flat enums carries a string error message, so no special implementation is necessary.
     {% for variant in e.variants() -%}
     {%- call cs::docstring(variant, 4) %}
-    public {% if variant|error_variant_name == "InnerException" %}new {% endif %}class {{ variant|error_variant_name }}: {{ type_name }} {
-        public {{ variant|error_variant_name }}(string message): base(message) {}
+    public {% if variant|error_variant_name(config) == "InnerException" %}new {% endif %}class {{ variant|error_variant_name(config) }}: {{ type_name }} {
+        public {{ variant|error_variant_name(config) }}(string message): base(message) {}
     }
     {% endfor %}
 
@@ -48,21 +48,21 @@ class {{ ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }}>, CallSt
         var value = stream.ReadInt();
         switch (value) {
             {%- for variant in e.variants() %}
-            case {{ loop.index }}: return new {{ type_name }}.{{ variant|error_variant_name }}({{ Type::String.borrow()|read_fn }}(stream));
+            case {{ loop.index }}: return new {{ type_name }}.{{ variant|error_variant_name(config) }}({{ Type::String.borrow()|read_fn }}(stream));
             {%- endfor %}
             default:
                 throw new InternalException(String.Format("invalid error value '{0}' in {{ ffi_converter_name }}.Read()", value));
         }
     }
 
-    public override int AllocationSize({{ type_name }} value) {
+    public override long AllocationSize({{ type_name }} value) {
         return 4 + {{ Type::String.borrow()|allocation_size_fn }}(value.Message);
     }
 
     public override void Write({{ type_name }} value, BigEndianStream stream) {
         switch (value) {
             {%- for variant in e.variants() %}
-            case {{ type_name }}.{{ variant|error_variant_name }}:
+            case {{ type_name }}.{{ variant|error_variant_name(config) }}:
                 stream.WriteInt({{ loop.index }});
                 break;
             {%- endfor %}
@@ -76,7 +76,7 @@ class {{ ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }}>, CallSt
 {%- call cs::docstring(e, 0) %}
 {%- let error_ffi_converter = ffi_converter_name %}
 {%- let self_lower_prefix = format!("{}.INSTANCE.Lower(this)", error_ffi_converter) %}
-{{ config.access_modifier() }} class {{ type_name }}: UniffiException{% if contains_object_references %}, IDisposable {% endif %} {
+{{ config.type_access_modifier(e.name()) }} class {{ type_name }}: UniffiException{% if contains_object_references %}, IDisposable {% endif %} {
     {{ type_name }}() : base() {}
     {{ type_name }}(String @Message) : base(@Message) {}
 
@@ -84,22 +84,22 @@ class {{ ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }}>, CallSt
     {% for variant in e.variants() -%}
     {%- call cs::docstring(variant, 4) %}
     {% if !variant.has_fields() -%}
-    public {% if variant|error_variant_name == "InnerException" %}new {% endif %}class {{ variant|error_variant_name }} : {{ type_name }} {
-        public {{ variant|error_variant_name }}() : base() {}
+    public {% if variant|error_variant_name(config) == "InnerException" %}new {% endif %}class {{ variant|error_variant_name(config) }} : {{ type_name }} {
+        public {{ variant|error_variant_name(config) }}() : base() {}
     }
     {% else %}
-    public {% if variant|error_variant_name == "InnerException" %}new {% endif %}class {{ variant|error_variant_name }} : {{ type_name }} {
+    public {% if variant|error_variant_name(config) == "InnerException" %}new {% endif %}class {{ variant|error_variant_name(config) }} : {{ type_name }} {
         // Members
         {%- for field in variant.fields() %}
         {%- let field_name = field.name()|or_pos_var(loop.index)|var_name %}
-        public {% call cs::enum_parameter_type_name(field|type_name(ci), variant|error_variant_name) %} {{ field_name }};
+        public {% call cs::enum_parameter_type_name(field|type_name(ci, config), variant|error_variant_name(config)) %} {{ field_name }};
         {%- endfor %}
 
         // Constructor
-        public {{ variant|error_variant_name }}(
+        public {{ variant|error_variant_name(config) }}(
                 {%- for field in variant.fields() %}
                 {%- let field_name = field.name()|or_pos_var(loop.index)|var_name %}
-                {% call cs::enum_parameter_type_name(field|type_name(ci), variant|error_variant_name) %} {{ field_name }}{% if loop.last %}{% else %}, {% endif %}
+                {% call cs::enum_parameter_type_name(field|type_name(ci, config), variant|error_variant_name(config)) %} {{ field_name }}{% if loop.last %}{% else %}, {% endif %}
                 {%- endfor %}) : base(
                 {%- for field in variant.fields() -%}
                 {%- let field_name = field.name()|or_pos_var(loop.index)|var_name %}
@@ -120,14 +120,22 @@ class {{ ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }}>, CallSt
     public void Dispose() {
         switch (this) {
             {%- for variant in e.variants() %}
-            case {{ type_name }}.{{ variant|error_variant_name }} variant_value:
+            case {{ type_name }}.{{ variant|error_variant_name(config) }} variant_value:
                 {%- if variant.has_fields() %}
+                {%- if config.aot_compatible() %}
+                {%- for field in variant.fields() %}
+                {%- let field_name = field.name()|or_pos_var(loop.index)|var_name %}
+                {%- let field_value = format!("variant_value.{}", field_name) %}
+                {{ field|dispose_value(field_value, ci) }}
+                {%- endfor %}
+                {%- else %}
                 FFIObjectUtil.DisposeAll(
                     {%- for field in variant.fields() %}
                     {%- let field_name = field.name()|or_pos_var(loop.index)|var_name %}
                     variant_value.{{ field_name }}{% if !loop.last %},{% endif %}
                     {%- endfor %});
                 {%- endif %}
+                {%- endif %}
                 break;
             {%- endfor %}
             default:
@@ -163,7 +171,7 @@ class {{ ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }}>, CallSt
         switch (value) {
             {%- for variant in e.variants() %}
             case {{ loop.index }}:
-                return new {{ type_name }}.{{ variant|error_variant_name }}(
+                return new {{ type_name }}.{{ variant|error_variant_name(config) }}(
                     {%- for field in variant.fields() %}
                     {{ field|read_fn }}(stream){% if !loop.last %},{% endif %}
                     {%- endfor %});
@@ -173,11 +181,11 @@ class {{ ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }}>, CallSt
         }
     }
 
-    public override int AllocationSize({{ type_name }} value) {
+    public override long AllocationSize({{ type_name }} value) {
         switch (value) {
             {%- for variant in e.variants() %}
 
-            case {{ type_name }}.{{ variant|error_variant_name }} variant_value:
+            case {{ type_name }}.{{ variant|error_variant_name(config) }} variant_value:
                 return 4
                     {%- for field in variant.fields() %}
                     {%- let field_name = field.name()|or_pos_var(loop.index)|var_name %}
@@ -192,7 +200,7 @@ class {{ ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }}>, CallSt
     public override void Write({{ type_name }} value, BigEndianStream stream) {
         switch (value) {
             {%- for variant in e.variants() %}
-            case {{ type_name }}.{{ variant|error_variant_name }} variant_value:
+            case {{ type_name }}.{{ variant|error_variant_name(config) }} variant_value:
                 stream.WriteInt({{ loop.index }});
                 {%- for field in variant.fields() %}
                 {%- let field_name = field.name()|or_pos_var(loop.index)|var_name %}
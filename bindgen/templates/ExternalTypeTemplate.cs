@@ -5,7 +5,7 @@
 {%- let namespace = ci.namespace_for_module_path(module_path)? %}
 {%- let package_name = self.external_type_package_name(module_path, namespace) %}
 {%- let local_ffi_converter_name = "FfiConverterType{}"|format(name) %}
-{%- let type_label = name|class_name(ci) %}
+{%- let type_label = name|class_name(ci, config) %}
 {%- let ext_converter = "{}.{}"|format(package_name, local_ffi_converter_name) %}
 
 {{- self.add_import(package_name) }}
@@ -15,19 +15,27 @@ class {{ local_ffi_converter_name }}: FfiConverterRustBuffer<{{ type_label }}>
     public static {{ local_ffi_converter_name }} INSTANCE = new {{ local_ffi_converter_name }}();
 
     public override {{ type_label }} Read(BigEndianStream stream) {
+        {%- if config.shared_runtime() %}
+        return {{ ext_converter }}.INSTANCE.Read(stream);
+        {%- else %}
         return {{ ext_converter }}.INSTANCE.Read(
             new {{ package_name }}.BigEndianStream(stream.InnerStream)
         );
+        {%- endif %}
     }
 
-    public override int AllocationSize({{ type_label }} value) {
+    public override long AllocationSize({{ type_label }} value) {
         return {{ ext_converter }}.INSTANCE.AllocationSize(value);
     }
 
     public override void Write({{ type_label }} value, BigEndianStream stream) {
+        {%- if config.shared_runtime() %}
+        {{ ext_converter }}.INSTANCE.Write(value, stream);
+        {%- else %}
         {{ ext_converter }}.INSTANCE.Write(
             value,
             new {{ package_name }}.BigEndianStream(stream.InnerStream)
         );
+        {%- endif %}
     }
 }
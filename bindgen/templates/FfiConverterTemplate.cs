@@ -6,7 +6,7 @@
 //
 // All implementing objects should be public to support external types.  When a
 // type is external we need to import it's FfiConverter.
-internal abstract class FfiConverter<CsType, FfiType> {
+{{ config.runtime_access_modifier() }} abstract class FfiConverter<CsType, FfiType> {
     // Convert an FFI type to a C# type
     public abstract CsType Lift(FfiType value);
 
@@ -24,7 +24,7 @@ internal abstract class FfiConverter<CsType, FfiType> {
     // encoding, so we pessimistically allocate the largest size possible (3
     // bytes per codepoint).  Allocating extra bytes is not really a big deal
     // because the `RustBuffer` is short-lived.
-    public abstract int AllocationSize(CsType value);
+    public abstract long AllocationSize(CsType value);
 
     // Write a C# type to a `ByteBuffer`
     public abstract void Write(CsType value, BigEndianStream stream);
@@ -37,14 +37,16 @@ internal abstract class FfiConverter<CsType, FfiType> {
     // normal FFI type.
     public RustBuffer LowerIntoRustBuffer(CsType value) {
         var rbuf = RustBuffer.Alloc(AllocationSize(value));
+        var stream = BigEndianStream.Rent(rbuf.data, Convert.ToInt64(rbuf.capacity));
         try {
-            var stream = rbuf.AsWriteableStream();
             Write(value, stream);
             rbuf.len = Convert.ToUInt64(stream.Position);
             return rbuf;
         } catch {
             RustBuffer.Free(rbuf);
             throw;
+        } finally {
+            stream.Return();
         }
     }
 
@@ -53,7 +55,7 @@ internal abstract class FfiConverter<CsType, FfiType> {
     // This here mostly because of the symmetry with `lowerIntoRustBuffer()`.
     // It's currently only used by the `FfiConverterRustBuffer` class below.
     protected CsType LiftFromRustBuffer(RustBuffer rbuf) {
-        var stream = rbuf.AsStream();
+        var stream = BigEndianStream.Rent(rbuf.data, Convert.ToInt64(rbuf.len));
         try {
            var item = Read(stream);
            if (stream.HasRemaining()) {
@@ -61,13 +63,14 @@ internal abstract class FfiConverter<CsType, FfiType> {
            }
            return item;
         } finally {
+            stream.Return();
             RustBuffer.Free(rbuf);
         }
     }
 }
 
 // FfiConverter that uses `RustBuffer` as the FfiType
-internal abstract class FfiConverterRustBuffer<CsType>: FfiConverter<CsType, RustBuffer> {
+{{ config.runtime_access_modifier() }} abstract class FfiConverterRustBuffer<CsType>: FfiConverter<CsType, RustBuffer> {
     public override CsType Lift(RustBuffer value) {
         return LiftFromRustBuffer(value);
     }
@@ -0,0 +1,27 @@
+{#/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
+
+{#
+// Top level functions rendered into their own file, used when `split_files` is enabled.
+#}
+
+{%- for imported_class in imports %}
+using {{ imported_class }};
+{%- endfor %}
+
+namespace {{ config.namespace() }};
+
+{%- for alias in type_aliases %}
+using {{ alias.alias }} = {{ alias.original_type }};
+{%- endfor %}
+
+{{ config.access_modifier() }} static class {{ self.class_name() }} {
+{%- for func in ci.function_definitions() %}
+{%- if !config.is_excluded(func.name()) %}
+{%- include "TopLevelFunctionTemplate.cs" %}
+{%- endif %}
+{%- endfor %}
+}
+
+{% import "macros.cs" as cs %}
@@ -6,7 +6,7 @@
 // This would be a good candidate for isolating in its own ffi-support lib.
 // Error runtime.
 [StructLayout(LayoutKind.Sequential)]
-struct UniffiRustCallStatus {
+{{ config.runtime_access_modifier() }} struct UniffiRustCallStatus {
     public sbyte code;
     public RustBuffer error_buf;
 
@@ -60,13 +60,27 @@ struct UniffiRustCallStatus {
     }
 }
 
+// Thrown when a string, byte array or collection is larger than its .NET type, or than the 32 bit
+// length prefix UniFFI serializes it with, can hold.
+{{ config.access_modifier() }} class UniffiSizeLimitException: UniffiException {
+    public UniffiSizeLimitException(string message, ulong size, ulong limit): base(message) {
+        Size = size;
+        Limit = limit;
+    }
+
+    // Size of the data, in the unit of the message: bytes, items or UTF-16 code units.
+    public ulong Size { get; }
+
+    public ulong Limit { get; }
+}
+
 // Each top-level error class has a companion object that can lift the error from the call status's rust buffer
-interface CallStatusErrorHandler<E> where E: System.Exception {
+{{ config.runtime_access_modifier() }} interface CallStatusErrorHandler<E> where E: System.Exception {
     E Lift(RustBuffer error_buf);
 }
 
 // CallStatusErrorHandler implementation for times when we don't expect a CALL_ERROR
-class NullCallStatusErrorHandler: CallStatusErrorHandler<UniffiException> {
+{{ config.runtime_access_modifier() }} class NullCallStatusErrorHandler: CallStatusErrorHandler<UniffiException> {
     public static NullCallStatusErrorHandler INSTANCE = new NullCallStatusErrorHandler();
 
     public UniffiException Lift(RustBuffer error_buf) {
@@ -78,7 +92,7 @@ class NullCallStatusErrorHandler: CallStatusErrorHandler<UniffiException> {
 // Helpers for calling Rust
 // In practice we usually need to be synchronized to call this safely, so it doesn't
 // synchronize itself
-class _UniffiHelpers {
+{{ config.runtime_access_modifier() }} class _UniffiHelpers {
     public delegate void RustCallAction(ref UniffiRustCallStatus status);
     public delegate U RustCallFunc<out U>(ref UniffiRustCallStatus status);
 
@@ -130,7 +144,9 @@ class _UniffiHelpers {
     }
 }
 
-static class FFIObjectUtil {
+{%- if !config.aot_compatible() %}
+
+{{ config.runtime_access_modifier() }} static class FFIObjectUtil {
     public static void DisposeAll(params Object?[] list) {
         Dispose(list);
     }
@@ -149,6 +165,14 @@ static class FFIObjectUtil {
          }
 
          var objType = obj.GetType();
+         // `UniffiOptional<T>` is declared by the bindings of each component that uses it.
+         if (objType.IsGenericType && objType.GetGenericTypeDefinition().Name == "UniffiOptional`1") {
+             if ((bool)objType.GetProperty("HasValue")!.GetValue(obj)!) {
+                 Dispose(objType.GetProperty("Value")!.GetValue(obj));
+             }
+             return;
+         }
+
          var typeCode = Type.GetTypeCode(objType);
          if (typeCode != TypeCode.Object) {
              return;
@@ -185,3 +209,103 @@ static class FFIObjectUtil {
          }
      }
 }
+{%- endif %}
+
+// Structural equality for records and enum variants with sequence, map or bytes fields,
+// which the compiler generated `record` equality compares by reference.
+{{ config.runtime_access_modifier() }} static class UniffiStructuralEquality {
+    public static bool AreEqual(Object? left, Object? right) {
+        if (ReferenceEquals(left, right)) {
+            return true;
+        }
+        if (left == null || right == null) {
+            return false;
+        }
+
+        if (left is byte[] leftBytes && right is byte[] rightBytes) {
+            if (leftBytes.Length != rightBytes.Length) {
+                return false;
+            }
+            for (var i = 0; i < leftBytes.Length; i++) {
+                if (leftBytes[i] != rightBytes[i]) {
+                    return false;
+                }
+            }
+            return true;
+        }
+
+        if (left is System.Collections.IDictionary leftMap && right is System.Collections.IDictionary rightMap) {
+            if (leftMap.Count != rightMap.Count) {
+                return false;
+            }
+            foreach (System.Collections.DictionaryEntry entry in leftMap) {
+                if (!rightMap.Contains(entry.Key) || !AreEqual(entry.Value, rightMap[entry.Key])) {
+                    return false;
+                }
+            }
+            return true;
+        }
+
+        if (left is System.Collections.IList leftList && right is System.Collections.IList rightList) {
+            var count = Count(leftList);
+            if (count != Count(rightList)) {
+                return false;
+            }
+            for (var i = 0; i < count; i++) {
+                if (!AreEqual(leftList[i], rightList[i])) {
+                    return false;
+                }
+            }
+            return true;
+        }
+
+        return left.Equals(right);
+    }
+
+    public static int HashOf(Object? value) {
+        switch (value) {
+            case null:
+                return 0;
+            case System.Collections.IDictionary map:
+                // Entries are combined by addition so that the hash doesn't depend on
+                // iteration order, same as the comparison.
+                var mapHash = 0;
+                foreach (System.Collections.DictionaryEntry entry in map) {
+                    mapHash = unchecked(mapHash + HashOf(entry.Key) * 31 + HashOf(entry.Value));
+                }
+                return mapHash;
+            case System.Collections.IList list:
+                var listHash = 17;
+                if (IsDefaultImmutableArray(list)) {
+                    return listHash;
+                }
+                foreach (var item in list) {
+                    listHash = unchecked(listHash * 31 + HashOf(item));
+                }
+                return listHash;
+            default:
+                return value.GetHashCode();
+        }
+    }
+
+    static int Count(System.Collections.IList list) {
+        return IsDefaultImmutableArray(list) ? 0 : list.Count;
+    }
+
+    // A boxed `default(ImmutableArray<T>)` throws when used as a list. The converters write it as
+    // an empty sequence, so it is equal to empty lists here too. The type is matched by name
+    // because `System.Collections.Immutable` is only referenced when configured.
+    static bool IsDefaultImmutableArray(System.Collections.IList list) {
+        var type = list.GetType();
+        if (!type.IsGenericType
+            || type.GetGenericTypeDefinition().FullName != "System.Collections.Immutable.ImmutableArray`1") {
+            return false;
+        }
+        try {
+            _ = list.Count;
+            return false;
+        } catch (InvalidOperationException) {
+            return true;
+        }
+    }
+}
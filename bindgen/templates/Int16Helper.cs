@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
 
-class {{ ffi_converter_name }}: FfiConverter<short, short> {
+{{ config.runtime_access_modifier() }} class {{ ffi_converter_name }}: FfiConverter<short, short> {
     public static {{ ffi_converter_name }} INSTANCE = new {{ ffi_converter_name }}();
 
     public override short Lift(short value) {
@@ -17,7 +17,7 @@ class {{ ffi_converter_name }}: FfiConverter<short, short> {
         return value;
     }
 
-    public override int AllocationSize(short value) {
+    public override long AllocationSize(short value) {
         return 2;
     }
 
@@ -2,24 +2,65 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
 
+{%- let key_type_name = key_type|type_name(ci, config) %}
+{%- let value_type_name = value_type|type_name(ci, config) %}
+{%- let entry_type_name = format!("KeyValuePair<{}, {}>", key_type_name, value_type_name) %}
+
 class {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
     public static {{ ffi_converter_name }} INSTANCE = new {{ ffi_converter_name }}();
 
     public override {{ type_name }} Read(BigEndianStream stream) {
-        var len = stream.ReadInt();
-        var result = new {{ type_name }}(len);
-        var readerKey = {{ key_type|read_fn }};
-        var readerValue = {{ value_type|read_fn }};
+        var len = stream.ReadLength();
+        {%- match config.map_kind() %}
+        {%- when MapKind::ImmutableDictionary %}
+        var result = ImmutableDictionary.CreateBuilder<{{ key_type_name }}, {{ value_type_name }}>();
+        {%- else %}
+        var result = new Dictionary<{{ key_type_name }}, {{ value_type_name }}>(len);
+        {%- endmatch %}
         for (int i = 0; i < len; i++) {
-            var key = readerKey(stream);
-            var value = readerValue(stream);
+            var key = {{ key_type|read_fn }}(stream);
+            var value = {{ value_type|read_fn }}(stream);
             result[key] = value;
         }
 
+        {%- match config.map_kind() %}
+        {%- when MapKind::ImmutableDictionary %}
+        return result.ToImmutable();
+        {%- else %}
         return result;
+        {%- endmatch %}
+    }
+
+    // Maps of any collection type are accepted as input, so arguments and fields can be configured
+    // with another type than the one returned by `Read`.
+    public RustBuffer Lower(IEnumerable<{{ entry_type_name }}> value) {
+        {%- match config.map_kind() %}
+        {%- when MapKind::ImmutableDictionary %}
+        return LowerIntoRustBuffer(value as {{ type_name }} ?? (value == null ? null! : ImmutableDictionary.CreateRange(value)));
+        {%- else %}
+        return LowerIntoRustBuffer(value as {{ type_name }} ?? value?.ToDictionary(entry => entry.Key, entry => entry.Value)!);
+        {%- endmatch %}
+    }
+
+    // The typed overloads iterate the concrete collection, so that lowering doesn't allocate an
+    // enumerator.
+    public override long AllocationSize({{ type_name }} value) {
+        var sizeForLength = 4;
+
+        // details/1-empty-list-as-default-method-parameter.md
+        if (value == null) {
+            return sizeForLength;
+        }
+
+        var sizeForItems = 0L;
+        foreach (var item in value) {
+            sizeForItems += {{ key_type|allocation_size_fn }}(item.Key) + {{ value_type|allocation_size_fn }}(item.Value);
+        }
+        return sizeForLength + sizeForItems;
     }
 
-    public override int AllocationSize({{ type_name }} value) {
+    // Fields and arguments configured with another collection type than `Read` returns.
+    public long AllocationSize(IReadOnlyCollection<{{ entry_type_name }}> value) {
         var sizeForLength = 4;
 
         // details/1-empty-list-as-default-method-parameter.md
@@ -27,9 +68,10 @@ class {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
             return sizeForLength;
         }
 
-        var allocationKeySizeFn = {{ key_type|allocation_size_fn }};
-        var allocationKValueSizeFn = {{ value_type|allocation_size_fn }};
-        var sizeForItems = value.Sum(item => allocationKeySizeFn(item.Key) + allocationKValueSizeFn(item.Value));
+        var sizeForItems = 0L;
+        foreach (var item in value) {
+            sizeForItems += {{ key_type|allocation_size_fn }}(item.Key) + {{ value_type|allocation_size_fn }}(item.Value);
+        }
         return sizeForLength + sizeForItems;
     }
 
@@ -41,11 +83,24 @@ class {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
         }
 
         stream.WriteInt(value.Count);
-        var writerKey = {{ key_type|write_fn }};
-        var writerValue = {{ value_type|write_fn }};
         foreach (var item in value) {
-            writerKey(item.Key, stream);
-            writerValue(item.Value, stream);
+            {{ key_type|write_fn }}(item.Key, stream);
+            {{ value_type|write_fn }}(item.Value, stream);
+        }
+    }
+
+    // Fields and arguments configured with another collection type than `Read` returns.
+    public void Write(IReadOnlyCollection<{{ entry_type_name }}> value, BigEndianStream stream) {
+        // details/1-empty-list-as-default-method-parameter.md
+        if (value == null) {
+            stream.WriteInt(0);
+            return;
+        }
+
+        stream.WriteInt(value.Count);
+        foreach (var item in value) {
+            {{ key_type|write_fn }}(item.Key, stream);
+            {{ value_type|write_fn }}(item.Value, stream);
         }
     }
 }
@@ -11,10 +11,13 @@ static class _UniFFILib {
     {%- for def in ci.ffi_definitions() %}
     {%- match def %}
     {%- when FfiDefinition::CallbackFunction(callback) %}
+    {#- On .NET 8+ callbacks are `[UnmanagedCallersOnly]` function pointers, see CallbackInterfaceImpl.cs #}
+#if !NET8_0_OR_GREATER
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate {% call cs::ffi_return_type(callback) %} {{ callback.name()|ffi_callback_name }}(
         {% call cs::arg_list_ffi_decl(callback) %}
     );
+#endif
     {%- when FfiDefinition::Struct(ffi_struct) %}
     [StructLayout(LayoutKind.Sequential)]
     public struct {{ ffi_struct.name()|ffi_struct_name }}
@@ -5,10 +5,11 @@
 
 {%- let obj = ci.get_object_definition(name).unwrap() %}
 {%- let is_error = ci.is_name_used_as_error(name) %}
-{%- let (interface_name, impl_name) = obj|object_names(ci) %}
+{%- let (interface_name, impl_name) = obj|object_names(ci, config) %}
+{%- let properties = config.object_properties(obj) %}
 
 {%- call cs::docstring(obj, 0) %}
-{{ config.access_modifier() }} interface {{ interface_name }}
+{{ config.type_access_modifier(name) }} interface {{ interface_name }}
     {%- for tm in obj.uniffi_traits() -%}
     {%- match tm -%}
     {%- when UniffiTrait::Eq { eq, ne } -%}
@@ -16,15 +17,39 @@
     {%- else -%}
     {%- endmatch -%}
     {%- endfor %} {
+    {%- for property in properties %}
+    {%- if config.is_public_member(name, property.getter.name()) %}
+    {%- call cs::docstring(property.getter, 4) %}
+    {{ property.value_type|type_name(ci, config) }} {{ property.name|method_name(impl_name, config) }} { get;{% if property.setter.is_some() %} set;{% endif %} }
+    {%- endif %}
+    {%- endfor %}
     {%- for meth in obj.methods() %}
+    {%- if config.is_public_member(name, meth.name()) && !config.is_property_accessor(obj, meth) %}
     {%- call cs::docstring(meth, 4) %}
     {%- call cs::method_throws_annotation(meth.throws_type()) %}
-    {%  call cs::return_type(meth) %} {{ meth.name()|method_name(impl_name) }}({% call cs::arg_list_decl(meth) %});
+    {%- if obj.has_callback_interface() %}
+    {%  call cs::return_type(meth) %} {{ meth.name()|method_name(impl_name, config)|async_name(meth.is_async(), config) }}({% call cs::foreign_arg_list_decl(meth) %});
+    {%- else %}
+    {%  call cs::return_type(meth) %} {{ meth.name()|method_name(impl_name, config)|async_name(meth.is_async(), config) }}({% call cs::arg_list_decl(meth) %});
+    {%- endif %}
+    {%- endif %}
     {%- endfor %}
 }
 
+{%- let async_enumerable = config.async_enumerable(obj) %}
+
 {%- call cs::docstring(obj, 0) %}
-{{ config.access_modifier() }} class {{ impl_name }} : {% if is_error -%}UniffiException, {% endif -%}{{ interface_name }}, IDisposable {
+{{ config.type_access_modifier(name) }} class {{ impl_name }} : {% if is_error -%}UniffiException, {% endif -%}{{ interface_name }}, IDisposable
+    {%- for tm in obj.uniffi_traits() -%}
+    {%- match tm -%}
+    {%- when UniffiTrait::Ord { cmp } -%}
+    , IComparable<{{ impl_name }}>
+    {%- else -%}
+    {%- endmatch -%}
+    {%- endfor -%}
+    {%- if let Some(enumerable) = async_enumerable -%}
+    , IAsyncEnumerable<{{ enumerable.item_type|type_name(ci, config) }}>
+    {%- endif %} {
     protected ulong pointer;
     private int _wasDestroyed = 0;
     private long _callCounter = 1;
@@ -39,15 +64,24 @@
 
     {%- match obj.primary_constructor() %}
     {%- when Some with (cons) %}
+    {%- if !config.is_member_excluded(name, cons.name()) %}
+    {%- let cons_access = config.member_access_modifier(name, cons.name()) %}
     {%- call cs::docstring(cons, 4) %}
     {%- if cons.is_async() %}
-    public static async Task<{{ impl_name }}> {{ impl_name }}Async ({%- call cs::arg_list_decl(cons) -%}) {
+    {{ cons_access }} static async {{ config.async_type_name() }}<{{ impl_name }}> {{ impl_name }}Async ({%- call cs::arg_list_decl(cons) -%}) {
         {%- call cs::async_call(cons, false) %}
     }
+    {%- if config.async_blocking() %}
+    {%- call cs::docstring(cons, 4) %}
+    {{ cons_access }} static {{ impl_name }} {{ impl_name }}Blocking({%- call cs::arg_list_decl(cons) -%}) {
+        {%- call cs::blocking_call(cons, false) %}
+    }
+    {%- endif %}
     {%- else %}
-    public {{ impl_name }}({% call cs::arg_list_decl(cons) -%}) :
+    {{ cons_access }} {{ impl_name }}({% call cs::arg_list_decl(cons) -%}) :
         this({% call cs::to_ffi_call(cons) %}) {}
     {%- endif %}
+    {%- endif %}
     {%- when None %}
     {%- endmatch %}
 
@@ -126,29 +160,134 @@
         }
     }
 
+    {% for property in properties -%}
+    {%- call cs::docstring(property.getter, 4) %}
+    {{ config.member_access_modifier(name, property.getter.name()) }} {% if is_error && property.name|method_name(impl_name, config) == "Message" %}new {% endif %}{{ property.value_type|type_name(ci, config) }} {{ property.name|method_name(impl_name, config) }} {
+        get {
+            return CallWithPointer(thisPtr => {{ property.value_type|lift_fn }}({%- call cs::to_ffi_call_with_prefix("thisPtr", property.getter) %}));
+        }
+        {%- if let Some(setter) = property.setter %}
+        set {
+            {%- for arg in setter.arguments() %}
+            {%- if arg.name()|var_name != "@value" %}
+            var {{ arg.name()|var_name }} = value;
+            {%- endif %}
+            {%- endfor %}
+            CallWithPointer(thisPtr => {%- call cs::to_ffi_call_with_prefix("thisPtr", setter) %});
+        }
+        {%- endif %}
+    }
+    {% endfor %}
+
     {% for meth in obj.methods() -%}
+    {%- if !config.is_member_excluded(name, meth.name()) && !config.is_property_accessor(obj, meth) %}
+    {%- let meth_access = config.member_access_modifier(name, meth.name()) %}
     {%- call cs::docstring(meth, 4) %}
     {%- call cs::method_throws_annotation(meth.throws_type()) %}
     {%- if meth.is_async() %}
-    public {% if is_error && meth.name()|method_name(impl_name) == "Message" %}new {% endif %}async {% call cs::return_type(meth) %} {{ meth.name()|method_name(impl_name) }}({%- call cs::arg_list_decl(meth) -%}) {
+    {%- if obj.has_callback_interface() %}
+    {#- The interface is implemented in C# too, so its methods don't take a `CancellationToken`. #}
+    {{ meth_access }} {% if is_error && meth.name()|method_name(impl_name, config) == "Message" %}new {% endif %}{% call cs::return_type(meth) %} {{ meth.name()|method_name(impl_name, config)|async_name(meth.is_async(), config) }}({%- call cs::foreign_arg_list_decl(meth) -%}) {
+        return {{ meth.name()|method_name(impl_name, config)|async_name(meth.is_async(), config) }}({% for arg in meth.arguments() %}{{ arg.name()|var_name }}, {% endfor %}CancellationToken.None);
+    }
+    {%- call cs::docstring(meth, 4) %}
+    {%- call cs::method_throws_annotation(meth.throws_type()) %}
+    {{ meth_access }} {% if is_error && meth.name()|method_name(impl_name, config) == "Message" %}new {% endif %}async {% call cs::return_type(meth) %} {{ meth.name()|method_name(impl_name, config)|async_name(meth.is_async(), config) }}({%- call cs::arg_list_decl_with_token(meth, "", "CancellationToken cancellationToken") -%}) {
         {%- call cs::async_call(meth, true) %}
     }
     {%- else %}
+    {{ meth_access }} {% if is_error && meth.name()|method_name(impl_name, config) == "Message" %}new {% endif %}async {% call cs::return_type(meth) %} {{ meth.name()|method_name(impl_name, config)|async_name(meth.is_async(), config) }}({%- call cs::arg_list_decl(meth) -%}) {
+        {%- call cs::async_call(meth, true) %}
+    }
+    {%- endif %}
+    {%- if config.async_blocking() %}
+    {%- call cs::docstring(meth, 4) %}
+    {%- call cs::method_throws_annotation(meth.throws_type()) %}
+    {{ meth_access }} {% call cs::blocking_return_type(meth) %} {{ meth.name()|method_name(impl_name, config) }}Blocking({%- call cs::arg_list_decl(meth) -%}) {
+        {%- call cs::blocking_call(meth, true) %}
+    }
+    {%- endif %}
+    {%- if meth.arguments()|has_bytes_args %}
+#if NET8_0_OR_GREATER
+    {%- call cs::docstring(meth, 4) %}
+    {%- call cs::method_throws_annotation(meth.throws_type()) %}
+    {{ meth_access }} {% if is_error && meth.name()|method_name(impl_name, config) == "Message" %}new {% endif %}async {% call cs::return_type(meth) %} {{ meth.name()|method_name(impl_name, config)|async_name(meth.is_async(), config) }}({%- call cs::arg_list_decl_bytes_as(meth, "ReadOnlyMemory<byte>") -%}) {
+        {%- call cs::async_call(meth, true) %}
+    }
+#endif
+    {%- endif %}
+    {%- else %}
 
     {%- match meth.return_type() -%}
     {%- when Some with (return_type) %}
-    public {% if is_error && meth.name()|method_name(impl_name) == "Message" %}new {% endif %}{{ return_type|type_name(ci) }} {{ meth.name()|method_name(impl_name) }}({% call cs::arg_list_decl(meth) %}) {
+    {{ meth_access }} {% if is_error && meth.name()|method_name(impl_name, config) == "Message" %}new {% endif %}{{ return_type|type_name(ci, config) }} {{ meth.name()|method_name(impl_name, config) }}({% call cs::arg_list_decl(meth) %}) {
         return CallWithPointer(thisPtr => {{ return_type|lift_fn }}({%- call cs::to_ffi_call_with_prefix("thisPtr", meth) %}));
     }
 
     {%- when None %}
-    public {% if is_error && meth.name()|method_name(impl_name) == "Message" %}new {% endif %}void {{ meth.name()|method_name(impl_name) }}({% call cs::arg_list_decl(meth) %}) {
+    {{ meth_access }} {% if is_error && meth.name()|method_name(impl_name, config) == "Message" %}new {% endif %}void {{ meth.name()|method_name(impl_name, config) }}({% call cs::arg_list_decl(meth) %}) {
         CallWithPointer(thisPtr => {%- call cs::to_ffi_call_with_prefix("thisPtr", meth) %});
     }
     {% endmatch %}
+    {%- if meth.arguments()|has_bytes_args %}
+#if NET8_0_OR_GREATER
+    {%- call cs::docstring(meth, 4) %}
+    {%- call cs::method_throws_annotation(meth.throws_type()) %}
+    {%- match meth.return_type() -%}
+    {%- when Some with (return_type) %}
+    {{ meth_access }} {% if is_error && meth.name()|method_name(impl_name, config) == "Message" %}new {% endif %}{{ return_type|type_name(ci, config) }} {{ meth.name()|method_name(impl_name, config) }}({% call cs::arg_list_decl_bytes_as(meth, "ReadOnlySpan<byte>") %}) {
+        {%- call cs::declare_bytes_args(meth) %}
+        try {
+            {%- call cs::lower_bytes_args(meth) %}
+            return CallWithPointer(thisPtr => {{ return_type|lift_fn }}({%- call cs::to_ffi_call_with_prefix_bytes_lowered("thisPtr", meth) %}));
+        } finally {
+            {%- call cs::free_bytes_args(meth) %}
+        }
+    }
+    {%- when None %}
+    {{ meth_access }} {% if is_error && meth.name()|method_name(impl_name, config) == "Message" %}new {% endif %}void {{ meth.name()|method_name(impl_name, config) }}({% call cs::arg_list_decl_bytes_as(meth, "ReadOnlySpan<byte>") %}) {
+        {%- call cs::declare_bytes_args(meth) %}
+        try {
+            {%- call cs::lower_bytes_args(meth) %}
+            CallWithPointer(thisPtr => {%- call cs::to_ffi_call_with_prefix_bytes_lowered("thisPtr", meth) %});
+        } finally {
+            {%- call cs::free_bytes_args(meth) %}
+        }
+    }
+    {%- endmatch %}
+#endif
+    {% endif %}
     {% endif %}
+    {%- endif %}
     {% endfor %}
 
+    {%- if let Some(enumerable) = async_enumerable %}
+    /// <summary>
+    /// Enumerates the values returned by <see cref="{{ enumerable.method.name()|method_name(impl_name, config)|async_name(true, config) }}"/> until it returns null.
+    /// This object is disposed when the enumeration ends.
+    /// </summary>
+    public async IAsyncEnumerator<{{ enumerable.item_type|type_name(ci, config) }}> GetAsyncEnumerator(CancellationToken cancellationToken = default) {
+        try {
+            while (true) {
+                var item = await {{ enumerable.method.name()|method_name(impl_name, config)|async_name(true, config) }}(cancellationToken);
+                {%- if enumerable.item_type|is_optional %}
+                if (!item.HasValue) {
+                    yield break;
+                }
+                yield return item.Value;
+                {%- else %}
+                if (item is not {} value) {
+                    yield break;
+                }
+                yield return value;
+                {%- endif %}
+            }
+        } finally {
+            Dispose();
+        }
+    }
+    {%- endif %}
+
     {%- for tm in obj.uniffi_traits() -%}
     {%- match tm %}
     {%- when UniffiTrait::Display { fmt } %}
@@ -166,28 +305,63 @@
         if (obj is null || !(obj is {{ impl_name }})) return false;
         return Equals(obj as {{ impl_name }});
     }
+    {%- if !config.reference_equality_operators() %}
+    private bool NotEquals({{ impl_name }} other)
+    {
+        return CallWithPointer(thisPtr => {{ Type::Boolean.borrow()|lift_fn }}({%- call cs::to_ffi_call_with_prefix("thisPtr", ne) %}));
+    }
+    public static bool operator ==({{ impl_name }}? left, {{ impl_name }}? right)
+    {
+        if (ReferenceEquals(left, right)) return true;
+        if (left is null || right is null) return false;
+        return left.Equals(right);
+    }
+    public static bool operator !=({{ impl_name }}? left, {{ impl_name }}? right)
+    {
+        if (ReferenceEquals(left, right)) return false;
+        if (left is null || right is null) return true;
+        return left.NotEquals(right);
+    }
+    {%- endif %}
     {%- when UniffiTrait::Hash  { hash }  %}
     public override int GetHashCode() {
         return (int)CallWithPointer(thisPtr => {{ Type::UInt64.borrow()|lift_fn }}({%- call cs::to_ffi_call_with_prefix("thisPtr", hash)  %}));
     }
+    {%- when UniffiTrait::Ord { cmp } %}
+    public int CompareTo({{ impl_name }}? other)
+    {
+        if (other is null) return 1;
+        return CallWithPointer(thisPtr => {{ Type::Int8.borrow()|lift_fn }}({%- call cs::to_ffi_call_with_prefix("thisPtr", cmp) %}));
+    }
+    {%- call cs::comparison_operators(impl_name) %}
     {%- else %}
-    // UniFFI: unrecognized UniffiTrait variant (e.g. Ord) — interface not yet implemented
+    // UniFFI: unrecognized UniffiTrait variant — interface not yet implemented
     {%- endmatch %}
     {%- endfor %}
 
     {% if !obj.alternate_constructors().is_empty() -%}
     {% for cons in obj.alternate_constructors() -%}
+    {%- if !config.is_member_excluded(name, cons.name()) %}
+    {%- let cons_access = config.member_access_modifier(name, cons.name()) %}
     {%- call cs::docstring(cons, 4) %}
     {%- call cs::method_throws_annotation(cons.throws_type()) %}
     {%- if cons.is_async() %}
-    public static async Task<{{ impl_name }}> {{ cons.name()|method_name(impl_name) }} ({%- call cs::arg_list_decl(cons) -%}) {
+    {{ cons_access }} static async {{ config.async_type_name() }}<{{ impl_name }}> {{ cons.name()|method_name(impl_name, config)|async_name(cons.is_async(), config) }} ({%- call cs::arg_list_decl(cons) -%}) {
         {%- call cs::async_call(cons, false) %}
     }
+    {%- if config.async_blocking() %}
+    {%- call cs::docstring(cons, 4) %}
+    {%- call cs::method_throws_annotation(cons.throws_type()) %}
+    {{ cons_access }} static {{ impl_name }} {{ cons.name()|method_name(impl_name, config) }}Blocking({%- call cs::arg_list_decl(cons) -%}) {
+        {%- call cs::blocking_call(cons, false) %}
+    }
+    {%- endif %}
     {%- else %}
-    public static {{ impl_name }} {{ cons.name()|method_name(impl_name) }}({% call cs::arg_list_decl(cons) %}) {
+    {{ cons_access }} static {{ impl_name }} {{ cons.name()|method_name(impl_name, config) }}({% call cs::arg_list_decl(cons) %}) {
         return new {{ impl_name }}({% call cs::to_ffi_call(cons) %});
     }
     {%- endif %}
+    {%- endif %}
     {% endfor %}
     {% endif %}
 }
@@ -240,7 +414,7 @@ class {{ ffi_converter_type }}: FfiConverter<{{ interface_name }}, ulong> {
         return Lift(stream.ReadULong());
     }
 
-    public override int AllocationSize({{ interface_name }} value) {
+    public override long AllocationSize({{ interface_name }} value) {
         return 8;
     }
 
@@ -265,7 +439,7 @@ class {{ ffi_converter_type }}: FfiConverter<{{ impl_name }}, ulong> {
         return Lift(stream.ReadULong());
     }
 
-    public override int AllocationSize({{ impl_name }} value) {
+    public override long AllocationSize({{ impl_name }} value) {
         return 8;
     }
 
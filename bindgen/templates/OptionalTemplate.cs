@@ -2,8 +2,41 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
 
-{%- let inner_type_name = inner_type|type_name(ci) %}
+{%- let inner_type_name = inner_type|type_name(ci, config) %}
 
+{%- if inner_type|is_optional %}
+{%- if self.include_once_check("UniffiOptional.cs") %}
+{% include "UniffiOptional.cs" %}
+{%- endif %}
+
+class {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
+    public static {{ ffi_converter_name }} INSTANCE = new {{ ffi_converter_name }}();
+
+    public override {{ type_name }} Read(BigEndianStream stream) {
+        if (stream.ReadByte() == 0) {
+            return default;
+        }
+        return new {{ type_name }}({{ inner_type|read_fn }}(stream));
+    }
+
+    public override long AllocationSize({{ type_name }} value) {
+        if (!value.HasValue) {
+            return 1;
+        } else {
+            return 1 + {{ inner_type|allocation_size_fn }}(value.Value);
+        }
+    }
+
+    public override void Write({{ type_name }} value, BigEndianStream stream) {
+        if (!value.HasValue) {
+            stream.WriteByte(0);
+        } else {
+            stream.WriteByte(1);
+            {{ inner_type|write_fn }}(value.Value, stream);
+        }
+    }
+}
+{%- else %}
 class {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ inner_type_name }}?> {
     public static {{ ffi_converter_name }} INSTANCE = new {{ ffi_converter_name }}();
 
@@ -14,7 +47,7 @@ class {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ inner_type_name }}?> {
         return {{ inner_type|read_fn }}(stream);
     }
 
-    public override int AllocationSize({{ inner_type_name }}? value) {
+    public override long AllocationSize({{ inner_type_name }}? value) {
         if (value == null) {
             return 1;
         } else {
@@ -31,3 +64,4 @@ class {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ inner_type_name }}?> {
         }
     }
 }
+{%- endif %}
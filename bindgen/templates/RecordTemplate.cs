@@ -6,8 +6,17 @@
 {%- let (ordered_fields, is_reordered) = rec.fields()|order_fields %}
 {%- let rec_ffi_converter = rec|ffi_converter_name %}
 {%- let self_lower_prefix = format!("{}.INSTANCE.Lower(this)", rec_ffi_converter) %}
+{%- let uniffi_trait_methods = rec.uniffi_trait_methods() %}
+{%- let is_class = config.is_class_record(rec) %}
+{%- let is_observable = config.is_observable_record(rec) %}
+{%- let is_struct = config.is_struct_record(rec) %}
+{%- if is_observable %}
+{{- self.add_import("System.Collections.Generic") }}
+{{- self.add_import("System.ComponentModel") }}
+{%- endif %}
 
 {%- call cs::docstring(rec, 0) %}
+{%- if !is_class %}
 {%- for field in ordered_fields %}
 {%- match field.docstring() %}
 {%- when Some with(docstring) %}
@@ -20,6 +29,7 @@
 {%- else %}
 {%- endmatch %}
 {%- endfor %}
+{%- endif %}
 {%- let (ordered_fields, is_reordered) = rec.fields()|order_fields %}
 {%- if is_reordered %}
 /// <remarks>
@@ -28,28 +38,112 @@
 /// future ordering changes.
 /// </remarks>
 {%- endif %}
-{{ config.access_modifier() }} record {{ type_name }} (
+{%- if is_class %}
+{%- let has_eq = uniffi_trait_methods.eq_eq.is_some() %}
+{%- let has_ord = uniffi_trait_methods.ord_cmp.is_some() %}
+{{ config.type_access_modifier(rec.name()) }} class {{ type_name }}
+    {%- if is_observable || contains_object_references || has_eq || has_ord %} : {% endif %}
+    {%- if is_observable %}INotifyPropertyChanged{% if contains_object_references || has_eq || has_ord %}, {% endif %}{% endif %}
+    {%- if contains_object_references %}IDisposable{% if has_eq || has_ord %}, {% endif %}{% endif %}
+    {%- if has_eq %}IEquatable<{{ type_name }}>{% if has_ord %}, {% endif %}{% endif %}
+    {%- if has_ord %}IComparable<{{ type_name }}>{% endif %} {
+    {%- if is_observable %}
+    public event PropertyChangedEventHandler? PropertyChanged;
+    {%- endif %}
+
+    {%- for field in rec.fields() %}
+    {%- let field_type = field|field_type_name(rec.name(), ci, config) %}
+    {%- let prop_name = field.name()|property_name %}
+    {%- if is_observable %}
+
+    private {{ field_type }} {{ field.name()|backing_field_name }};
+    {%- call cs::docstring(field, 4) %}
+    public {{ field_type }} {{ prop_name }} {
+        get => {{ field.name()|backing_field_name }};
+        set {
+            if (EqualityComparer<{{ field_type }}>.Default.Equals({{ field.name()|backing_field_name }}, value)) {
+                return;
+            }
+            {{ field.name()|backing_field_name }} = value;
+            PropertyChanged?.Invoke(this, new PropertyChangedEventArgs(nameof({{ prop_name }})));
+        }
+    }
+    {%- else %}
+    {%- call cs::docstring(field, 4) %}
+    public {{ field_type }} {{ prop_name }} { get; set; }
+    {%- endif %}
+    {%- endfor %}
+
+    public {{ type_name }}(
+        {%- for field in ordered_fields %}
+        {{ field|field_type_name(rec.name(), ci, config) }} {{ field.name()|property_name -}}
+        {%- match field.default_value() %}
+            {%- when Some with(literal) %} = {{ literal|render_literal(field, ci, config) }}
+            {%- else %}
+        {%- endmatch -%}
+        {% if !loop.last %}, {% endif %}
+        {%- endfor %}
+    ) {
+        {%- for field in rec.fields() %}
+        {%- if is_observable %}
+        this.{{ field.name()|backing_field_name }} = {{ field.name()|property_name }};
+        {%- else %}
+        this.{{ field.name()|property_name }} = {{ field.name()|property_name }};
+        {%- endif %}
+        {%- endfor %}
+    }
+    {%- if has_eq %}
+
+    public override bool Equals(object? other) {
+        return Equals(other as {{ type_name }});
+    }
+    {%- endif %}
+{%- else %}
+{{ config.type_access_modifier(rec.name()) }} {% if is_struct %}readonly record struct{% else %}record{% endif %} {{ type_name }} (
     {%- for field in ordered_fields %}
     {%- call cs::docstring(field, 4) %}
-    {{ field|type_name(ci) }} {{ field.name()|property_name -}}
+    {{ field|field_type_name(rec.name(), ci, config) }} {{ field.name()|property_name -}}
     {%- match field.default_value() %}
-        {%- when Some with(literal) %} = {{ literal|render_literal(field, ci) }}
+        {%- when Some with(literal) %} = {{ literal|render_literal(field, ci, config) }}
         {%- else %}
     {%- endmatch -%}
     {% if !loop.last %}, {% endif %}
     {%- endfor %}
-) {% if contains_object_references %}: IDisposable {% endif %}{
+) {% call cs::value_type_interfaces(type_name, contains_object_references, uniffi_trait_methods) %}{
+{%- endif %}
     {%- if contains_object_references %}
     public void Dispose() {
         {%- call cs::destroy_fields(rec, "this") %}
     }
     {%- endif %}
 
+    {%- if !is_class && uniffi_trait_methods.eq_eq.is_none() && rec.fields()|has_compound_fields %}
+
+    public {% if !is_struct %}virtual {% endif %}bool Equals({{ type_name }}{% if !is_struct %}?{% endif %} other) {
+        {%- if !is_struct %}
+        if (other is null) {
+            return false;
+        }
+        {%- endif %}
+        return
+        {%- for field in rec.fields() %}
+            {% if !loop.first %}&& {% endif %}UniffiStructuralEquality.AreEqual(this.{{ field.name()|property_name }}, other.{{ field.name()|property_name }})
+        {%- endfor %};
+    }
+
+    public override int GetHashCode() {
+        var hash = 17;
+        {%- for field in rec.fields() %}
+        hash = unchecked(hash * 31 + UniffiStructuralEquality.HashOf(this.{{ field.name()|property_name }}));
+        {%- endfor %}
+        return hash;
+    }
+    {%- endif %}
+
     {%- if !rec.methods().is_empty() %}
     {%- call cs::value_type_methods(rec.methods(), self_lower_prefix) %}
     {%- endif %}
 
-    {%- let uniffi_trait_methods = rec.uniffi_trait_methods() %}
     {%- call cs::value_type_uniffi_traits(uniffi_trait_methods, self_lower_prefix) %}
 }
 
@@ -59,12 +153,12 @@ class {{ rec|ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
     public override {{ type_name }} Read(BigEndianStream stream) {
         return new {{ type_name }}(
         {%- for field in rec.fields() %}
-            {{ field.name()|property_name }}: {{ field|read_fn }}(stream){% if !loop.last %},{% endif%}
+            {{ field.name()|property_name }}: {{ field|read_field(rec.name(), config) }}{% if !loop.last %},{% endif%}
         {%- endfor %}
         );
     }
 
-    public override int AllocationSize({{ type_name }} value) {
+    public override long AllocationSize({{ type_name }} value) {
         return 0
         {%- for field in rec.fields() %}
             + {{ field|allocation_size_fn }}(value.{{ field.name()|property_name }})
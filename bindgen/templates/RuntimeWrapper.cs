@@ -0,0 +1,61 @@
+{#/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+// FFI runtime shared by bindings of multiple components, used when `shared_runtime` is enabled.
+//
+// Bindings of each component import this namespace instead of inlining their own copy of the
+// runtime, so all components share one exception hierarchy and one set of converters for
+// builtin types. The runtime must be generated with the same `uniffi-bindgen-cs` version as the
+// bindings that use it.
+#}
+
+{%- import "macros.cs" as cs %}
+
+{{- self.add_import("System") }}
+{{- self.add_import("System.Collections.Concurrent") }}
+{{- self.add_import("System.Collections.Generic") }}
+{{- self.add_import("System.Diagnostics.CodeAnalysis") }}
+{{- self.add_import("System.IO") }}
+{{- self.add_import("System.Linq") }}
+{{- self.add_import("System.Runtime.CompilerServices") }}
+{{- self.add_import("System.Runtime.InteropServices") }}
+{{- self.add_import("System.Threading") }}
+
+{%- for imported_class in self.imports() %}
+using {{ imported_class }};
+{%- endfor %}
+
+namespace {{ config.runtime_namespace() }};
+
+{% include "RustBufferTemplate.cs" %}
+{% include "FfiConverterTemplate.cs" %}
+{% include "Helpers.cs" %}
+{% include "BigEndianStream.cs" %}
+{% include "ConcurrentHandleMap.cs" %}
+{% include "CallbackResponseStatus.cs" %}
+
+// Native functions used by the runtime itself. Functions specific to a component are declared
+// in the bindings of that component.
+#if NET8_0_OR_GREATER
+static partial class _UniFFILib {
+#else
+static class _UniFFILib {
+#endif
+    {% for func in self.ffi_functions() -%}
+#if NET8_0_OR_GREATER
+    [LibraryImport("{{ config.cdylib_name() }}")]
+    [UnmanagedCallConv(CallConvs = new[] { typeof(CallConvCdecl) })]
+    public static partial
+#else
+    [DllImport("{{ config.cdylib_name() }}", CallingConvention = CallingConvention.Cdecl)]
+    public static extern
+#endif
+    {% match func.return_type() -%}{%- when Some with (type_) %} {{ type_.borrow()|ffi_type_name }}{% when None %} void{% endmatch %} {{ func.name() }}(
+        {%- call cs::arg_list_ffi_decl(func) %}
+    );
+
+    {% endfor %}
+}
+
+{{ type_helper_code }}
@@ -7,12 +7,12 @@
 // pointer to the underlying data.
 
 [StructLayout(LayoutKind.Sequential)]
-internal struct RustBuffer {
+{{ config.runtime_access_modifier() }} struct RustBuffer {
     public ulong capacity;
     public ulong len;
     public IntPtr data;
 
-    public static RustBuffer Alloc(int size) {
+    public static RustBuffer Alloc(long size) {
         return _UniffiHelpers.RustCall((ref UniffiRustCallStatus status) => {
             var buffer = _UniFFILib.{{ ci.ffi_rustbuffer_alloc().name() }}(Convert.ToUInt64(size), ref status);
             if (buffer.data == IntPtr.Zero) {
@@ -30,35 +30,17 @@ internal struct RustBuffer {
 
     public static BigEndianStream MemoryStream(IntPtr data, long length)
     {
-        unsafe
-        {
-            return new BigEndianStream(new UnmanagedMemoryStream((byte*)data.ToPointer(), length));
-        }
+        return new BigEndianStream(data, length);
     }
 
     public BigEndianStream AsStream()
     {
-        unsafe
-        {
-            return new BigEndianStream(
-                new UnmanagedMemoryStream((byte*)data.ToPointer(), Convert.ToInt64(len))
-            );
-        }
+        return new BigEndianStream(data, Convert.ToInt64(len));
     }
 
     public BigEndianStream AsWriteableStream()
     {
-        unsafe
-        {
-            return new BigEndianStream(
-                new UnmanagedMemoryStream(
-                    (byte*)data.ToPointer(),
-                    Convert.ToInt64(capacity),
-                    Convert.ToInt64(capacity),
-                    FileAccess.Write
-                )
-            );
-        }
+        return new BigEndianStream(data, Convert.ToInt64(capacity));
     }
 }
 
@@ -69,7 +51,7 @@ internal struct RustBuffer {
 // completeness.
 
 [StructLayout(LayoutKind.Sequential)]
-internal struct ForeignBytes {
+{{ config.runtime_access_modifier() }} struct ForeignBytes {
     public int length;
     public IntPtr data;
 }
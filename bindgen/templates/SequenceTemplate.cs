@@ -2,47 +2,156 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
 
-{%- let inner_type_name = inner_type|type_name(ci) %}
+{%- let inner_type_name = inner_type|type_name(ci, config) %}
 
-class {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ inner_type_name }}[]> {
+class {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
     public static {{ ffi_converter_name }} INSTANCE = new {{ ffi_converter_name }}();
 
-    public override {{ inner_type_name }}[]  Read(BigEndianStream stream) {
-        var length = stream.ReadInt();
+    public override {{ type_name }} Read(BigEndianStream stream) {
+        var length = stream.ReadLength();
+        {%- match config.sequence_kind() %}
+        {%- when SequenceKind::List %}
+        var result = new List<{{ inner_type_name }}>(length);
+        for (int i = 0; i < length; i++) {
+            result.Add({{ inner_type|read_fn }}(stream));
+        }
+        return result;
+        {%- when SequenceKind::ImmutableArray %}
+        if (length == 0) {
+            return ImmutableArray<{{ inner_type_name }}>.Empty;
+        }
+
+        var result = ImmutableArray.CreateBuilder<{{ inner_type_name }}>(length);
+        for (int i = 0; i < length; i++) {
+            result.Add({{ inner_type|read_fn }}(stream));
+        }
+        return result.MoveToImmutable();
+        {%- else %}
         if (length == 0) {
             return [];
         }
 
         var result = {{ inner_type_name|array_new_expr }};
-        var readFn = {{ inner_type|read_fn }};
         for (int i = 0; i < length; i++) {
-            result[i] = readFn(stream);
+            result[i] = {{ inner_type|read_fn }}(stream);
         }
         return result;
+        {%- endmatch %}
+    }
+
+    // Sequences of any collection type are accepted as input, so arguments and fields can be
+    // configured with another type than the one returned by `Read`.
+    public RustBuffer Lower(IEnumerable<{{ inner_type_name }}> value) {
+        {%- match config.sequence_kind() %}
+        {%- when SequenceKind::Array %}
+        return LowerIntoRustBuffer(value as {{ type_name }} ?? value?.ToArray()!);
+        {%- when SequenceKind::List %}
+        return LowerIntoRustBuffer(value as {{ type_name }} ?? value?.ToList()!);
+        {%- when SequenceKind::ReadOnlyList %}
+        return LowerIntoRustBuffer(value as {{ type_name }} ?? value?.ToArray()!);
+        {%- when SequenceKind::ImmutableArray %}
+        return LowerIntoRustBuffer(value is {{ type_name }} array ? array : value == null ? default : ImmutableArray.CreateRange(value));
+        {%- endmatch %}
     }
 
-    public override int AllocationSize({{ inner_type_name }}[]  value) {
+    // The typed overloads iterate the concrete collection, so that lowering doesn't allocate an
+    // enumerator.
+    public override long AllocationSize({{ type_name }} value) {
         var sizeForLength = 4;
 
         // details/1-empty-list-as-default-method-parameter.md
+        {%- match config.sequence_kind() %}
+        {%- when SequenceKind::ImmutableArray %}
+        if (value.IsDefault) {
+        {%- when SequenceKind::ReadOnlyList %}
+        if (IsEmptyDefault(value)) {
+        {%- else %}
         if (value == null) {
+        {%- endmatch %}
             return sizeForLength;
         }
 
-        var allocationSizeFn = {{ inner_type|allocation_size_fn }};
-        var sizeForItems = value.Sum(item => allocationSizeFn(item));
+        var sizeForItems = 0L;
+        {%- if config.sequence_kind() == SequenceKind::ReadOnlyList %}
+        for (var i = 0; i < value.Count; i++) {
+            sizeForItems += {{ inner_type|allocation_size_fn }}(value[i]);
+        }
+        {%- else %}
+        foreach (var item in value) {
+            sizeForItems += {{ inner_type|allocation_size_fn }}(item);
+        }
+        {%- endif %}
         return sizeForLength + sizeForItems;
     }
 
-    public override void Write({{ inner_type_name }}[] value, BigEndianStream stream) {
+    // Fields and arguments configured with another collection type than `Read` returns.
+    public long AllocationSize(IReadOnlyCollection<{{ inner_type_name }}> value) {
+        var sizeForLength = 4;
+
         // details/1-empty-list-as-default-method-parameter.md
+        if (IsEmptyDefault(value)) {
+            return sizeForLength;
+        }
+
+        var sizeForItems = 0L;
+        foreach (var item in value) {
+            sizeForItems += {{ inner_type|allocation_size_fn }}(item);
+        }
+        return sizeForLength + sizeForItems;
+    }
+
+    public override void Write({{ type_name }} value, BigEndianStream stream) {
+        // details/1-empty-list-as-default-method-parameter.md
+        {%- match config.sequence_kind() %}
+        {%- when SequenceKind::ImmutableArray %}
+        if (value.IsDefault) {
+        {%- when SequenceKind::ReadOnlyList %}
+        if (IsEmptyDefault(value)) {
+        {%- else %}
         if (value == null) {
+        {%- endmatch %}
             stream.WriteInt(0);
             return;
         }
 
+        {%- match config.sequence_kind() %}
+        {%- when SequenceKind::ReadOnlyList %}
+        stream.WriteInt(value.Count);
+        for (var i = 0; i < value.Count; i++) {
+            {{ inner_type|write_fn }}(value[i], stream);
+        }
+        {%- when SequenceKind::List %}
+        stream.WriteInt(value.Count);
+        foreach (var item in value) {
+            {{ inner_type|write_fn }}(item, stream);
+        }
+        {%- else %}
         stream.WriteInt(value.Length);
-        var writerFn = {{ inner_type|write_fn }};
-        value.ForEach(item => writerFn(item, stream));
+        foreach (var item in value) {
+            {{ inner_type|write_fn }}(item, stream);
+        }
+        {%- endmatch %}
+    }
+
+    // Fields and arguments configured with another collection type than `Read` returns.
+    public void Write(IReadOnlyCollection<{{ inner_type_name }}> value, BigEndianStream stream) {
+        // details/1-empty-list-as-default-method-parameter.md
+        if (IsEmptyDefault(value)) {
+            stream.WriteInt(0);
+            return;
+        }
+
+        stream.WriteInt(value.Count);
+        foreach (var item in value) {
+            {{ inner_type|write_fn }}(item, stream);
+        }
+    }
+
+    static bool IsEmptyDefault(IReadOnlyCollection<{{ inner_type_name }}> value) {
+        {%- if config.uses_immutable_arrays() %}
+        return value == null || value is ImmutableArray<{{ inner_type_name }}> { IsDefault: true };
+        {%- else %}
+        return value == null;
+        {%- endif %}
     }
 }
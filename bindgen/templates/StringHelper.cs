@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
 
-class FfiConverterString: FfiConverter<string, RustBuffer> {
+{{ config.runtime_access_modifier() }} class FfiConverterString: FfiConverter<string, RustBuffer> {
     public static FfiConverterString INSTANCE = new FfiConverterString();
 
     // Note: we don't inherit from FfiConverterRustBuffer, because we use a
@@ -10,17 +10,19 @@ class FfiConverterString: FfiConverter<string, RustBuffer> {
     // store our length and avoid writing it out to the buffer.
     public override string Lift(RustBuffer value) {
         try {
-            var bytes = value.AsStream().ReadBytes(Convert.ToInt32(value.len));
-            return System.Text.Encoding.UTF8.GetString(bytes);
+            if (value.len == 0) {
+                return "";
+            }
+            unsafe {
+                return BigEndianStream.DecodeUtf8((byte*)value.data.ToPointer(), Convert.ToInt64(value.len));
+            }
         } finally {
             RustBuffer.Free(value);
         }
     }
 
     public override string Read(BigEndianStream stream) {
-        var length = stream.ReadInt();
-        var bytes = stream.ReadBytes(length);
-        return System.Text.Encoding.UTF8.GetString(bytes);
+        return stream.ReadString();
     }
 
     public override RustBuffer Lower(string value) {
@@ -31,9 +33,13 @@ class FfiConverterString: FfiConverter<string, RustBuffer> {
         }
         {%- when _ %}
         {%- endmatch %}
-        var bytes = System.Text.Encoding.UTF8.GetBytes(value);
-        var rbuf = RustBuffer.Alloc(bytes.Length);
-        rbuf.AsWriteableStream().WriteBytes(bytes);
+        // Encode straight into the `RustBuffer`, without an intermediate managed array. The length
+        // is the `RustBuffer` length rather than a 32 bit prefix, so it can be over 2 GiB.
+        var length = BigEndianStream.Utf8ByteCount(value);
+        var rbuf = RustBuffer.Alloc(length);
+        unsafe {
+            BigEndianStream.EncodeUtf8(value, (byte*)rbuf.data.ToPointer(), length);
+        }
         return rbuf;
     }
 
@@ -41,15 +47,13 @@ class FfiConverterString: FfiConverter<string, RustBuffer> {
     // We aren't sure exactly how many bytes our string will be once it's UTF-8
     // encoded.  Allocate 3 bytes per unicode codepoint which will always be
     // enough.
-    public override int AllocationSize(string value) {
+    public override long AllocationSize(string value) {
         const int sizeForLength = 4;
-        var sizeForString = System.Text.Encoding.UTF8.GetByteCount(value);
+        var sizeForString = BigEndianStream.Utf8ByteCount(value);
         return sizeForLength + sizeForString;
     }
 
     public override void Write(string value, BigEndianStream stream) {
-        var bytes = System.Text.Encoding.UTF8.GetBytes(value);
-        stream.WriteInt(bytes.Length);
-        stream.WriteBytes(bytes);
+        stream.WriteString(value);
     }
 }
@@ -2,42 +2,182 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
 
-class FfiConverterTimestamp: FfiConverterRustBuffer<DateTime> {
-    public static FfiConverterTimestamp INSTANCE = new FfiConverterTimestamp();
+{%- if self.include_once_check("UniffiTimeOverflowException.cs") %}
+{% include "UniffiTimeOverflowException.cs" %}
+{%- endif %}
+
+// Timestamp as seconds and nanoseconds since the Unix epoch, like Rust's `SystemTime`, without
+// losing precision or range. Seconds are rounded down, so nanoseconds are never negative.
+{{ config.access_modifier() }} readonly struct UniffiTimestamp : IEquatable<UniffiTimestamp>, IComparable<UniffiTimestamp> {
+    const uint NanosecondsPerSecond = 1_000_000_000;
 
     // https://github.com/dotnet/runtime/blob/main/src/libraries/System.Private.CoreLib/src/System/TimeSpan.cs
-    private const uint NanosecondsPerTick = 100;
+    const uint NanosecondsPerTick = 100;
 
     // DateTime.UnixEpoch is not available in net48
-    private static readonly DateTime UnixEpoch = new DateTime(1970, 1, 1, 0, 0, 0, DateTimeKind.Utc);
+    static readonly long UnixEpochTicks = new DateTime(1970, 1, 1, 0, 0, 0, DateTimeKind.Utc).Ticks;
+
+    internal static readonly UniffiTimestamp MinDateTime = FromDateTime(DateTime.MinValue);
+    internal static readonly UniffiTimestamp MaxDateTime = FromDateTime(DateTime.MaxValue);
+    {%- if config.timestamp_kind() == TimestampKind::NodaTime %}
+    internal static readonly UniffiTimestamp MinInstant = FromInstant(NodaTime.Instant.MinValue);
+    internal static readonly UniffiTimestamp MaxInstant = FromInstant(NodaTime.Instant.MaxValue);
+    {%- endif %}
+
+    public UniffiTimestamp(long seconds, uint nanoseconds) {
+        if (nanoseconds >= NanosecondsPerSecond) {
+            throw new ArgumentOutOfRangeException(nameof(nanoseconds), nanoseconds, "must be less than a second");
+        }
+        Seconds = seconds;
+        Nanoseconds = nanoseconds;
+    }
+
+    public long Seconds { get; }
+
+    public uint Nanoseconds { get; }
+
+    // The kind of `value` is ignored, so local times should be converted to UTC first.
+    public static UniffiTimestamp FromDateTime(DateTime value) {
+        var ticks = value.Ticks - UnixEpochTicks;
+        var seconds = ticks / TimeSpan.TicksPerSecond;
+        var subsecondTicks = ticks % TimeSpan.TicksPerSecond;
+        if (subsecondTicks < 0) {
+            seconds -= 1;
+            subsecondTicks += TimeSpan.TicksPerSecond;
+        }
+        return new UniffiTimestamp(seconds, (uint)subsecondTicks * NanosecondsPerTick);
+    }
+
+    public static UniffiTimestamp FromDateTimeOffset(DateTimeOffset value) {
+        return FromDateTime(value.UtcDateTime);
+    }
+
+    // Nanoseconds are truncated to ticks of 100 nanoseconds.
+    public DateTime ToDateTime() {
+        return new DateTime(ToTicks("DateTime"), DateTimeKind.Utc);
+    }
+
+    // Nanoseconds are truncated to ticks of 100 nanoseconds.
+    public DateTimeOffset ToDateTimeOffset() {
+        return new DateTimeOffset(ToTicks("DateTimeOffset"), TimeSpan.Zero);
+    }
+
+    long ToTicks(string typeName) {
+        if (this < MinDateTime || this > MaxDateTime) {
+            throw new UniffiTimeOverflowException($"{this} is outside the range of {typeName}");
+        }
+        return UnixEpochTicks + Seconds * TimeSpan.TicksPerSecond + Nanoseconds / NanosecondsPerTick;
+    }
+    {%- if config.timestamp_kind() == TimestampKind::NodaTime %}
+
+    public static UniffiTimestamp FromInstant(NodaTime.Instant value) {
+        var seconds = value.ToUnixTimeSeconds();
+        var nanoseconds = (value - NodaTime.Instant.FromUnixTimeSeconds(seconds)).ToInt64Nanoseconds();
+        return new UniffiTimestamp(seconds, (uint)nanoseconds);
+    }
 
-    public override DateTime Read(BigEndianStream stream) {
+    public NodaTime.Instant ToInstant() {
+        if (this < MinInstant || this > MaxInstant) {
+            throw new UniffiTimeOverflowException($"{this} is outside the range of Instant");
+        }
+        return NodaTime.Instant.FromUnixTimeSeconds(Seconds).PlusNanoseconds(Nanoseconds);
+    }
+    {%- endif %}
+
+    public int CompareTo(UniffiTimestamp other) {
+        var seconds = Seconds.CompareTo(other.Seconds);
+        return seconds != 0 ? seconds : Nanoseconds.CompareTo(other.Nanoseconds);
+    }
+
+    public bool Equals(UniffiTimestamp other) {
+        return Seconds == other.Seconds && Nanoseconds == other.Nanoseconds;
+    }
+
+    public override bool Equals(object? obj) {
+        return obj is UniffiTimestamp other && Equals(other);
+    }
+
+    public override int GetHashCode() {
+        return unchecked(Seconds.GetHashCode() * 31 + Nanoseconds.GetHashCode());
+    }
+
+    public override string ToString() {
+        return $"UniffiTimestamp {{ Seconds = {Seconds}, Nanoseconds = {Nanoseconds} }}";
+    }
+
+    public static bool operator ==(UniffiTimestamp left, UniffiTimestamp right) => left.Equals(right);
+    public static bool operator !=(UniffiTimestamp left, UniffiTimestamp right) => !left.Equals(right);
+    public static bool operator <(UniffiTimestamp left, UniffiTimestamp right) => left.CompareTo(right) < 0;
+    public static bool operator <=(UniffiTimestamp left, UniffiTimestamp right) => left.CompareTo(right) <= 0;
+    public static bool operator >(UniffiTimestamp left, UniffiTimestamp right) => left.CompareTo(right) > 0;
+    public static bool operator >=(UniffiTimestamp left, UniffiTimestamp right) => left.CompareTo(right) >= 0;
+}
+
+{%- let timestamp_kind = config.timestamp_kind() %}
+{%- let timestamp_type = timestamp_kind.type_label() %}
+
+{{ config.runtime_access_modifier() }} class FfiConverterTimestamp: FfiConverterRustBuffer<{{ timestamp_type }}> {
+    public static FfiConverterTimestamp INSTANCE = new FfiConverterTimestamp();
+
+    const uint NanosecondsPerSecond = 1_000_000_000;
+
+    public override {{ timestamp_type }} Read(BigEndianStream stream) {
+        // Times before the epoch are sent as the negated seconds and nanoseconds before it.
         var seconds = stream.ReadLong();
         var nanoseconds = stream.ReadUInt();
-        var sign = 1;
-        if (seconds < 0) {
-            sign = -1;
-        }
-        var ticks = seconds * TimeSpan.TicksPerSecond;
-        ticks += (nanoseconds / NanosecondsPerTick) * sign;
-        return UnixEpoch.AddTicks(ticks);
+        var value = seconds < 0 && nanoseconds > 0
+            ? new UniffiTimestamp(seconds - 1, NanosecondsPerSecond - nanoseconds)
+            : new UniffiTimestamp(seconds, nanoseconds);
+        {%- match timestamp_kind %}
+        {%- when TimestampKind::DateTime %}
+        {%- if config.clamps_time() %}
+        value = Clamp(value, UniffiTimestamp.MinDateTime, UniffiTimestamp.MaxDateTime);
+        {%- endif %}
+        return value.ToDateTime();
+        {%- when TimestampKind::DateTimeOffset %}
+        {%- if config.clamps_time() %}
+        value = Clamp(value, UniffiTimestamp.MinDateTime, UniffiTimestamp.MaxDateTime);
+        {%- endif %}
+        return value.ToDateTimeOffset();
+        {%- when TimestampKind::UniffiTimestamp %}
+        return value;
+        {%- when TimestampKind::NodaTime %}
+        {%- if config.clamps_time() %}
+        value = Clamp(value, UniffiTimestamp.MinInstant, UniffiTimestamp.MaxInstant);
+        {%- endif %}
+        return value.ToInstant();
+        {%- endmatch %}
     }
 
-    public override int AllocationSize(DateTime value) {
+    public override long AllocationSize({{ timestamp_type }} value) {
         // 8 bytes for seconds, 4 bytes for nanoseconds
         return 12;
     }
 
-    public override void Write(DateTime value, BigEndianStream stream) {
-        var epochOffset = value.Subtract(UnixEpoch);
-
-        int sign = 1;
-        if (epochOffset.Ticks < 0) {
-            epochOffset = epochOffset.Negate();
-            sign = -1;
+    public override void Write({{ timestamp_type }} value, BigEndianStream stream) {
+        {%- match timestamp_kind %}
+        {%- when TimestampKind::DateTime %}
+        var timestamp = UniffiTimestamp.FromDateTime(value);
+        {%- when TimestampKind::DateTimeOffset %}
+        var timestamp = UniffiTimestamp.FromDateTimeOffset(value);
+        {%- when TimestampKind::UniffiTimestamp %}
+        var timestamp = value;
+        {%- when TimestampKind::NodaTime %}
+        var timestamp = UniffiTimestamp.FromInstant(value);
+        {%- endmatch %}
+        // Times in the second before the epoch can't be sent, see https://github.com/mozilla/uniffi-rs/issues/1433
+        if (timestamp.Seconds < 0 && timestamp.Nanoseconds > 0) {
+            stream.WriteLong(timestamp.Seconds + 1);
+            stream.WriteUInt(NanosecondsPerSecond - timestamp.Nanoseconds);
+        } else {
+            stream.WriteLong(timestamp.Seconds);
+            stream.WriteUInt(timestamp.Nanoseconds);
         }
+    }
+    {%- if config.clamps_time() && timestamp_kind != TimestampKind::UniffiTimestamp %}
 
-        stream.WriteLong(epochOffset.Ticks / TimeSpan.TicksPerSecond * sign);
-        stream.WriteUInt(Convert.ToUInt32(epochOffset.Ticks % TimeSpan.TicksPerSecond * NanosecondsPerTick));
+    static UniffiTimestamp Clamp(UniffiTimestamp value, UniffiTimestamp min, UniffiTimestamp max) {
+        return value < min ? min : value > max ? max : value;
     }
+    {%- endif %}
 }
@@ -2,22 +2,67 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
 
+{%- let func_access = config.function_access_modifier(func.name()) %}
 {%- call cs::docstring(func, 4) %}
 {%- call cs::method_throws_annotation(func.throws_type()) %}
 {%- if func.is_async() %}
-   public static async {% call cs::return_type(func) %} {{ func.name()|fn_name }}({%- call cs::arg_list_decl(func) -%}) 
+   {{ func_access }} static async {% call cs::return_type(func) %} {{ func.name()|fn_name|async_name(func.is_async(), config) }}({%- call cs::arg_list_decl(func) -%}) 
    {
         {%- call cs::async_call(func, false) %}
    }
+{%- if config.async_blocking() %}
+{%- call cs::docstring(func, 4) %}
+{%- call cs::method_throws_annotation(func.throws_type()) %}
+    {{ func_access }} static {% call cs::blocking_return_type(func) %} {{ func.name()|fn_name }}Blocking({%- call cs::arg_list_decl(func) -%}) {
+        {%- call cs::blocking_call(func, false) %}
+    }
+{%- endif %}
+{%- if func.arguments()|has_bytes_args %}
+#if NET8_0_OR_GREATER
+{%- call cs::docstring(func, 4) %}
+{%- call cs::method_throws_annotation(func.throws_type()) %}
+    {{ func_access }} static async {% call cs::return_type(func) %} {{ func.name()|fn_name|async_name(func.is_async(), config) }}({%- call cs::arg_list_decl_bytes_as(func, "ReadOnlyMemory<byte>") -%}) {
+        {%- call cs::async_call(func, false) %}
+    }
+#endif
+{%- endif %}
 {%- else %}
 {%- match func.return_type() -%}
 {%- when Some with (return_type) %}
-    public static {{ return_type|type_name(ci) }} {{ func.name()|fn_name }}({%- call cs::arg_list_decl(func) -%}) {
+    {{ func_access }} static {{ return_type|type_name(ci, config) }} {{ func.name()|fn_name }}({%- call cs::arg_list_decl(func) -%}) {
         return {{ return_type|lift_fn }}({% call cs::to_ffi_call(func) %});
     }
 {% when None %}
-    public static void {{ func.name()|fn_name }}({% call cs::arg_list_decl(func) %}) {
+    {{ func_access }} static void {{ func.name()|fn_name }}({% call cs::arg_list_decl(func) %}) {
         {% call cs::to_ffi_call(func) %};
     }
 {% endmatch %}
+{%- if func.arguments()|has_bytes_args %}
+#if NET8_0_OR_GREATER
+{%- call cs::docstring(func, 4) %}
+{%- call cs::method_throws_annotation(func.throws_type()) %}
+{%- match func.return_type() -%}
+{%- when Some with (return_type) %}
+    {{ func_access }} static {{ return_type|type_name(ci, config) }} {{ func.name()|fn_name }}({%- call cs::arg_list_decl_bytes_as(func, "ReadOnlySpan<byte>") -%}) {
+        {%- call cs::declare_bytes_args(func) %}
+        try {
+            {%- call cs::lower_bytes_args(func) %}
+            return {{ return_type|lift_fn }}({% call cs::to_ffi_call_bytes_lowered(func) %});
+        } finally {
+            {%- call cs::free_bytes_args(func) %}
+        }
+    }
+{%- when None %}
+    {{ func_access }} static void {{ func.name()|fn_name }}({% call cs::arg_list_decl_bytes_as(func, "ReadOnlySpan<byte>") %}) {
+        {%- call cs::declare_bytes_args(func) %}
+        try {
+            {%- call cs::lower_bytes_args(func) %}
+            {% call cs::to_ffi_call_bytes_lowered(func) %};
+        } finally {
+            {%- call cs::free_bytes_args(func) %}
+        }
+    }
+{%- endmatch %}
+#endif
+{% endif %}
 {% endif  %}
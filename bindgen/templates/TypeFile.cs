@@ -0,0 +1,23 @@
+{#/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
+
+{#
+// A single type rendered into its own file, used when `split_files` is enabled.
+// The runtime helpers are located in the main bindings file of the same namespace.
+#}
+
+{%- for imported_class in imports %}
+using {{ imported_class }};
+{%- endfor %}
+
+namespace {{ config.namespace() }};
+
+{%- for alias in type_aliases %}
+using {{ alias.alias }} = {{ alias.original_type }};
+{%- endfor %}
+
+{# details/1-empty-list-as-default-method-parameter.md #}
+#pragma warning disable 8625
+{{ type_code }}
+#pragma warning restore 8625
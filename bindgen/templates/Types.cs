@@ -4,8 +4,8 @@
 
 {%- import "macros.cs" as cs %}
 
-{%- for type_ in ci.iter_local_types() %}
-{%- let type_name = type_|type_name(ci) %}
+{%- for type_ in self.local_types %}
+{%- let type_name = type_|type_name(ci, config) %}
 {%- let ffi_converter_name = type_|ffi_converter_name %}
 {%- let canonical_type_name = type_|canonical_name %}
 {%- let contains_object_references = ci.item_contains_object_references(type_) %}
@@ -102,6 +102,13 @@
 {%- endmatch %}
 {%- endfor %}
 
+{%- if include_support_code %}
+{%- if config.split_files() %}
+{#- Callback support code is shared by types that live in separate files #}
+{% if self.include_once_check("ConcurrentHandleMap.cs") %}{% include "ConcurrentHandleMap.cs" %}{% endif %}
+{% if self.include_once_check("CallbackResponseStatus.cs") %}{% include "CallbackResponseStatus.cs" %}{% endif %}
+{%- endif %}
+
 {%- for type_ in ci.iter_external_types() %}
 {%- let name = type_.name().unwrap() %}
 {%- let module_path = type_.module_path().unwrap() %}
@@ -117,4 +124,5 @@
 
 {%- if ci.has_async_fns() %}
 {% include "Async.cs" %}
+{%- endif %}
 {%- endif %}
\ No newline at end of file
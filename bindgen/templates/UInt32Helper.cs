@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
 
-class {{ ffi_converter_name }}: FfiConverter<uint, uint> {
+{{ config.runtime_access_modifier() }} class {{ ffi_converter_name }}: FfiConverter<uint, uint> {
     public static {{ ffi_converter_name }} INSTANCE = new {{ ffi_converter_name }}();
 
     public override uint Lift(uint value) {
@@ -17,7 +17,7 @@ class {{ ffi_converter_name }}: FfiConverter<uint, uint> {
         return value;
     }
 
-    public override int AllocationSize(uint value) {
+    public override long AllocationSize(uint value) {
         return 4;
     }
 
@@ -0,0 +1,56 @@
+{#/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
+
+// Value of an optional of an optional, which `T?` would collapse: `None` has no value, and
+// `Some(None)` has a `null` value. It's part of the generated API, so it is declared by the
+// bindings of each component with optionals of optionals instead of the runtime.
+{{ config.access_modifier() }} readonly struct UniffiOptional<T> : IEquatable<UniffiOptional<T>> {
+    readonly T value;
+
+    public UniffiOptional(T value) {
+        this.value = value;
+        HasValue = true;
+    }
+
+    public bool HasValue { get; }
+
+    public T Value {
+        get {
+            if (!HasValue) {
+                throw new InvalidOperationException("UniffiOptional has no value");
+            }
+            return value;
+        }
+    }
+
+    public T GetValueOrDefault(T defaultValue) {
+        return HasValue ? value : defaultValue;
+    }
+
+    public bool Equals(UniffiOptional<T> other) {
+        return HasValue == other.HasValue && EqualityComparer<T>.Default.Equals(value, other.value);
+    }
+
+    public override bool Equals(object? obj) {
+        return obj is UniffiOptional<T> other && Equals(other);
+    }
+
+    public override int GetHashCode() {
+        return HasValue ? unchecked((value?.GetHashCode() ?? 0) * 31 + 1) : 0;
+    }
+
+    public override string ToString() {
+        return HasValue ? $"Some({value})" : "None";
+    }
+}
+
+{{ config.access_modifier() }} static class UniffiOptional {
+    public static UniffiOptional<T> Some<T>(T value) {
+        return new UniffiOptional<T>(value);
+    }
+
+    public static UniffiOptional<T> None<T>() {
+        return default;
+    }
+}
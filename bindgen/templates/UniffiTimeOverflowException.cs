@@ -0,0 +1,10 @@
+{#/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */#}
+
+// Thrown when a timestamp or duration is outside the range of its C# type, unless `time.overflow` is
+// configured to clamp it.
+{{ config.access_modifier() }} class UniffiTimeOverflowException: OverflowException {
+    public UniffiTimeOverflowException(string message): base(message) {
+    }
+}
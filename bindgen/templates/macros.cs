@@ -55,9 +55,46 @@
 )
 {%- endmacro -%}
 
+{#
+// Async functions are called through `_UniFFIAsync`, which drives the Rust future using the
+// poll/complete/free/cancel FFI functions. `*Blocking` twins use the same plumbing, but block
+// the calling thread until the future completes.
+#}
 {%- macro async_call(func, is_method) %}
     {%- if func.return_type().is_some() %}
     return {% endif %}await _UniFFIAsync.UniffiRustCallAsync(
+        {%- call rust_future(func, is_method) %}
+        {%- call rust_future_args(func) %}
+    );
+{%- endmacro -%}
+
+{%- macro blocking_call(func, is_method) %}
+    {%- if func.return_type().is_some() %}
+    return {% endif %}_UniFFIAsync.UniffiRustCallBlocking(
+        {%- call rust_future(func, is_method) %}
+        {%- call rust_future_args(func) %}
+    );
+{%- endmacro -%}
+
+{%- macro async_value_method_call(self_lower_prefix, func) %}
+    {%- if func.return_type().is_some() %}
+    return {% endif %}await _UniFFIAsync.UniffiRustCallAsync(
+        // Get rust future
+        _UniFFILib.{{ func.ffi_func().name() }}({{ self_lower_prefix }}{%- if func.arguments().len() > 0 %}, {% endif -%}{% call lower_arg_list(func) %}),
+        {%- call rust_future_args(func) %}
+    );
+{%- endmacro -%}
+
+{%- macro blocking_value_method_call(self_lower_prefix, func) %}
+    {%- if func.return_type().is_some() %}
+    return {% endif %}_UniFFIAsync.UniffiRustCallBlocking(
+        // Get rust future
+        _UniFFILib.{{ func.ffi_func().name() }}({{ self_lower_prefix }}{%- if func.arguments().len() > 0 %}, {% endif -%}{% call lower_arg_list(func) %}),
+        {%- call rust_future_args(func) %}
+    );
+{%- endmacro -%}
+
+{%- macro rust_future(func, is_method) %}
         // Get rust future
         {%- if is_method %}
         CallWithPointer(thisPtr => {
@@ -66,36 +103,9 @@
         {%- else %}
         _UniFFILib.{{ func.ffi_func().name() }}({% call lower_arg_list(func) %}),
         {%- endif%}
-        // Poll
-        (ulong future, IntPtr continuation, ulong data) => _UniFFILib.{{ func.ffi_rust_future_poll(ci) }}(future, continuation, data),
-        // Complete
-        (ulong future, ref UniffiRustCallStatus status) => {
-            {%- if func.return_type().is_some() %}
-            return {% endif %}_UniFFILib.{{ func.ffi_rust_future_complete(ci) }}(future, ref status);
-        },
-        // Free
-        (ulong future) => _UniFFILib.{{ func.ffi_rust_future_free(ci) }}(future),
-        {%- match func.return_type() %}
-        {%- when Some(return_type) %}
-        // Lift
-        (result) => {{ return_type|lift_fn }}(result),
-        {% else %}
-        {% endmatch -%}
-        // Error
-        {%- match func.throws_type() %}
-        {%- when Some(e)  %}
-        {{ e|error_converter_name }}.INSTANCE
-        {%- when None %}
-        NullCallStatusErrorHandler.INSTANCE
-        {% endmatch %}
-    );
 {%- endmacro -%}
 
-{%- macro async_value_method_call(self_lower_prefix, func) %}
-    {%- if func.return_type().is_some() %}
-    return {% endif %}await _UniFFIAsync.UniffiRustCallAsync(
-        // Get rust future
-        _UniFFILib.{{ func.ffi_func().name() }}({{ self_lower_prefix }}{%- if func.arguments().len() > 0 %}, {% endif -%}{% call lower_arg_list(func) %}),
+{%- macro rust_future_args(func) %}
         // Poll
         (ulong future, IntPtr continuation, ulong data) => _UniFFILib.{{ func.ffi_rust_future_poll(ci) }}(future, continuation, data),
         // Complete
@@ -105,6 +115,8 @@
         },
         // Free
         (ulong future) => _UniFFILib.{{ func.ffi_rust_future_free(ci) }}(future),
+        // Cancel
+        (ulong future) => _UniFFILib.{{ func.ffi_rust_future_cancel(ci) }}(future),
         {%- match func.return_type() %}
         {%- when Some(return_type) %}
         // Lift
@@ -117,8 +129,96 @@
         {{ e|error_converter_name }}.INSTANCE
         {%- when None %}
         NullCallStatusErrorHandler.INSTANCE
-        {% endmatch %}
-    );
+        {%- endmatch %},
+        cancellationToken
+{%- endmacro -%}
+
+{#
+// Overloads taking `bytes` arguments as `ReadOnlySpan<byte>` can't lower them inside the
+// `RustCall` lambdas, spans can't be captured. These macros lower the `bytes` arguments into
+// locals first, and pass the locals to Rust. Rust takes ownership of the locals when it is called,
+// until then they are freed by `free_bytes_args` in a `finally` block:
+//
+//     declare_bytes_args
+//     try {
+//         lower_bytes_args
+//         ... to_ffi_call_bytes_lowered ...
+//     } finally {
+//         free_bytes_args
+//     }
+#}
+{%- macro declare_bytes_args(func) %}
+    {%- for arg in func.arguments() %}
+    {%- if arg|is_bytes %}
+        RustBuffer {{ arg.name()|var_name }}Lowered = default;
+    {%- endif %}
+    {%- endfor %}
+        var _uniffiBytesPassed = false;
+{%- endmacro -%}
+
+{%- macro lower_bytes_args(func) %}
+    {%- for arg in func.arguments() %}
+    {%- if arg|is_bytes %}
+            {{ arg.name()|var_name }}Lowered = {{ arg|lower_fn }}({{ arg.name()|var_name }});
+    {%- endif %}
+    {%- endfor %}
+{%- endmacro -%}
+
+{%- macro free_bytes_args(func) %}
+            if (!_uniffiBytesPassed) {
+            {%- for arg in func.arguments() %}
+            {%- if arg|is_bytes %}
+                if ({{ arg.name()|var_name }}Lowered.data != IntPtr.Zero) {
+                    RustBuffer.Free({{ arg.name()|var_name }}Lowered);
+                }
+            {%- endif %}
+            {%- endfor %}
+            }
+{%- endmacro -%}
+
+{#
+// The other arguments are lowered before Rust takes ownership of the `bytes` arguments, so that
+// the `bytes` arguments are freed if lowering them throws.
+#}
+{%- macro lower_other_args(func) %}
+    {%- for arg in func.arguments() %}
+    {%- if !arg|is_bytes %}
+        var {{ arg.name()|var_name }}Lowered = {{ arg|lower_fn }}({{ arg.name()|var_name }});
+    {%- endif %}
+    {%- endfor %}
+        _uniffiBytesPassed = true;
+{%- endmacro -%}
+
+{%- macro lowered_arg_list(func) %}
+    {%- for arg in func.arguments() %}
+        {{- arg.name()|var_name }}Lowered
+        {%- if !loop.last %}, {% endif %}
+    {%- endfor %}
+{%- endmacro -%}
+
+{%- macro to_ffi_call_bytes_lowered(func) -%}
+    {%- match func.throws_type() %}
+    {%- when Some with (e) %}
+    _UniffiHelpers.RustCallWithError({{ e|error_converter_name}}.INSTANCE,
+    {%- else %}
+    _UniffiHelpers.RustCall(
+    {%- endmatch %} (ref UniffiRustCallStatus _status) => {
+        {%- call lower_other_args(func) %}
+        {% if func.return_type().is_some() %}return {% endif %}_UniFFILib.{{ func.ffi_func().name() }}({% call lowered_arg_list(func) -%}{% if func.arguments().len() > 0 %},{% endif %} ref _status);
+    })
+{%- endmacro -%}
+
+{%- macro to_ffi_call_with_prefix_bytes_lowered(prefix, func) %}
+    {%- match func.throws_type() %}
+    {%- when Some with (e) %}
+    _UniffiHelpers.RustCallWithError({{ e|error_converter_name}}.INSTANCE,
+    {%- else %}
+    _UniffiHelpers.RustCall(
+    {%- endmatch %} (ref UniffiRustCallStatus _status) => {
+        {%- call lower_other_args(func) %}
+        {% if func.return_type().is_some() %}return {% endif %}_UniFFILib.{{ func.ffi_func().name() }}(
+            {{- prefix }}, {% call lowered_arg_list(func) -%}{% if func.arguments().len() > 0 %},{% endif %} ref _status);
+    })
 {%- endmacro -%}
 
 {%- macro lower_arg_list(func) %}
@@ -131,17 +231,49 @@
 {#-
 // Arglist as used in C# declarations of methods, functions and constructors.
 // Note the var_name and type_name filters.
+// Async functions calling into Rust take a trailing `CancellationToken`, which cancels the Rust
+// future.
 -#}
 
 {% macro arg_list_decl(func) %}
+    {%- call arg_list_decl_bytes_as(func, "") %}
+{%- endmacro %}
+
+{#-
+// Same as `arg_list_decl`, but `bytes` arguments are declared as `bytes_type` when it's not empty.
+// Such arguments have no default value, so the overload doesn't compete with the `byte[]` one, and
+// neither do the arguments before them, which C# requires to precede optional parameters.
+-#}
+{% macro arg_list_decl_bytes_as(func, bytes_type) %}
+    {%- call arg_list_decl_with_token(func, bytes_type, "CancellationToken cancellationToken = default") %}
+{%- endmacro %}
+
+{#-
+// Arglist of methods of interfaces implemented in C#. Rust calls these without a
+// `CancellationToken`.
+-#}
+{% macro foreign_arg_list_decl(func) %}
+    {%- call arg_list_decl_with_token(func, "", "") %}
+{%- endmacro %}
+
+{#-
+// Arglist with `token_decl` declaring the `CancellationToken` of async functions, if not empty.
+-#}
+{% macro arg_list_decl_with_token(func, bytes_type, token_decl) %}
     {%- for arg in func.arguments() -%}
-        {{ arg|type_name(ci) }} {{ arg.name()|var_name -}}
+        {%- let bytes_as_type = arg|is_bytes && !bytes_type.is_empty() %}
+        {%- let drops_default = !bytes_type.is_empty() && func.arguments()|has_bytes_args_from(loop.index0) %}
+        {%- if bytes_as_type %}{{ bytes_type }}{% else %}{{ arg|argument_type_name(func, ci, config) }}{% endif %} {{ arg.name()|var_name -}}
         {%- match arg.default_value() %}
-        {%- when Some with(literal) %} = {{ literal|render_literal(arg, ci) }}
+        {%- when Some with(literal) %}{% if !drops_default %} = {{ literal|render_literal(arg, ci, config) }}{% endif %}
         {%- else %}
         {%- endmatch %}
         {%- if !loop.last %}, {% endif -%}
     {%- endfor %}
+    {%- if func.is_async() && !token_decl.is_empty() -%}
+        {%- if !func.arguments().is_empty() %}, {% endif -%}
+        {{ token_decl }}
+    {%- endif %}
 {%- endmacro %}
 
 {#-
@@ -155,6 +287,37 @@
     {%- if func.has_rust_call_status_arg() %}ref UniffiRustCallStatus _uniffi_out_err{% endif %}
 {%- endmacro -%}
 
+{#-
+// Arglist of `[UnmanagedCallersOnly]` methods implementing FFI callbacks. These methods can't have
+// `ref` parameters, so the RustCallStatus is passed as a pointer.
+-#}
+{%- macro arg_list_ffi_unmanaged_decl(func) %}
+    {%- for arg in func.arguments() %}
+        {{- arg.type_().borrow()|arg_type_name }} {{ arg.name()|var_name -}}{%- if !loop.last || func.has_rust_call_status_arg() -%},{%- endif -%}
+    {%- endfor %}
+    {%- if func.has_rust_call_status_arg() %}UniffiRustCallStatus* _uniffi_out_err{% endif %}
+{%- endmacro -%}
+
+{#-
+// Arguments forwarded from an `[UnmanagedCallersOnly]` method to the managed implementation.
+-#}
+{%- macro arg_list_ffi_unmanaged_forward(func) %}
+    {%- for arg in func.arguments() %}
+        {{- arg.name()|var_name -}}{%- if !loop.last || func.has_rust_call_status_arg() -%}, {% endif -%}
+    {%- endfor %}
+    {%- if func.has_rust_call_status_arg() %}ref *_uniffi_out_err{% endif %}
+{%- endmacro -%}
+
+{#-
+// Function pointer type of an `[UnmanagedCallersOnly]` method implementing an FFI callback.
+-#}
+{%- macro ffi_fn_ptr_type(func) -%}
+delegate* unmanaged[Cdecl]<
+    {%- for arg in func.arguments() %}{{ arg.type_().borrow()|arg_type_name }}, {% endfor -%}
+    {%- if func.has_rust_call_status_arg() %}UniffiRustCallStatus*, {% endif -%}
+    {%- call ffi_return_type(func) %}>
+{%- endmacro -%}
+
 {%- macro ffi_return_type(func) %}
     {%- match func.return_type() %}
     {%- when Some(return_type) %}{{ return_type|ffi_type_name }}
@@ -164,16 +327,24 @@
 
 // Macro for destroying fields
 {%- macro destroy_fields(member, prefix) %}
+    {%- if config.aot_compatible() %}
+        {%- for field in member.fields() %}
+        {%- let property_name = field.name()|property_name %}
+        {%- let field_value = format!("{}.{}", prefix, property_name) %}
+        {{ field|dispose_value(field_value, ci) }}
+        {%- endfor %}
+    {%- else %}
     FFIObjectUtil.DisposeAll(
         {%- for field in member.fields() %}
             {{ prefix }}.{{ field.name()|property_name }}{% if !loop.last %},{% endif %}
         {%- endfor %});
+    {%- endif %}
 {%- endmacro -%}
 
 {%- macro method_throws_annotation(throwable_type) %}
     {%- match throwable_type -%}
     {%- when Some with (throwable) %}
-    /// <exception cref="{{ throwable|type_name(ci) }}"></exception>
+    /// <exception cref="{{ throwable|type_name(ci, config) }}"></exception>
     {%- else -%}
     {%- endmatch %}
 {%- endmacro %}
@@ -190,18 +361,25 @@
 {%- if func.is_async() -%}
 {%- match func.return_type() -%}
 {%- when Some(return_type) -%}
-Task<{{ return_type|type_name(ci) }}>
+{{ config.async_type_name() }}<{{ return_type|type_name(ci, config) }}>
 {%- when None -%}
-Task
+{{ config.async_type_name() }}
 {%- endmatch -%}
 {%- else -%}
+{%- call blocking_return_type(func) -%}
+{%- endif -%}
+{%- endmacro -%}
+
+{#-
+// Return type of synchronous functions, and of `*Blocking` twins of async functions.
+-#}
+{%- macro blocking_return_type(func) -%}
 {%- match func.return_type() -%}
 {%- when Some(return_type) -%}
-{{ return_type|type_name(ci) }}
+{{ return_type|type_name(ci, config) }}
 {%- when None -%}
 void
 {%- endmatch -%}
-{%- endif -%}
 {%- endmacro -%}
 
 {#
@@ -245,26 +423,36 @@ void
 #}
 {%- macro value_type_methods(methods, self_lower_prefix) %}
     {% for meth in methods -%}
+    {%- if !config.is_member_excluded(meth.object_name(), meth.name()) %}
+    {%- let meth_access = config.member_access_modifier(meth.object_name(), meth.name()) %}
     {%- call docstring(meth, 4) %}
     {%- call method_throws_annotation(meth.throws_type()) %}
     {%- if meth.is_async() %}
-    public async {% call return_type(meth) %} {{ meth.name()|fn_name }}({%- call arg_list_decl(meth) -%}) {
+    {{ meth_access }} async {% call return_type(meth) %} {{ meth.name()|fn_name|async_name(meth.is_async(), config) }}({%- call arg_list_decl(meth) -%}) {
         {%- call async_value_method_call(self_lower_prefix, meth) %}
     }
+    {%- if config.async_blocking() %}
+    {%- call docstring(meth, 4) %}
+    {%- call method_throws_annotation(meth.throws_type()) %}
+    {{ meth_access }} {% call blocking_return_type(meth) %} {{ meth.name()|fn_name }}Blocking({%- call arg_list_decl(meth) -%}) {
+        {%- call blocking_value_method_call(self_lower_prefix, meth) %}
+    }
+    {%- endif %}
     {%- else %}
 
     {%- match meth.return_type() -%}
     {%- when Some with (return_type) %}
-    public {{ return_type|type_name(ci) }} {{ meth.name()|fn_name }}({% call arg_list_decl(meth) %}) {
+    {{ meth_access }} {{ return_type|type_name(ci, config) }} {{ meth.name()|fn_name }}({% call arg_list_decl(meth) %}) {
         return {{ return_type|lift_fn }}({%- call to_ffi_value_method_call(self_lower_prefix, meth) %});
     }
 
     {%- when None %}
-    public void {{ meth.name()|fn_name }}({% call arg_list_decl(meth) %}) {
+    {{ meth_access }} void {{ meth.name()|fn_name }}({% call arg_list_decl(meth) %}) {
         {%- call to_ffi_value_method_call(self_lower_prefix, meth) %};
     }
     {% endmatch %}
     {% endif %}
+    {%- endif %}
     {% endfor %}
 {%- endmacro %}
 
@@ -293,7 +481,7 @@ void
     {%- endif %}
     {%- match uniffi_trait_methods.eq_eq %}
     {%- when Some(eq) %}
-    public virtual bool Equals({{ eq.object_name()|class_name(ci) }}? other) {
+    public virtual bool Equals({{ eq.object_name()|class_name(ci, config) }}? other) {
         if (other is null) return false;
         return {{ Type::Boolean.borrow()|lift_fn }}({%- call to_ffi_call_with_prefix(self_lower_prefix, eq) %});
     }
@@ -309,6 +497,36 @@ void
     }
     {%- when None %}
     {%- endmatch %}
+    {%- match uniffi_trait_methods.ord_cmp %}
+    {%- when Some(cmp) %}
+    {%- let cmp_type_name = cmp.object_name()|class_name(ci, config) %}
+    public int CompareTo({{ cmp_type_name }}? other) {
+        if (other is null) return 1;
+        return {{ Type::Int8.borrow()|lift_fn }}({%- call to_ffi_call_with_prefix(self_lower_prefix, cmp) %});
+    }
+    {%- call comparison_operators(cmp_type_name) %}
+    {%- when None %}
+    {%- endmatch %}
+{%- endmacro %}
+
+{#
+// Base list of records and enums with associated data.
+#}
+{%- macro value_type_interfaces(type_name, contains_object_references, uniffi_trait_methods) %}
+    {%- if contains_object_references || uniffi_trait_methods.ord_cmp.is_some() %}: {% endif %}
+    {%- if contains_object_references %}IDisposable{% if uniffi_trait_methods.ord_cmp.is_some() %}, {% endif %}{% endif %}
+    {%- if uniffi_trait_methods.ord_cmp.is_some() %}IComparable<{{ type_name }}>{% endif %}
+    {%- if contains_object_references || uniffi_trait_methods.ord_cmp.is_some() %} {% endif %}
+{%- endmacro %}
+
+{#
+// Operators for types implementing `IComparable<T>`, where `null` is less than any value.
+#}
+{%- macro comparison_operators(type_name) %}
+    public static bool operator <({{ type_name }}? left, {{ type_name }}? right) => Comparer<{{ type_name }}>.Default.Compare(left, right) < 0;
+    public static bool operator >({{ type_name }}? left, {{ type_name }}? right) => Comparer<{{ type_name }}>.Default.Compare(left, right) > 0;
+    public static bool operator <=({{ type_name }}? left, {{ type_name }}? right) => Comparer<{{ type_name }}>.Default.Compare(left, right) <= 0;
+    public static bool operator >=({{ type_name }}? left, {{ type_name }}? right) => Comparer<{{ type_name }}>.Default.Compare(left, right) >= 0;
 {%- endmacro %}
 
 {#
@@ -318,26 +536,36 @@ void
 #}
 {%- macro flat_enum_extension_methods(methods, self_param_type, self_lower_prefix) %}
     {% for meth in methods -%}
+    {%- if !config.is_member_excluded(meth.object_name(), meth.name()) %}
+    {%- let meth_access = config.member_access_modifier(meth.object_name(), meth.name()) %}
     {%- call docstring(meth, 4) %}
     {%- call method_throws_annotation(meth.throws_type()) %}
     {%- if meth.is_async() %}
-    public static async {% call return_type(meth) %} {{ meth.name()|fn_name }}(this {{ self_param_type }} self_{%- if meth.arguments().len() > 0 %}, {% endif -%}{%- call arg_list_decl(meth) -%}) {
+    {{ meth_access }} static async {% call return_type(meth) %} {{ meth.name()|fn_name|async_name(meth.is_async(), config) }}(this {{ self_param_type }} self_, {%- call arg_list_decl(meth) -%}) {
         {%- call async_value_method_call(self_lower_prefix, meth) %}
     }
+    {%- if config.async_blocking() %}
+    {%- call docstring(meth, 4) %}
+    {%- call method_throws_annotation(meth.throws_type()) %}
+    {{ meth_access }} static {% call blocking_return_type(meth) %} {{ meth.name()|fn_name }}Blocking(this {{ self_param_type }} self_, {%- call arg_list_decl(meth) -%}) {
+        {%- call blocking_value_method_call(self_lower_prefix, meth) %}
+    }
+    {%- endif %}
     {%- else %}
 
     {%- match meth.return_type() -%}
     {%- when Some with (return_type) %}
-    public static {{ return_type|type_name(ci) }} {{ meth.name()|fn_name }}(this {{ self_param_type }} self_{%- if meth.arguments().len() > 0 %}, {% endif -%}{% call arg_list_decl(meth) %}) {
+    {{ meth_access }} static {{ return_type|type_name(ci, config) }} {{ meth.name()|fn_name }}(this {{ self_param_type }} self_{%- if meth.arguments().len() > 0 %}, {% endif -%}{% call arg_list_decl(meth) %}) {
         return {{ return_type|lift_fn }}({%- call to_ffi_value_method_call(self_lower_prefix, meth) %});
     }
 
     {%- when None %}
-    public static void {{ meth.name()|fn_name }}(this {{ self_param_type }} self_{%- if meth.arguments().len() > 0 %}, {% endif -%}{% call arg_list_decl(meth) %}) {
+    {{ meth_access }} static void {{ meth.name()|fn_name }}(this {{ self_param_type }} self_{%- if meth.arguments().len() > 0 %}, {% endif -%}{% call arg_list_decl(meth) %}) {
         {%- call to_ffi_value_method_call(self_lower_prefix, meth) %};
     }
     {% endmatch %}
     {% endif %}
+    {%- endif %}
     {% endfor %}
 {%- endmacro %}
 
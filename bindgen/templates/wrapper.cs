@@ -25,6 +25,12 @@
 {{- self.add_import("System.Linq") }}
 {{- self.add_import("System.Runtime.CompilerServices") }}
 {{- self.add_import("System.Runtime.InteropServices") }}
+{%- if config.uses_immutable_collections() %}
+{{- self.add_import("System.Collections.Immutable") }}
+{%- endif %}
+{%- if config.shared_runtime() %}
+{{- self.add_import(config.runtime_namespace()) }}
+{%- endif %}
 
 {%- for imported_class in self.imports() %}
 using {{ imported_class }};
@@ -37,10 +43,12 @@ namespace {{ config.namespace() }};
 using {{ alias.alias }} = {{ alias.original_type }};
 {%- endfor %}
 
+{%- if !config.shared_runtime() %}
 {% include "RustBufferTemplate.cs" %}
 {% include "FfiConverterTemplate.cs" %}
 {% include "Helpers.cs" %}
 {% include "BigEndianStream.cs" %}
+{%- endif %}
 
 // Contains loading, initialization code,
 // and the FFI Function declarations in a com.sun.jna.Library.
@@ -52,16 +60,14 @@ using {{ alias.alias }} = {{ alias.original_type }};
 {{ type_helper_code }}
 #pragma warning restore 8625
 
-{%- match config.global_methods_class_name %}
-{%- when Some(class_name) %}
-{{ config.access_modifier() }} static class {{ class_name }} {
-{%- when None %}
-{{ config.access_modifier() }} static class {{ ci.namespace().to_upper_camel_case() }}Methods {
-{%- endmatch %}
-
+{%- if !config.split_files() %}
+{{ config.access_modifier() }} static class {{ config.methods_class_name(ci) }} {
 {%- for func in ci.function_definitions() %}
+{%- if !config.is_excluded(func.name()) %}
 {%- include "TopLevelFunctionTemplate.cs" %}
+{%- endif %}
 {%- endfor %}
 }
+{%- endif %}
 
 {% import "macros.cs" as cs %}
@@ -0,0 +1,57 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using System.Threading.Tasks;
+using uniffi.aot_compatible;
+
+namespace UniffiCS.BindingTests;
+
+// Bindings generated with `aot_compatible = true` dispose fields without reflection.
+public class TestAotCompatible
+{
+    [Fact]
+    public void RecordDecrementsLiveCount()
+    {
+        using (var journal = AotCompatibleMethods.ResourceJournal())
+        {
+            Assert.Equal(4, AotCompatibleMethods.LiveCount());
+        }
+        Assert.Equal(0, AotCompatibleMethods.LiveCount());
+    }
+
+    [Fact]
+    public void EnumDecrementsLiveCount()
+    {
+        using (var resource = AotCompatibleMethods.MaybeResource(false))
+        {
+            Assert.Equal(1, AotCompatibleMethods.LiveCount());
+        }
+        Assert.Equal(0, AotCompatibleMethods.LiveCount());
+
+        using (var journal = AotCompatibleMethods.MaybeResource(true))
+        {
+            Assert.Equal(4, AotCompatibleMethods.LiveCount());
+        }
+        Assert.Equal(0, AotCompatibleMethods.LiveCount());
+    }
+
+    [Fact]
+    public void ErrorDecrementsLiveCount()
+    {
+        var error = Assert.Throws<ResourceException.Busy>(() => AotCompatibleMethods.Busy());
+        Assert.Equal(1, AotCompatibleMethods.LiveCount());
+        error.Dispose();
+        Assert.Equal(0, AotCompatibleMethods.LiveCount());
+    }
+
+    [Fact]
+    public async Task AsyncFunction()
+    {
+        using (var resource = await AotCompatibleMethods.AsyncResource())
+        {
+            Assert.Equal(1, AotCompatibleMethods.LiveCount());
+        }
+        Assert.Equal(0, AotCompatibleMethods.LiveCount());
+    }
+}
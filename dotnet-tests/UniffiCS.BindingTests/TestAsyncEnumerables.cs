@@ -0,0 +1,82 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+using System;
+using System.Collections.Generic;
+using System.Threading;
+using System.Threading.Tasks;
+using uniffi.async_enumerables;
+
+namespace UniffiCS.BindingTests;
+
+public class TestAsyncEnumerables
+{
+    [Fact]
+    public async Task EnumeratesRecords()
+    {
+        var feed = new EventFeed(new[] { "start", "stop" });
+        var names = new List<string>();
+        await foreach (var e in feed)
+        {
+            names.Add(e.Name);
+        }
+        Assert.Equal(new List<string> { "start", "stop" }, names);
+    }
+
+    [Fact]
+    public async Task EnumeratesValueTypes()
+    {
+        var values = new List<uint>();
+        await foreach (var value in new Countdown(3))
+        {
+            values.Add(value);
+        }
+        Assert.Equal(new List<uint> { 3, 2, 1 }, values);
+    }
+
+    [Fact]
+    public async Task DisposesWhenEnumerationEnds()
+    {
+        var liveBefore = AsyncEnumerablesMethods.LiveFeeds();
+
+        var feed = new EventFeed(new[] { "only" });
+        Assert.Equal(liveBefore + 1, AsyncEnumerablesMethods.LiveFeeds());
+        await foreach (var _ in feed) { }
+
+        Assert.Equal(liveBefore, AsyncEnumerablesMethods.LiveFeeds());
+        await Assert.ThrowsAsync<ObjectDisposedException>(async () => await feed.Next());
+    }
+
+    [Fact]
+    public async Task DisposesWhenBreakingOut()
+    {
+        var feed = new EventFeed(new[] { "first", "second" });
+        await foreach (var e in feed)
+        {
+            Assert.Equal("first", e.Name);
+            break;
+        }
+        await Assert.ThrowsAsync<ObjectDisposedException>(async () => await feed.Next());
+    }
+
+    [Fact]
+    public async Task HonorsCancellation()
+    {
+        using var cts = new CancellationTokenSource();
+        var feed = new Countdown(10);
+        var values = new List<uint>();
+
+        await Assert.ThrowsAsync<OperationCanceledException>(async () =>
+        {
+            await foreach (var value in feed.WithCancellation(cts.Token))
+            {
+                values.Add(value);
+                cts.Cancel();
+            }
+        });
+
+        Assert.Equal(new List<uint> { 10 }, values);
+        await Assert.ThrowsAsync<ObjectDisposedException>(async () => await feed.Next());
+    }
+}
@@ -0,0 +1,56 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using System.Threading;
+using System.Threading.Tasks;
+using uniffi.async_suffix;
+
+namespace UniffiCS.BindingTests;
+
+public class TestAsyncSuffix
+{
+    class StaticSource : Source
+    {
+        public async Task<string> ReadAsync(CancellationToken cancellationToken = default)
+        {
+            await Task.Yield();
+            return "data";
+        }
+    }
+
+    [Fact]
+    public async Task FunctionsGetSuffix()
+    {
+        Assert.Equal("Hello, suffix!", await AsyncSuffixMethods.GreetAsync("suffix"));
+        Assert.Equal("Hello, suffix!", AsyncSuffixMethods.GreetBlocking("suffix"));
+        Assert.Null(typeof(AsyncSuffixMethods).GetMethod("Greet"));
+        Assert.Equal(1u, AsyncSuffixMethods.Version());
+    }
+
+    [Fact]
+    public async Task SuffixIsNotDoubled()
+    {
+        Assert.Equal(42u, await AsyncSuffixMethods.LoadAsync());
+        Assert.Null(typeof(AsyncSuffixMethods).GetMethod("LoadAsyncAsync"));
+    }
+
+    [Fact]
+    public async Task ObjectsGetSuffix()
+    {
+        using var downloader = await Downloader.DownloaderAsync();
+        Assert.Equal("data", await downloader.DownloadAsync(new StaticSource()));
+        Assert.Equal(1u, downloader.Count());
+        Assert.NotNull(typeof(IDownloader).GetMethod("DownloadAsync"));
+        Assert.Null(typeof(Downloader).GetMethod("Download"));
+
+        using var restored = await Downloader.WithHistoryAsync(new[] { "first" });
+        Assert.Equal(1u, restored.Count());
+    }
+
+    [Fact]
+    public async Task RecordMethodsGetSuffix()
+    {
+        Assert.Equal(4u, await new Chunk("data").LengthAsync());
+    }
+}
@@ -0,0 +1,79 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+using System;
+using System.Threading;
+using System.Threading.Tasks;
+using Blocking = uniffi.async_blocking;
+using ValueTasks = uniffi.async_value_task;
+
+namespace UniffiCS.BindingTests;
+
+public class TestAsyncSurface
+{
+    [Fact]
+    public async Task TaskAndBlocking()
+    {
+        Task<uint> task = Blocking.AsyncBlockingMethods.Add(1, 2);
+        Assert.Equal(3u, await task);
+        Assert.Equal(3u, Blocking.AsyncBlockingMethods.AddBlocking(1, 2));
+        Assert.Throws<Blocking.CounterException.Overflow>(
+            () => Blocking.AsyncBlockingMethods.AddBlocking(uint.MaxValue, 1)
+        );
+
+        using var counter = Blocking.Counter.CounterBlocking(1);
+        Assert.Equal(3u, counter.IncrementBlocking(new Blocking.Step(2)));
+        Assert.Equal(5u, await counter.Increment(new Blocking.Step(2)));
+        Assert.Equal(4u, new Blocking.Step(2).DoubledBlocking());
+        counter.ResetBlocking();
+        Assert.Equal(0u, counter.Value());
+    }
+
+    [Fact]
+    public void BlockingHonorsCancellation()
+    {
+        using var cts = new CancellationTokenSource();
+        cts.Cancel();
+        Assert.Throws<OperationCanceledException>(
+            () => Blocking.AsyncBlockingMethods.AddBlocking(1, 2, cts.Token)
+        );
+    }
+
+    [Fact]
+    public void BlockingDoesNotDeadlockOnSynchronizationContext()
+    {
+        var previous = SynchronizationContext.Current;
+        // Posting to this context never runs the callback, like a blocked UI thread
+        SynchronizationContext.SetSynchronizationContext(new BlockedSynchronizationContext());
+        try
+        {
+            Assert.Equal(3u, Blocking.AsyncBlockingMethods.AddBlocking(1, 2));
+        }
+        finally
+        {
+            SynchronizationContext.SetSynchronizationContext(previous);
+        }
+    }
+
+    class BlockedSynchronizationContext : SynchronizationContext
+    {
+        public override void Post(SendOrPostCallback d, object? state) { }
+    }
+
+    [Fact]
+    public async Task ValueTask()
+    {
+        ValueTask<uint> task = ValueTasks.AsyncValueTaskMethods.Add(1, 2);
+        Assert.Equal(3u, await task);
+        await Assert.ThrowsAsync<ValueTasks.CounterException.Overflow>(
+            async () => await ValueTasks.AsyncValueTaskMethods.Add(uint.MaxValue, 1)
+        );
+
+        using var counter = await ValueTasks.Counter.CounterAsync(1);
+        Assert.Equal(3u, await counter.Increment(new ValueTasks.Step(2)));
+        Assert.Equal(4u, await new ValueTasks.Step(2).Doubled());
+        await counter.Reset();
+        Assert.Equal(0u, counter.Value());
+    }
+}
@@ -4,6 +4,7 @@
 
 using System;
 using System.IO;
+using System.Linq;
 using System.Runtime.InteropServices;
 using uniffi.arithmetic;
 
@@ -171,6 +172,72 @@ public class TestBigEndianStream
         Assert.Throws<StreamUnderflowException>(() => newStream(7).ReadDouble());
     }
 
+    [Fact]
+    public void TestLengthsLongerThanArraysThrow()
+    {
+        var stream = new BigEndianStream(new MemoryStream(new byte[] { 0x80, 0x00, 0x00, 0x00 }));
+        var exception = Assert.Throws<UniffiSizeLimitException>(() => stream.ReadLength());
+        Assert.Equal(0x8000_0000ul, exception.Size);
+        Assert.Equal((ulong)BigEndianStream.MaxArrayLength, exception.Limit);
+
+        exception = Assert.Throws<UniffiSizeLimitException>(
+            () => new BigEndianStream(new MemoryStream()).ReadBytes(int.MaxValue)
+        );
+        Assert.Equal((ulong)int.MaxValue, exception.Size);
+    }
+
+    [Fact]
+    public void TestUtf8ByteCount()
+    {
+        Assert.Equal(0, BigEndianStream.Utf8ByteCount(""));
+        Assert.Equal(10, BigEndianStream.Utf8ByteCount("aé€\U0001F600"));
+    }
+
+    [Fact]
+    public void TestStringsRoundTripAcrossChunks()
+    {
+        var values = new[] { "", "a", string.Concat(Enumerable.Repeat("aé€\U0001F600", 5)), new string('x', 37) + "\U0001F600" };
+        for (var chunkLength = 4; chunkLength <= 8; chunkLength++)
+        {
+            foreach (var value in values)
+            {
+                var expected = System.Text.Encoding.UTF8.GetBytes(value);
+                Assert.Equal(expected.Length, BigEndianStream.Utf8ByteCount(value, chunkLength));
+                Assert.Equal(expected, EncodeUtf8(value, chunkLength));
+                Assert.Equal(value, DecodeUtf8(expected, chunkLength));
+            }
+        }
+    }
+
+    [Fact]
+    public void TestChunksDontSplitSurrogatePairs()
+    {
+        // The pair starts at the last char of the first chunk, and its UTF-8 sequence at the last
+        // byte of the first chunk.
+        var value = "abc\U0001F600d";
+        var bytes = new byte[] { 0x61, 0x62, 0x63, 0xF0, 0x9F, 0x98, 0x80, 0x64 };
+        Assert.Equal(bytes, EncodeUtf8(value, 4));
+        Assert.Equal(value, DecodeUtf8(bytes, 4));
+    }
+
+    static unsafe byte[] EncodeUtf8(string value, int chunkLength)
+    {
+        var result = new byte[BigEndianStream.Utf8ByteCount(value, chunkLength)];
+        fixed (byte* destination = result)
+        {
+            BigEndianStream.EncodeUtf8(value, destination, result.Length, chunkLength);
+        }
+        return result;
+    }
+
+    static unsafe string DecodeUtf8(byte[] bytes, int chunkLength)
+    {
+        fixed (byte* source = bytes)
+        {
+            return BigEndianStream.DecodeUtf8(source, bytes.Length, chunkLength);
+        }
+    }
+
     static void ReadWriteTest<T>(
         Func<BigEndianStream, Action<T>> write,
         Func<BigEndianStream, Func<T>> read,
@@ -0,0 +1,89 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using System;
+using System.Linq;
+using System.Threading.Tasks;
+using uniffi.byte_spans;
+
+namespace UniffiCS.BindingTests;
+
+public class TestByteSpans
+{
+    static readonly byte[] Payload = Enumerable.Range(0, 4 * 1024 * 1024).Select(i => (byte)(i * 7)).ToArray();
+
+    [Fact]
+    public void SpanOverloadMatchesArray()
+    {
+        var expected = ByteSpansMethods.Checksum(Payload);
+        Assert.Equal(expected, ByteSpansMethods.Checksum(new ReadOnlySpan<byte>(Payload)));
+        Assert.Equal(
+            ByteSpansMethods.Checksum(Payload.AsSpan(16, 1024).ToArray()),
+            ByteSpansMethods.Checksum(Payload.AsSpan(16, 1024))
+        );
+    }
+
+    [Fact]
+    public void EmptySpan()
+    {
+        Assert.Equal(0u, ByteSpansMethods.Checksum(ReadOnlySpan<byte>.Empty));
+        Assert.Equal(0u, ByteSpansMethods.Checksum(Array.Empty<byte>()));
+    }
+
+    [Fact]
+    public void SpanOverloadMixesArguments()
+    {
+        var first = new byte[] { 1, 2, 3 };
+        var second = new byte[] { 4, 5 };
+        Assert.Equal(new byte[] { 1, 2, 3, 0, 4, 5 }, ByteSpansMethods.Concat(first, 0, second));
+        Assert.Equal(
+            new byte[] { 2, 3, 9, 4 },
+            ByteSpansMethods.Concat(first.AsSpan(1), 9, second.AsSpan(0, 1))
+        );
+    }
+
+    [Fact]
+    public void SpanOverloadRequiresPrecedingArguments()
+    {
+        var data = new byte[] { 1, 2, 3 };
+        Assert.Equal(0u, ByteSpansMethods.PrefixedChecksum());
+        Assert.Equal(ByteSpansMethods.Checksum(data), ByteSpansMethods.PrefixedChecksum(data: data));
+        Assert.Equal(ByteSpansMethods.Checksum(data), ByteSpansMethods.PrefixedChecksum(false, data.AsSpan()));
+        Assert.Equal(
+            ByteSpansMethods.Checksum(new byte[] { 0xFF, 1, 2, 3 }),
+            ByteSpansMethods.PrefixedChecksum(true, data.AsSpan())
+        );
+    }
+
+    [Fact]
+    public async Task MemoryOverloadForAsync()
+    {
+        var expected = ByteSpansMethods.Checksum(Payload);
+        Assert.Equal(expected, await ByteSpansMethods.ChecksumAsync(Payload));
+        Assert.Equal(expected, await ByteSpansMethods.ChecksumAsync(new ReadOnlyMemory<byte>(Payload)));
+    }
+
+    [Fact]
+    public async Task ObjectMethodOverloads()
+    {
+        using var fromArrays = new Hasher();
+        fromArrays.Update(Payload);
+        Assert.Equal((ulong)Payload.Length + 3, await fromArrays.UpdateAsync(new byte[] { 1, 2, 3 }));
+
+        using var fromSpans = new Hasher();
+        fromSpans.Update(Payload.AsSpan());
+        Assert.Equal((ulong)Payload.Length + 3, await fromSpans.UpdateAsync(new byte[] { 1, 2, 3 }.AsMemory()));
+
+        Assert.Equal(fromArrays.Digest(), fromSpans.Digest());
+    }
+
+    [Fact]
+    public void StringLowering()
+    {
+        Assert.Equal(0ul, ByteSpansMethods.Utf8Length(""));
+        Assert.Equal(5ul, ByteSpansMethods.Utf8Length("hello"));
+        Assert.Equal(6ul, ByteSpansMethods.Utf8Length("héllo"));
+        Assert.Equal(4ul, ByteSpansMethods.Utf8Length("😀"));
+    }
+}
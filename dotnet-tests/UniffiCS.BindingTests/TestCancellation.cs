@@ -0,0 +1,83 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+using System;
+using System.Threading;
+using System.Threading.Tasks;
+using uniffi.cancellation;
+
+namespace UniffiCS.BindingTests;
+
+public class TestCancellation
+{
+    // Wait until Rust polled the future at least once, so that cancellation happens mid-poll
+    static async Task WaitUntilPolled(uint polledBefore)
+    {
+        while (CancellationMethods.PolledFutures() == polledBefore)
+        {
+            await Task.Delay(1);
+        }
+    }
+
+    static async Task AssertCancelled(Func<CancellationToken, Task> call)
+    {
+        var polledBefore = CancellationMethods.PolledFutures();
+        var droppedBefore = CancellationMethods.DroppedFutures();
+
+        using var cts = new CancellationTokenSource();
+        var task = call(cts.Token);
+        await WaitUntilPolled(polledBefore);
+        cts.Cancel();
+
+        await Assert.ThrowsAsync<OperationCanceledException>(() => task);
+        Assert.Equal(droppedBefore + 1, CancellationMethods.DroppedFutures());
+    }
+
+    [Fact]
+    public async Task CancelFunction()
+    {
+        await AssertCancelled(token => CancellationMethods.WaitForever(token));
+    }
+
+    [Fact]
+    public async Task CancelVoidFunction()
+    {
+        await AssertCancelled(token => CancellationMethods.WaitForeverVoid(token));
+    }
+
+    [Fact]
+    public async Task CancelObjectMethod()
+    {
+        using var worker = new Worker();
+        await AssertCancelled(token => worker.WaitForever(token));
+    }
+
+    [Fact]
+    public async Task CancelRecordMethod()
+    {
+        var ticket = new Ticket(1);
+        await AssertCancelled(token => ticket.WaitForever(token));
+    }
+
+    [Fact]
+    public async Task AlreadyCancelledToken()
+    {
+        using var cts = new CancellationTokenSource();
+        cts.Cancel();
+        await Assert.ThrowsAsync<OperationCanceledException>(
+            () => CancellationMethods.WaitForever(cts.Token)
+        );
+    }
+
+    [Fact]
+    public async Task NotCancelled()
+    {
+        using var cts = new CancellationTokenSource();
+        Assert.Equal(42u, await CancellationMethods.Ready(42, cts.Token));
+        Assert.Equal(42u, await CancellationMethods.Ready(42));
+
+        using var worker = new Worker();
+        Assert.Equal(42u, await worker.Ready(42, cts.Token));
+    }
+}
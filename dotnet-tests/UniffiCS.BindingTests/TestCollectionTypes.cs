@@ -0,0 +1,117 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using System;
+using System.Collections.Generic;
+using System.Collections.Immutable;
+using uniffi.collection_types;
+
+namespace UniffiCS.BindingTests;
+
+public class TestCollectionTypes
+{
+    [Fact]
+    public void CollectionsUseTheConfiguredTypes()
+    {
+        Assert.Equal(typeof(IReadOnlyList<string>), typeof(Inventory).GetProperty("Tags")!.PropertyType);
+        Assert.Equal(
+            typeof(IReadOnlyDictionary<string, uint>),
+            typeof(CollectionTypesMethods).GetMethod("Tally")!.ReturnType
+        );
+        Assert.Equal(
+            typeof(IReadOnlyList<IReadOnlyList<int>>),
+            typeof(CollectionTypesMethods).GetMethod("Transpose")!.ReturnType
+        );
+    }
+
+    [Fact]
+    public void OverridesApplyToFieldsAndArguments()
+    {
+        Assert.Equal(typeof(ImmutableArray<string>), typeof(Inventory).GetProperty("Items")!.PropertyType);
+        Assert.Equal(
+            typeof(ImmutableDictionary<string, double>),
+            typeof(Inventory).GetProperty("Prices")!.PropertyType
+        );
+        Assert.Equal(
+            typeof(List<int>),
+            typeof(CollectionTypesMethods).GetMethod("Sum")!.GetParameters()[0].ParameterType
+        );
+        Assert.Equal(typeof(string[]), typeof(Basket).GetMethod("Add")!.GetParameters()[0].ParameterType);
+        Assert.Equal(typeof(IReadOnlyList<string>), typeof(Basket).GetMethod("Items")!.ReturnType);
+    }
+
+    [Fact]
+    public void OverriddenFieldsRoundTrip()
+    {
+        var inventory = new Inventory(
+            Name: "pantry",
+            Items: ImmutableArray.Create("rice"),
+            Prices: ImmutableDictionary<string, double>.Empty.Add("rice", 2.5),
+            Tags: new[] { "dry" }
+        );
+
+        var restocked = CollectionTypesMethods.Restock(inventory, new List<string> { "beans", "lentils" });
+
+        Assert.Equal(new[] { "rice", "beans", "lentils" }, restocked.Items);
+        Assert.Equal(2.5, restocked.Prices["rice"]);
+        Assert.Equal(new[] { "dry" }, restocked.Tags);
+    }
+
+    [Fact]
+    public void DefaultImmutableArraysAreEmpty()
+    {
+        var inventory = new Inventory(
+            Name: "empty",
+            Items: default,
+            Prices: ImmutableDictionary<string, double>.Empty,
+            Tags: Array.Empty<string>()
+        );
+
+        var restocked = CollectionTypesMethods.Restock(inventory, Array.Empty<string>());
+
+        Assert.Empty(restocked.Items);
+    }
+
+    [Fact]
+    public void DefaultImmutableArraysEqualEmptyArrays()
+    {
+        var inventory = new Inventory(
+            Name: "empty",
+            Items: default,
+            Prices: ImmutableDictionary<string, double>.Empty,
+            Tags: Array.Empty<string>()
+        );
+        var empty = inventory with { Items = ImmutableArray<string>.Empty };
+
+        Assert.Equal(inventory, empty);
+        Assert.Equal(empty, inventory);
+        Assert.Equal(inventory, inventory with { });
+        Assert.Equal(empty.GetHashCode(), inventory.GetHashCode());
+        Assert.NotEqual(inventory, inventory with { Items = ImmutableArray.Create("rice") });
+    }
+
+    [Fact]
+    public void ArgumentsAcceptAnyCollection()
+    {
+        Assert.Equal(6, CollectionTypesMethods.Sum(new List<int> { 1, 2, 3 }));
+
+        var counts = CollectionTypesMethods.Tally(ImmutableArray.Create("a", "b", "a"));
+        Assert.Equal(2u, counts["a"]);
+        Assert.Equal(1u, counts["b"]);
+
+        var columns = CollectionTypesMethods.Transpose(new[] { new[] { 1, 2 }, new[] { 3, 4 } });
+        Assert.Equal(new[] { 1, 3 }, columns[0]);
+        Assert.Equal(new[] { 2, 4 }, columns[1]);
+    }
+
+    [Fact]
+    public void ObjectMethodsUseOverrides()
+    {
+        using var basket = new Basket();
+        basket.Add(new[] { "apple" });
+        basket.Add(new[] { "pear" });
+
+        Assert.Equal(new[] { "apple", "pear" }, basket.Items());
+    }
+}
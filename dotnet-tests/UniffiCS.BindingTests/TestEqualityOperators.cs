@@ -0,0 +1,52 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using uniffi.ordering;
+using uniffi.reference_equality;
+
+namespace UniffiCS.BindingTests;
+
+public class TestEqualityOperators
+{
+    [Fact]
+    public void OperatorsCallIntoRust()
+    {
+        using var lower = new Name("bob");
+        using var upper = new Name("BOB");
+        using var other = new Name("alice");
+
+        // `Name` compares case-insensitively in Rust.
+        Assert.True(lower == upper);
+        Assert.False(lower != upper);
+        Assert.False(lower == other);
+        Assert.True(lower != other);
+    }
+
+    [Fact]
+    public void OperatorsHandleNull()
+    {
+        using var name = new Name("bob");
+        Name? missing = null;
+
+        Assert.False(name == null);
+        Assert.True(name != null);
+        Assert.False(null == name);
+        Assert.True(missing == null);
+        Assert.False(missing != null);
+    }
+
+    [Fact]
+    public void ReferenceEqualityOperators()
+    {
+        // Generated with `reference_equality_operators = true`.
+        using var first = new Token("token");
+        using var second = new Token("token");
+
+        Assert.True(first.Equals(second));
+        Assert.False(first == second);
+        Assert.True(first != second);
+        var alias = first;
+        Assert.True(first == alias);
+    }
+}
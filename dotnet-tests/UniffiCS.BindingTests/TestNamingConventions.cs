@@ -0,0 +1,54 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using uniffi.naming_conventions;
+
+namespace UniffiCS.BindingTests;
+
+public class TestNamingConventions
+{
+    class AcceptAll : Listener
+    {
+        public bool Accept(string key, string value)
+        {
+            return true;
+        }
+    }
+
+    [Fact]
+    public void ObjectsUseConfiguredInterfaceName()
+    {
+        using var store = new Store(null);
+        Assert.IsAssignableFrom<StoreContract>(store);
+        Assert.Null(typeof(Store).Assembly.GetType("uniffi.naming_conventions.IStore"));
+    }
+
+    [Fact]
+    public void MethodsNamedLikeTheirClassUseConfiguredSuffix()
+    {
+        using var store = new Store(new AcceptAll());
+        store.Put("key", "value");
+        Assert.Equal(1u, store.StoreMethod());
+        Assert.NotNull(typeof(StoreContract).GetMethod("StoreMethod"));
+    }
+
+    [Fact]
+    public void ErrorsUseConfiguredSuffix()
+    {
+        using var store = new Store(null);
+        Assert.Throws<StoreFailure.NotFound>(() => store.Get("missing"));
+        Assert.Null(typeof(Store).Assembly.GetType("uniffi.naming_conventions.StoreException"));
+    }
+
+    [Fact]
+    public void TraitInterfacesUseConfiguredImplName()
+    {
+        var listener = NamingConventionsMethods.RejectAll();
+        Assert.IsType<ListenerImplementation>(listener);
+
+        using var store = new Store(listener);
+        Assert.Throws<StoreFailure.RejectedFailure>(() => store.Put("key", "value"));
+        Assert.Equal(0u, store.StoreMethod());
+    }
+}
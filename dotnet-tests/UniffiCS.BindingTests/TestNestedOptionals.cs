@@ -0,0 +1,77 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+using System;
+using System.Collections.Generic;
+using System.Threading.Tasks;
+using uniffi.nested_optionals;
+
+namespace UniffiCS.BindingTests;
+
+public class TestNestedOptionals
+{
+    [Fact]
+    public void ArgumentsKeepAllThreeStates()
+    {
+        Assert.Equal("none", NestedOptionalsMethods.Describe(UniffiOptional.None<string?>()));
+        Assert.Equal("some(none)", NestedOptionalsMethods.Describe(UniffiOptional.Some<string?>(null)));
+        Assert.Equal("some(value)", NestedOptionalsMethods.Describe(UniffiOptional.Some<string?>("value")));
+    }
+
+    [Fact]
+    public void ReturnValuesKeepAllThreeStates()
+    {
+        var none = NestedOptionalsMethods.Echo(default);
+        Assert.False(none.HasValue);
+        Assert.Throws<InvalidOperationException>(() => none.Value);
+
+        var someNone = NestedOptionalsMethods.Echo(new UniffiOptional<string?>(null));
+        Assert.True(someNone.HasValue);
+        Assert.Null(someNone.Value);
+
+        var some = NestedOptionalsMethods.Echo(UniffiOptional.Some<string?>("value"));
+        Assert.True(some.HasValue);
+        Assert.Equal("value", some.Value);
+
+        Assert.NotEqual(none, someNone);
+        Assert.Equal(UniffiOptional.Some<string?>("value"), some);
+    }
+
+    [Fact]
+    public void SequencesKeepAllThreeStates()
+    {
+        var values = new[] { UniffiOptional.None<int?>(), UniffiOptional.Some<int?>(null), UniffiOptional.Some<int?>(1) };
+        Assert.Equal(values, NestedOptionalsMethods.EchoAll(values));
+    }
+
+    [Fact]
+    public void PatchLeavesNoneAndClearsSomeNone()
+    {
+        var profile = new Profile(Name: "Ann", Nickname: "annie", Age: 30);
+
+        Assert.Equal(profile, NestedOptionalsMethods.ApplyPatch(profile, new ProfilePatch()));
+
+        var cleared = NestedOptionalsMethods.ApplyPatch(
+            profile,
+            new ProfilePatch(Nickname: UniffiOptional.Some<string?>(null))
+        );
+        Assert.Null(cleared.Nickname);
+        Assert.Equal(30u, cleared.Age);
+
+        var updated = NestedOptionalsMethods.ApplyPatch(profile, new ProfilePatch(Age: UniffiOptional.Some<uint?>(31)));
+        Assert.Equal("annie", updated.Nickname);
+        Assert.Equal(31u, updated.Age);
+    }
+
+    [Fact]
+    public async Task EnumerationEndsOnNoneOnly()
+    {
+        var answers = new List<string?>();
+        await foreach (var answer in new Survey(new[] { "yes", null, "no" }))
+        {
+            answers.Add(answer);
+        }
+        Assert.Equal(new List<string?> { "yes", null, "no" }, answers);
+    }
+}
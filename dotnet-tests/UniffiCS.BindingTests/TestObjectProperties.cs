@@ -0,0 +1,50 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using uniffi.object_properties;
+
+namespace UniffiCS.BindingTests;
+
+public class TestObjectProperties
+{
+    [Fact]
+    public void AccessorsArePropertiesWithSetters()
+    {
+        using var document = new Document("draft");
+        Assert.Equal("draft", document.Name);
+        document.Name = "final";
+        Assert.Equal("final", document.Name);
+
+        Assert.True(typeof(IDocument).GetProperty("Name")!.CanWrite);
+        Assert.Null(typeof(Document).GetMethod("SetName"));
+        Assert.Null(typeof(Document).GetMethod("Name"));
+    }
+
+    [Fact]
+    public void PrefixedAccessorsAreReadOnlyProperties()
+    {
+        using var document = new Document("notes");
+        Assert.True(document.IsEmpty);
+        Assert.Equal(0u, document.LineCount);
+
+        document.AddLine("first");
+        Assert.False(document.IsEmpty);
+        Assert.Equal(1u, document.LineCount);
+
+        Assert.False(typeof(IDocument).GetProperty("LineCount")!.CanWrite);
+        Assert.Null(typeof(Document).GetMethod("GetLineCount"));
+    }
+
+    [Fact]
+    public void ConflictingAndThrowingAccessorsStayMethods()
+    {
+        using var document = new Document("notes");
+        Assert.Equal("[notes]", document.Label);
+        Assert.Equal("[notes]", document.GetLabel());
+
+        Assert.Throws<DocumentException.Untitled>(() => document.Title());
+        document.AddLine("Heading");
+        Assert.Equal("Heading", document.Title());
+    }
+}
@@ -0,0 +1,65 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using uniffi.ordering;
+
+namespace UniffiCS.BindingTests;
+
+public class TestOrdering
+{
+    [Fact]
+    public void RecordComparesThroughRust()
+    {
+        var v1_2 = new AppVersion(1, 2, "beta");
+        var v1_10 = new AppVersion(1, 10, "alpha");
+        var v2_0 = new AppVersion(2, 0, "");
+
+        Assert.True(v1_2 < v1_10);
+        Assert.True(v2_0 > v1_10);
+        Assert.True(v1_2 <= new AppVersion(1, 2, "rc"));
+        Assert.True(v1_2 >= new AppVersion(1, 2, "rc"));
+        Assert.Equal(0, v1_2.CompareTo(new AppVersion(1, 2, "other")));
+        Assert.Equal(1, v1_2.CompareTo(null));
+
+        var sorted = new[] { v2_0, v1_10, v1_2 }.OrderBy(v => v).ToArray();
+        Assert.Equal(new[] { v1_2, v1_10, v2_0 }, sorted);
+    }
+
+    [Fact]
+    public void EnumComparesThroughRust()
+    {
+        var priorities = new SortedSet<Priority>
+        {
+            new Priority.High(1),
+            new Priority.Low(5),
+            new Priority.High(7),
+            new Priority.Low(2),
+        };
+        Assert.Equal(
+            new Priority[] { new Priority.Low(5), new Priority.Low(2), new Priority.High(7), new Priority.High(1) },
+            priorities.ToArray()
+        );
+        Assert.True(new Priority.Low(100) < new Priority.High(-100));
+    }
+
+    [Fact]
+    public void ObjectComparesThroughRust()
+    {
+        using var upper = new Name("BOB");
+        using var lower = new Name("alice");
+        using var mixed = new Name("Carol");
+
+        Assert.True(lower < upper);
+        Assert.True(mixed > upper);
+        Assert.False(upper < null);
+        Assert.True(null < upper);
+
+        var sorted = new List<Name> { mixed, upper, lower };
+        sorted.Sort();
+        Assert.Equal(new[] { "alice", "BOB", "Carol" }, sorted.Select(n => n.Value()));
+    }
+}
@@ -0,0 +1,38 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using uniffi.record_structs;
+
+namespace UniffiCS.BindingTests;
+
+public class TestRecordStructs
+{
+    [Fact]
+    public void PrimitiveRecordsAreStructs()
+    {
+        Assert.True(typeof(Vec2).IsValueType);
+        Assert.True(typeof(Label).IsValueType);
+        Assert.False(typeof(Cell).IsValueType);
+    }
+
+    [Fact]
+    public void StructsRoundTrip()
+    {
+        var scaled = RecordStructsMethods.Scale(new[] { new Vec2(1, 2), new Vec2(3) }, 2);
+        Assert.Equal(new[] { new Vec2(2, 4), new Vec2(6, 0) }, scaled);
+
+        Assert.Equal(new Vec2(0, 0), RecordStructsMethods.OriginOr(null));
+        Assert.Equal(new Vec2(5, 6), RecordStructsMethods.OriginOr(new Vec2(5, 6)));
+
+        var label = RecordStructsMethods.Nudge(new Label("here", new Vec2(1, 1)));
+        Assert.Equal(new Label("here", new Vec2(2, 1)), label);
+    }
+
+    [Fact]
+    public void RecordsExportingTraitsStayRecords()
+    {
+        var cell = RecordStructsMethods.NextCell(new Cell(1, 1));
+        Assert.Equal(new Cell(1, 2), cell);
+    }
+}
@@ -0,0 +1,58 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using System.Collections.Generic;
+using System.ComponentModel;
+using uniffi.record_styles;
+
+namespace UniffiCS.BindingTests;
+
+public class TestRecordStyles
+{
+    [Fact]
+    public void ObservableRecordsRaisePropertyChanged()
+    {
+        var profile = new Profile(Name: "Ann", Tags: new[] { "admin" });
+        Assert.IsAssignableFrom<INotifyPropertyChanged>(profile);
+
+        var changed = new List<string?>();
+        profile.PropertyChanged += (_, e) => changed.Add(e.PropertyName);
+        profile.Nickname = "annie";
+        profile.Nickname = "annie";
+        profile.Name = "Anne";
+
+        Assert.Equal(new[] { "Nickname", "Name" }, changed);
+        Assert.Equal("Anne (annie): admin", RecordStylesMethods.DescribeProfile(profile));
+    }
+
+    [Fact]
+    public void ClassRecordsAreMutable()
+    {
+        var settings = new Settings(Theme: "dark", Volume: 10);
+        Assert.Null(typeof(Settings).GetMethod("<Clone>$"));
+
+        settings.Volume = 20;
+        var louder = RecordStylesMethods.Louder(settings);
+        Assert.Equal((byte)30, louder.Volume);
+        Assert.Equal("dark", louder.Theme);
+    }
+
+    [Fact]
+    public void ClassRecordsCompareThroughRust()
+    {
+        var first = new Settings("dark", 10);
+        var second = new Settings("dark", 10);
+        Assert.True(first.Equals(second));
+        Assert.True(first.Equals((object)second));
+        Assert.Equal(first.GetHashCode(), second.GetHashCode());
+        Assert.False(first.Equals(new Settings("light", 10)));
+    }
+
+    [Fact]
+    public void PerRecordStyleOverridesGlobalStyle()
+    {
+        Assert.NotNull(typeof(Point).GetMethod("<Clone>$"));
+        Assert.Equal(new Point(-1, 2), RecordStylesMethods.Mirror(new Point(1, -2)));
+    }
+}
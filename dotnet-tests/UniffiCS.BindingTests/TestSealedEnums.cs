@@ -0,0 +1,80 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using System;
+using System.Linq;
+using uniffi.sealed_enums;
+
+namespace UniffiCS.BindingTests;
+
+// Bindings generated with `sealed_enums = true`.
+public class TestSealedEnums
+{
+    static double Area(Shape shape) =>
+        shape.Match(
+            circle: c => Math.PI * c.Radius * c.Radius,
+            rectangle: r => r.Width * r.Height,
+            point: _ => 0.0
+        );
+
+    [Fact]
+    public void HierarchyIsSealed()
+    {
+        Assert.True(typeof(Shape).IsAbstract);
+        Assert.True(typeof(Shape.Circle).IsSealed);
+        Assert.True(typeof(Shape.Rectangle).IsSealed);
+        Assert.True(typeof(Shape.Point).IsSealed);
+        Assert.Empty(typeof(Shape).GetConstructors());
+    }
+
+    [Fact]
+    public void Match()
+    {
+        Assert.Equal(1.0, Area(SealedEnumsMethods.UnitSquare()));
+        Assert.Equal(4.0, Area(SealedEnumsMethods.Scale(SealedEnumsMethods.UnitSquare(), 2.0)));
+        Assert.Equal(Math.PI * 9, Area(SealedEnumsMethods.Scale(new Shape.Circle(1.0), 3.0)), 6);
+        Assert.Equal(0.0, Area(SealedEnumsMethods.Scale(new Shape.Point(), 3.0)));
+    }
+
+    [Fact]
+    public void Switch()
+    {
+        var log = new System.Collections.Generic.List<string>();
+        foreach (var message in SealedEnumsMethods.Messages())
+        {
+            message.Switch(
+                text: t => log.Add($"text {t.V1}"),
+                move: m => log.Add($"move {m.X} {m.Y}"),
+                quit: _ => log.Add("quit")
+            );
+        }
+        Assert.Equal(new[] { "text hello", "move 1 -1", "quit" }, log);
+    }
+
+    [Fact]
+    public void IsAndAs()
+    {
+        var messages = SealedEnumsMethods.Messages();
+        Assert.True(messages[0].IsText);
+        Assert.False(messages[0].IsMove);
+        Assert.Equal("hello", messages[0].AsText!.V1);
+        Assert.Null(messages[0].AsMove);
+        Assert.Equal(1, messages[1].AsMove!.X);
+        Assert.True(messages[2].IsQuit);
+        Assert.Equal(1, messages.Count(m => m.IsQuit));
+    }
+
+    [Fact]
+    public void PatternMatching()
+    {
+        var description = SealedEnumsMethods.UnitSquare() switch
+        {
+            Shape.Circle c => $"circle {c.Radius}",
+            Shape.Rectangle r => $"rectangle {r.Width}x{r.Height}",
+            Shape.Point => "point",
+            _ => throw new InvalidOperationException(),
+        };
+        Assert.Equal("rectangle 1x1", description);
+    }
+}
@@ -0,0 +1,89 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using uniffi.serialization_benchmark;
+
+namespace UniffiCS.BindingTests;
+
+// Reports allocations of lowering through `RustBuffer` memory and through a `Stream`, which is how
+// every `BigEndianStream` used to work. Allocations depend on the runtime, so they are reported
+// rather than asserted, and the benchmarks only run when explicit tests are enabled:
+// `dotnet test dotnet-tests -- --explicit only --filter-trait "Category=Benchmark"`.
+[Trait("Category", "Benchmark")]
+public class TestSerializationBenchmark
+{
+    const int SampleCount = 1_000_000;
+
+    readonly ITestOutputHelper output;
+
+    public TestSerializationBenchmark(ITestOutputHelper output)
+    {
+        this.output = output;
+    }
+
+    static Sample[] MakeSamples() =>
+        Enumerable
+            .Range(0, SampleCount)
+            .Select(i => new Sample((ulong)i, $"sample-{i}", i / 2.0, Enumerable.Repeat("a", i % 3).ToArray()))
+            .ToArray();
+
+    static Dictionary<string, Dictionary<string, long>> MakeNested() =>
+        Enumerable
+            .Range(0, 1000)
+            .ToDictionary(
+                o => $"outer-{o}",
+                o => Enumerable.Range(0, 1000).ToDictionary(i => $"inner-{i}", i => (long)(o * 1000 + i))
+            );
+
+    void Measure<T>(string name, FfiConverterRustBuffer<T> converter, T value)
+    {
+        // Warm up, so JIT and the pooled stream don't count.
+        RustBuffer.Free(converter.Lower(value));
+
+        var before = GC.GetAllocatedBytesForCurrentThread();
+        var rbuf = converter.Lower(value);
+        var pooled = GC.GetAllocatedBytesForCurrentThread() - before;
+        RustBuffer.Free(rbuf);
+
+        var size = converter.AllocationSize(value);
+        var memoryStream = new MemoryStream(new byte[size]);
+        before = GC.GetAllocatedBytesForCurrentThread();
+        converter.Write(value, new BigEndianStream(memoryStream));
+        var streamed = GC.GetAllocatedBytesForCurrentThread() - before;
+
+        output.WriteLine($"{name}: {size} bytes, RustBuffer allocated {pooled} bytes, Stream allocated {streamed} bytes");
+    }
+
+    [Fact(Explicit = true)]
+    public void SequenceOfRecords()
+    {
+        var samples = MakeSamples();
+        Measure("Sequence of records", FfiConverterSequenceTypeSample.INSTANCE, samples);
+
+        var summary = SerializationBenchmarkMethods.SummarizeSamples(samples);
+        Assert.Equal((ulong)SampleCount, summary.Count);
+        Assert.Equal((ulong)SampleCount * (SampleCount - 1) / 2, summary.IdSum);
+        Assert.Equal((ulong)samples.Sum(s => s.Tags.Length), summary.TagCount);
+
+        var lifted = SerializationBenchmarkMethods.MakeSamples(SampleCount);
+        Assert.Equal(SampleCount, lifted.Length);
+        Assert.Equal("sample-999999", lifted[SampleCount - 1].Name);
+        Assert.Equal(samples[12345].Tags, lifted[12345].Tags);
+    }
+
+    [Fact(Explicit = true)]
+    public void NestedMaps()
+    {
+        var nested = MakeNested();
+        Measure("Nested maps", FfiConverterDictionaryStringDictionaryStringInt64.INSTANCE, nested);
+
+        Assert.Equal(nested.Values.SelectMany(inner => inner.Values).Sum(), SerializationBenchmarkMethods.SumNested(nested));
+        var lifted = SerializationBenchmarkMethods.MakeNested(1000, 1000);
+        Assert.Equal(nested["outer-999"]["inner-999"], lifted["outer-999"]["inner-999"]);
+    }
+}
@@ -0,0 +1,54 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using System;
+using System.Collections.Generic;
+using uniffi.shared_runtime;
+
+namespace UniffiCS.BindingTests;
+
+// Bindings for `shared_runtime` use the runtime generated into the `Uniffi.Runtime` namespace.
+public class TestSharedRuntime
+{
+    class Logger : uniffi.shared_runtime.Logger
+    {
+        public List<string> Messages = new List<string>();
+
+        public void Log(string message)
+        {
+            Messages.Add(message);
+        }
+    }
+
+    [Fact]
+    public void BuiltinConverters()
+    {
+        Assert.Equal("Hello, runtime!", SharedRuntimeMethods.Greet("runtime"));
+
+        var time = new DateTime(2024, 1, 1, 0, 0, 0, DateTimeKind.Utc);
+        Assert.Equal(
+            time.AddSeconds(90),
+            SharedRuntimeMethods.AddDuration(time, TimeSpan.FromSeconds(90))
+        );
+    }
+
+    [Fact]
+    public void ErrorsDeriveFromSharedException()
+    {
+        using var vault = new Vault();
+        var exception = Assert.Throws<VaultException.Empty>(() => vault.Open());
+        Assert.IsAssignableFrom<Uniffi.Runtime.UniffiException>(exception);
+
+        vault.Store(new byte[] { 1, 2, 3 });
+        Assert.Equal(new byte[] { 1, 2, 3 }, vault.Open());
+    }
+
+    [Fact]
+    public void CallbackInterfaceUsesSharedHandleMap()
+    {
+        var logger = new Logger();
+        SharedRuntimeMethods.LogAll(logger, new[] { "one", "two" });
+        Assert.Equal(new List<string> { "one", "two" }, logger.Messages);
+    }
+}
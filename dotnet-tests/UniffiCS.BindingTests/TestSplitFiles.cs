@@ -0,0 +1,64 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using System.Collections.Generic;
+using uniffi.split_files;
+
+namespace UniffiCS.BindingTests;
+
+// Bindings for `split_files` are generated into one file per type, see `fixtures/split-files/uniffi.toml`.
+public class TestSplitFiles
+{
+    class Listener : CanvasListener
+    {
+        public List<Shape> Shapes = new List<Shape>();
+
+        public void OnDraw(Shape shape)
+        {
+            Shapes.Add(shape);
+        }
+    }
+
+    [Fact]
+    public void TopLevelFunction()
+    {
+        var midpoint = SplitFilesMethods.Midpoint(new Point(0, 0), new Point(4, 8));
+        Assert.Equal(new Point(2, 4), midpoint);
+    }
+
+    [Fact]
+    public void ObjectWithCallbackInterface()
+    {
+        var listener = new Listener();
+        using var canvas = new Canvas();
+        canvas.Draw(new Shape.Dot(new Point(1, 2)), listener);
+        canvas.Draw(new Shape.Line(new Point(1, 2), new Point(3, 4)), listener);
+
+        Assert.Equal(2u, canvas.ShapeCount());
+        Assert.Equal(2, listener.Shapes.Count);
+        Assert.Equal(new Shape.Line(new Point(1, 2), new Point(3, 4)), listener.Shapes[1]);
+    }
+
+    [Fact]
+    public void ErrorInSeparateFile()
+    {
+        using var canvas = new Canvas();
+        var exception = Assert.Throws<CanvasException.OutOfBounds>(
+            () => canvas.Draw(new Shape.Dot(new Point(-1, 2)), new Listener()));
+        Assert.Equal(-1, exception.x);
+        Assert.Equal(2, exception.y);
+    }
+
+    [Fact]
+    public void FlatEnumInMap()
+    {
+        using var canvas = new Canvas();
+        canvas.Label("up", Direction.North);
+        canvas.Label("down", Direction.South);
+
+        var labels = canvas.Labels();
+        Assert.Equal(Direction.North, labels["up"]);
+        Assert.Equal(Direction.South, labels["down"]);
+    }
+}
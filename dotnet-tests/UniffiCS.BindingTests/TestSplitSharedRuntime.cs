@@ -0,0 +1,30 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using uniffi.split_shared_runtime;
+
+namespace UniffiCS.BindingTests;
+
+// Bindings for `split_shared_runtime` are generated into one file per type, which use the runtime
+// generated into the `Uniffi.Runtime` namespace.
+public class TestSplitSharedRuntime
+{
+    [Fact]
+    public void TopLevelFunction()
+    {
+        Assert.Equal(5u, SplitSharedRuntimeMethods.EntrySize(new Entry("key", new byte[] { 1, 2 })));
+    }
+
+    [Fact]
+    public void ObjectWithErrors()
+    {
+        using var ledger = new Ledger();
+        ledger.Record(new Entry("key", new byte[] { 1, 2 }));
+        Assert.Equal(new Entry("key", new byte[] { 1, 2 }), ledger.Find("key"));
+
+        var exception = Assert.Throws<LedgerException.Missing>(() => ledger.Find("other"));
+        Assert.Equal("other", exception.key);
+        Assert.IsAssignableFrom<Uniffi.Runtime.UniffiException>(exception);
+    }
+}
@@ -0,0 +1,92 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using System.Collections.Generic;
+using uniffi.structural_equality;
+
+namespace UniffiCS.BindingTests;
+
+public class TestStructuralEquality
+{
+    [Fact]
+    public void RecordsCompareCompoundFieldsByValue()
+    {
+        var first = StructuralEqualityMethods.TakeSnapshot("one");
+        var second = StructuralEqualityMethods.TakeSnapshot("one");
+
+        Assert.NotSame(first.Payload, second.Payload);
+        Assert.Equal(first, second);
+        Assert.True(first.Equals((object)second));
+        Assert.Equal(first.GetHashCode(), second.GetHashCode());
+        Assert.NotEqual(first, StructuralEqualityMethods.TakeSnapshot("two"));
+    }
+
+    [Fact]
+    public void MapsCompareIndependentlyOfOrder()
+    {
+        var snapshot = StructuralEqualityMethods.TakeSnapshot("one");
+        var reordered = snapshot with
+        {
+            Counts = new Dictionary<string, uint> { ["y"] = 2, ["x"] = 1 },
+        };
+
+        Assert.Equal(snapshot, reordered);
+        Assert.Equal(snapshot.GetHashCode(), reordered.GetHashCode());
+        Assert.NotEqual(snapshot, snapshot with { Counts = new Dictionary<string, uint> { ["x"] = 1 } });
+    }
+
+    [Fact]
+    public void OptionalBytesCompareByValue()
+    {
+        var snapshot = StructuralEqualityMethods.TakeSnapshot("one");
+
+        Assert.Equal(snapshot, snapshot with { Thumbnail = new byte[] { 1, 2, 3 } });
+        Assert.NotEqual(snapshot, snapshot with { Thumbnail = null });
+        Assert.NotEqual(snapshot, snapshot with { Thumbnail = new byte[] { 1, 2 } });
+    }
+
+    [Fact]
+    public void NestedRecordsCompareByValue()
+    {
+        var names = new[] { "one", "two" };
+        var first = StructuralEqualityMethods.RecordHistory(names);
+        var second = StructuralEqualityMethods.RecordHistory(names);
+
+        Assert.Equal(first, second);
+        Assert.Equal(first.GetHashCode(), second.GetHashCode());
+        Assert.NotEqual(first, StructuralEqualityMethods.RecordHistory(new[] { "one" }));
+    }
+
+    [Fact]
+    public void RecordsWorkAsDictionaryKeys()
+    {
+        var cache = new Dictionary<Snapshot, int> { [StructuralEqualityMethods.TakeSnapshot("one")] = 1 };
+
+        Assert.True(cache.ContainsKey(StructuralEqualityMethods.TakeSnapshot("one")));
+        Assert.False(cache.ContainsKey(StructuralEqualityMethods.TakeSnapshot("two")));
+    }
+
+    [Fact]
+    public void EnumVariantsCompareCompoundFieldsByValue()
+    {
+        var batch = StructuralEqualityMethods.Batch(new uint[] { 1, 2, 3 });
+        Assert.Equal(batch, StructuralEqualityMethods.Batch(new uint[] { 1, 2, 3 }));
+        Assert.Equal(batch.GetHashCode(), StructuralEqualityMethods.Batch(new uint[] { 1, 2, 3 }).GetHashCode());
+        Assert.NotEqual(batch, StructuralEqualityMethods.Batch(new uint[] { 1, 2 }));
+
+        var message = StructuralEqualityMethods.Message(new byte[] { 4, 5 });
+        Assert.Equal(message, StructuralEqualityMethods.Message(new byte[] { 4, 5 }));
+        Assert.NotEqual<Event>(message, batch);
+    }
+
+    [Fact]
+    public void ExportedEqualityIsKept()
+    {
+        var first = StructuralEqualityMethods.Fingerprint(new byte[] { 1, 2 });
+        var second = StructuralEqualityMethods.Fingerprint(new byte[] { 1, 2 });
+
+        Assert.Equal(first, second);
+        Assert.Equal(first.GetHashCode(), second.GetHashCode());
+    }
+}
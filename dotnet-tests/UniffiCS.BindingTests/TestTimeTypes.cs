@@ -0,0 +1,99 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+using System;
+using NodaTime;
+using Clamped = uniffi.time_clamped;
+using Lossless = uniffi.time_lossless;
+using Noda = uniffi.time_noda;
+
+namespace UniffiCS.BindingTests;
+
+public class TestTimeTypes
+{
+    [Fact]
+    public void LosslessTimestampsKeepNanosecondsAndRange()
+    {
+        var farFuture = Lossless.TimeLosslessMethods.FromEpoch(400_000_000_000, 123_456_789);
+        Assert.Equal(new Lossless.UniffiTimestamp(400_000_000_000, 123_456_789), farFuture);
+        Assert.Throws<Lossless.UniffiTimeOverflowException>(() => farFuture.ToDateTime());
+
+        var beforeEpoch = Lossless.TimeLosslessMethods.FromEpoch(-100, 1);
+        Assert.Equal(new Lossless.UniffiTimestamp(-100, 1), beforeEpoch);
+        Assert.Equal("-99.999999999", Lossless.TimeLosslessMethods.SinceEpoch(beforeEpoch));
+
+        Assert.Equal(
+            new Lossless.UniffiTimestamp(1, 0),
+            Lossless.TimeLosslessMethods.Shift(
+                new Lossless.UniffiTimestamp(0, 999_999_999),
+                new Lossless.UniffiDuration(0, 1)
+            )
+        );
+    }
+
+    [Fact]
+    public void LosslessTimestampsConvertToDateTime()
+    {
+        var dateTime = new DateTime(1955, 11, 5, 0, 6, 0, DateTimeKind.Utc).AddTicks(2_830_001);
+        var timestamp = Lossless.UniffiTimestamp.FromDateTime(dateTime);
+        Assert.Equal(-446_774_040, timestamp.Seconds);
+        Assert.Equal(283_000_100u, timestamp.Nanoseconds);
+        Assert.Equal(dateTime, timestamp.ToDateTime());
+        Assert.Equal(new DateTimeOffset(dateTime), timestamp.ToDateTimeOffset());
+        Assert.True(timestamp < Lossless.UniffiTimestamp.FromDateTime(dateTime.AddTicks(1)));
+    }
+
+    [Fact]
+    public void LosslessDurationsKeepNanosecondsAndRange()
+    {
+        var longest = Lossless.TimeLosslessMethods.Duration(ulong.MaxValue, 999_999_999);
+        Assert.Equal(new Lossless.UniffiDuration(ulong.MaxValue, 999_999_999), longest);
+        Assert.Throws<Lossless.UniffiTimeOverflowException>(() => longest.ToTimeSpan());
+
+        Assert.Equal("1.000000005", Lossless.TimeLosslessMethods.DescribeDuration(new Lossless.UniffiDuration(1, 5)));
+        Assert.Equal(TimeSpan.FromSeconds(1.5), new Lossless.UniffiDuration(1, 500_000_000).ToTimeSpan());
+        Assert.Throws<Lossless.UniffiTimeOverflowException>(() => Lossless.UniffiDuration.FromTimeSpan(TimeSpan.FromSeconds(-1)));
+        Assert.Throws<ArgumentOutOfRangeException>(() => new Lossless.UniffiDuration(0, 1_000_000_000));
+    }
+
+    [Fact]
+    public void ClampedTimestampsStayInRange()
+    {
+        Assert.Equal(DateTimeOffset.MaxValue, Clamped.TimeClampedMethods.FromEpoch(400_000_000_000, 0));
+        Assert.Equal(DateTimeOffset.MinValue, Clamped.TimeClampedMethods.FromEpoch(-100_000_000_000, 0));
+
+        var offset = new DateTimeOffset(1970, 1, 1, 0, 0, 1, TimeSpan.FromHours(1));
+        Assert.Equal("-3599.000000000", Clamped.TimeClampedMethods.SinceEpoch(offset));
+        Assert.Equal(offset, Clamped.TimeClampedMethods.Shift(offset, TimeSpan.Zero));
+    }
+
+    [Fact]
+    public void ClampedDurationsStayInRange()
+    {
+        Assert.Equal(TimeSpan.MaxValue, Clamped.TimeClampedMethods.Duration(ulong.MaxValue, 0));
+        Assert.Equal("0.000000000", Clamped.TimeClampedMethods.DescribeDuration(TimeSpan.FromSeconds(-1)));
+    }
+
+    [Fact]
+    public void NodaTimeKeepsNanoseconds()
+    {
+        Assert.Equal(Instant.FromUnixTimeSeconds(1).PlusNanoseconds(5), Noda.TimeNodaMethods.FromEpoch(1, 5));
+        Assert.Equal(
+            Instant.FromUnixTimeSeconds(-10).PlusNanoseconds(3),
+            Noda.TimeNodaMethods.Shift(Instant.FromUnixTimeSeconds(-10).PlusNanoseconds(1), Duration.FromNanoseconds(2))
+        );
+        Assert.Equal(Duration.FromSeconds(1) + Duration.FromNanoseconds(5), Noda.TimeNodaMethods.Duration(1, 5));
+        Assert.Equal("1.000000005", Noda.TimeNodaMethods.DescribeDuration(Duration.FromSeconds(1) + Duration.FromNanoseconds(5)));
+    }
+
+    [Fact]
+    public void NodaTimeOverflowThrows()
+    {
+        Assert.Throws<Noda.UniffiTimeOverflowException>(() => Noda.TimeNodaMethods.FromEpoch(400_000_000_000, 0));
+        Assert.Throws<Noda.UniffiTimeOverflowException>(() => Noda.TimeNodaMethods.Duration(ulong.MaxValue, 0));
+        Assert.Throws<Noda.UniffiTimeOverflowException>(
+            () => Noda.TimeNodaMethods.DescribeDuration(Duration.FromSeconds(-1))
+        );
+    }
+}
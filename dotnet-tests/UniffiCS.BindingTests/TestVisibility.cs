@@ -0,0 +1,62 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+using System.Reflection;
+using uniffi.visibility;
+
+namespace UniffiCS.BindingTests;
+
+public class TestVisibility
+{
+    const BindingFlags AllStatic = BindingFlags.Public | BindingFlags.NonPublic | BindingFlags.Static;
+    const BindingFlags AllInstance = BindingFlags.Public | BindingFlags.NonPublic | BindingFlags.Instance;
+
+    [Fact]
+    public void ExcludedItemsAreNotGenerated()
+    {
+        Assert.Null(typeof(VisibilityMethods).GetMethod("MakeHidden", AllStatic));
+        Assert.Null(typeof(VisibilityMethods).Assembly.GetType("uniffi.visibility.Hidden"));
+        Assert.Null(typeof(Catalog).GetMethod("Dump", AllInstance));
+        Assert.Null(typeof(ICatalog).GetMethod("Dump"));
+    }
+
+    [Fact]
+    public void AccessModifiersAreOverridden()
+    {
+        Assert.True(typeof(Catalog).IsPublic);
+        Assert.True(typeof(VisibilityMethods).GetMethod("Greet", AllStatic)!.IsPublic);
+
+        Assert.False(typeof(Settings).IsPublic);
+        Assert.True(typeof(VisibilityMethods).GetMethod("DefaultSettings", AllStatic)!.IsAssembly);
+        Assert.True(typeof(Catalog).GetMethod("RawItems", AllInstance)!.IsAssembly);
+    }
+
+    [Fact]
+    public void NonPublicMethodsAreNotDeclaredByTheInterface()
+    {
+        Assert.NotNull(typeof(ICatalog).GetMethod("Count"));
+        Assert.Null(typeof(ICatalog).GetMethod("RawItems"));
+    }
+
+    [Fact]
+    public void OverridesApplyToRenamedItems()
+    {
+        Assert.True(typeof(Catalog).GetMethod("Total", AllInstance)!.IsAssembly);
+        Assert.Null(typeof(ICatalog).GetMethod("Total"));
+    }
+
+    [Fact]
+    public void GeneratedItemsStillWork()
+    {
+        Assert.Equal("Hello, bindings!", VisibilityMethods.Greet("bindings"));
+        Assert.True(VisibilityMethods.DefaultSettings().Verbose);
+
+        using var catalog = new Catalog();
+        catalog.Add("first");
+        catalog.Add("second");
+        Assert.Equal(2u, catalog.Count());
+        Assert.Equal(2u, catalog.Total());
+        Assert.Equal(new[] { "first", "second" }, catalog.RawItems());
+    }
+}
@@ -0,0 +1,88 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::Arc;
+
+// Same shapes as the `disposable` fixture, disposed without reflection in C#.
+
+static LIVE_COUNT: AtomicI32 = AtomicI32::new(0);
+
+#[derive(Debug, uniffi::Object)]
+pub struct Resource {}
+
+#[uniffi::export]
+impl Resource {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        LIVE_COUNT.fetch_add(1, Ordering::SeqCst);
+        Arc::new(Self {})
+    }
+}
+
+impl Drop for Resource {
+    fn drop(&mut self) {
+        LIVE_COUNT.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct ResourceJournal {
+    pub primary: Option<Arc<Resource>>,
+    pub resources: Vec<Arc<Resource>>,
+    pub nested: HashMap<i32, Option<Vec<Arc<Resource>>>>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum MaybeResource {
+    Some { resource: Arc<Resource> },
+    Journal(ResourceJournal),
+    None,
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum ResourceError {
+    #[error("Busy")]
+    Busy { resources: Vec<Arc<Resource>> },
+}
+
+#[uniffi::export]
+pub fn live_count() -> i32 {
+    LIVE_COUNT.load(Ordering::SeqCst)
+}
+
+#[uniffi::export]
+pub fn resource_journal() -> ResourceJournal {
+    ResourceJournal {
+        primary: Some(Resource::new()),
+        resources: vec![Resource::new(), Resource::new()],
+        nested: HashMap::from([(1, Some(vec![Resource::new()])), (2, None)]),
+    }
+}
+
+#[uniffi::export]
+pub fn maybe_resource(journal: bool) -> MaybeResource {
+    if journal {
+        MaybeResource::Journal(resource_journal())
+    } else {
+        MaybeResource::Some {
+            resource: Resource::new(),
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn busy() -> Result<(), ResourceError> {
+    Err(ResourceError::Busy {
+        resources: vec![Resource::new()],
+    })
+}
+
+#[uniffi::export]
+pub async fn async_resource() -> Arc<Resource> {
+    Resource::new()
+}
+
+uniffi::setup_scaffolding!();
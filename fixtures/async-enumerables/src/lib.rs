@@ -0,0 +1,71 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+
+static LIVE_FEEDS: AtomicU32 = AtomicU32::new(0);
+
+#[derive(uniffi::Record)]
+pub struct Event {
+    pub name: String,
+}
+
+// Feed of events, enumerated in C# as `IAsyncEnumerable<Event>`.
+#[derive(uniffi::Object)]
+pub struct EventFeed {
+    events: Mutex<VecDeque<Event>>,
+}
+
+#[uniffi::export]
+impl EventFeed {
+    #[uniffi::constructor]
+    pub fn new(names: Vec<String>) -> Arc<Self> {
+        LIVE_FEEDS.fetch_add(1, Ordering::SeqCst);
+        Arc::new(Self {
+            events: Mutex::new(names.into_iter().map(|name| Event { name }).collect()),
+        })
+    }
+
+    pub async fn next(&self) -> Option<Event> {
+        self.events.lock().unwrap().pop_front()
+    }
+}
+
+impl Drop for EventFeed {
+    fn drop(&mut self) {
+        LIVE_FEEDS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+// Enumerated in C# as `IAsyncEnumerable<uint>`.
+#[derive(uniffi::Object)]
+pub struct Countdown {
+    remaining: Mutex<u32>,
+}
+
+#[uniffi::export]
+impl Countdown {
+    #[uniffi::constructor]
+    pub fn new(from: u32) -> Arc<Self> {
+        Arc::new(Self {
+            remaining: Mutex::new(from),
+        })
+    }
+
+    pub async fn next(&self) -> Option<u32> {
+        let mut remaining = self.remaining.lock().unwrap();
+        let value = *remaining;
+        *remaining = value.checked_sub(1)?;
+        Some(value)
+    }
+}
+
+#[uniffi::export]
+pub fn live_feeds() -> u32 {
+    LIVE_FEEDS.load(Ordering::SeqCst)
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,76 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::{Arc, Mutex};
+
+// Async functions, methods and constructors get the `Async` suffix, see `async_suffix` in
+// `uniffi.toml`.
+
+#[uniffi::export]
+pub async fn greet(name: String) -> String {
+    format!("Hello, {name}!")
+}
+
+// Already suffixed, so not renamed to `LoadAsyncAsync`.
+#[uniffi::export]
+pub async fn load_async() -> u32 {
+    42
+}
+
+#[uniffi::export]
+pub fn version() -> u32 {
+    1
+}
+
+#[derive(uniffi::Record)]
+pub struct Chunk {
+    pub data: String,
+}
+
+#[uniffi::export]
+impl Chunk {
+    pub async fn length(&self) -> u32 {
+        self.data.len() as u32
+    }
+}
+
+#[uniffi::export(with_foreign)]
+#[async_trait::async_trait]
+pub trait Source: Send + Sync {
+    async fn read(&self) -> String;
+}
+
+#[derive(uniffi::Object)]
+pub struct Downloader {
+    downloads: Mutex<Vec<String>>,
+}
+
+#[uniffi::export]
+impl Downloader {
+    #[uniffi::constructor]
+    pub async fn new() -> Arc<Self> {
+        Arc::new(Self {
+            downloads: Mutex::new(Vec::new()),
+        })
+    }
+
+    #[uniffi::constructor]
+    pub async fn with_history(history: Vec<String>) -> Arc<Self> {
+        Arc::new(Self {
+            downloads: Mutex::new(history),
+        })
+    }
+
+    pub async fn download(&self, source: Arc<dyn Source>) -> String {
+        let data = source.read().await;
+        self.downloads.lock().unwrap().push(data.clone());
+        data
+    }
+
+    pub fn count(&self) -> u32 {
+        self.downloads.lock().unwrap().len() as u32
+    }
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,61 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum CounterError {
+    #[error("Overflow")]
+    Overflow,
+}
+
+#[uniffi::export]
+pub async fn add(a: u32, b: u32) -> Result<u32, CounterError> {
+    a.checked_add(b).ok_or(CounterError::Overflow)
+}
+
+#[derive(uniffi::Record)]
+pub struct Step {
+    pub amount: u32,
+}
+
+#[uniffi::export]
+impl Step {
+    pub async fn doubled(&self) -> u32 {
+        self.amount * 2
+    }
+}
+
+#[derive(uniffi::Object)]
+pub struct Counter {
+    value: Mutex<u32>,
+}
+
+#[uniffi::export]
+impl Counter {
+    #[uniffi::constructor]
+    pub async fn new(initial: u32) -> Arc<Self> {
+        Arc::new(Self {
+            value: Mutex::new(initial),
+        })
+    }
+
+    pub async fn increment(&self, step: Step) -> Result<u32, CounterError> {
+        let mut value = self.value.lock().unwrap();
+        *value = value
+            .checked_add(step.amount)
+            .ok_or(CounterError::Overflow)?;
+        Ok(*value)
+    }
+
+    pub async fn reset(&self) {
+        *self.value.lock().unwrap() = 0;
+    }
+
+    pub fn value(&self) -> u32 {
+        *self.value.lock().unwrap()
+    }
+}
+
+uniffi::setup_scaffolding!();
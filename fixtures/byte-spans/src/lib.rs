@@ -0,0 +1,76 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::{Arc, Mutex};
+
+// `bytes` arguments get `ReadOnlySpan<byte>` / `ReadOnlyMemory<byte>` overloads in C#.
+
+fn sum(data: &[u8]) -> u32 {
+    data.iter()
+        .fold(0u32, |acc, b| acc.wrapping_mul(31).wrapping_add(*b as u32))
+}
+
+#[uniffi::export]
+pub fn checksum(data: Vec<u8>) -> u32 {
+    sum(&data)
+}
+
+#[uniffi::export]
+pub fn concat(first: Vec<u8>, separator: u8, second: Vec<u8>) -> Vec<u8> {
+    let mut result = first;
+    result.push(separator);
+    result.extend(second);
+    result
+}
+
+// The span overload can't keep the default of `prefix`, because `data` has none there.
+#[uniffi::export(default(prefix = false, data = []))]
+pub fn prefixed_checksum(prefix: bool, data: Vec<u8>) -> u32 {
+    if prefix {
+        sum(&[&[0xFF], data.as_slice()].concat())
+    } else {
+        sum(&data)
+    }
+}
+
+#[uniffi::export]
+pub async fn checksum_async(data: Vec<u8>) -> u32 {
+    sum(&data)
+}
+
+#[uniffi::export]
+pub fn utf8_length(value: String) -> u64 {
+    value.len() as u64
+}
+
+#[derive(uniffi::Object)]
+pub struct Hasher {
+    data: Mutex<Vec<u8>>,
+}
+
+#[uniffi::export]
+impl Hasher {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            data: Mutex::new(Vec::new()),
+        })
+    }
+
+    pub fn update(&self, data: Vec<u8>) {
+        self.data.lock().unwrap().extend(data);
+    }
+
+    pub async fn update_async(&self, data: Vec<u8>) -> u64 {
+        let mut contents = self.data.lock().unwrap();
+        contents.extend(data);
+        contents.len() as u64
+    }
+
+    pub fn digest(&self) -> u32 {
+        sum(&self.data.lock().unwrap())
+    }
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,96 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+static POLLED_FUTURES: AtomicU32 = AtomicU32::new(0);
+static DROPPED_FUTURES: AtomicU32 = AtomicU32::new(0);
+
+// Future that never completes, and counts how many times it was polled and dropped.
+struct Pending {
+    polled: bool,
+}
+
+impl Future for Pending {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        if !self.polled {
+            self.polled = true;
+            POLLED_FUTURES.fetch_add(1, Ordering::SeqCst);
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Pending {
+    fn drop(&mut self) {
+        DROPPED_FUTURES.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[uniffi::export]
+pub fn polled_futures() -> u32 {
+    POLLED_FUTURES.load(Ordering::SeqCst)
+}
+
+#[uniffi::export]
+pub fn dropped_futures() -> u32 {
+    DROPPED_FUTURES.load(Ordering::SeqCst)
+}
+
+#[uniffi::export]
+pub async fn wait_forever() -> u32 {
+    Pending { polled: false }.await;
+    0
+}
+
+#[uniffi::export]
+pub async fn wait_forever_void() {
+    Pending { polled: false }.await;
+}
+
+#[uniffi::export]
+pub async fn ready(value: u32) -> u32 {
+    value
+}
+
+#[derive(uniffi::Record)]
+pub struct Ticket {
+    pub id: u32,
+}
+
+#[uniffi::export]
+impl Ticket {
+    pub async fn wait_forever(&self) -> u32 {
+        Pending { polled: false }.await;
+        self.id
+    }
+}
+
+#[derive(uniffi::Object)]
+pub struct Worker {}
+
+#[uniffi::export]
+impl Worker {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {})
+    }
+
+    pub async fn wait_forever(&self) -> u32 {
+        Pending { polled: false }.await;
+        0
+    }
+
+    pub async fn ready(&self, value: u32) -> u32 {
+        value
+    }
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,74 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+// Sequences and maps generated as read-only collections, with some fields and arguments
+// overridden, see `uniffi.toml`.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Inventory {
+    pub name: String,
+    // `ImmutableArray<string>`.
+    pub items: Vec<String>,
+    // `ImmutableDictionary<string, double>`.
+    pub prices: HashMap<String, f64>,
+    // `IReadOnlyList<string>`, like all other sequences.
+    pub tags: Vec<String>,
+}
+
+#[uniffi::export]
+pub fn restock(inventory: Inventory, extra: Vec<String>) -> Inventory {
+    let mut items = inventory.items;
+    items.extend(extra);
+    Inventory { items, ..inventory }
+}
+
+// `values` is a `List<int>`.
+#[uniffi::export]
+pub fn sum(values: Vec<i32>) -> i32 {
+    values.into_iter().sum()
+}
+
+#[uniffi::export]
+pub fn tally(words: Vec<String>) -> HashMap<String, u32> {
+    let mut counts = HashMap::new();
+    for word in words {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[uniffi::export]
+pub fn transpose(rows: Vec<Vec<i32>>) -> Vec<Vec<i32>> {
+    let width = rows.first().map_or(0, Vec::len);
+    (0..width)
+        .map(|column| rows.iter().map(|row| row[column]).collect())
+        .collect()
+}
+
+#[derive(Debug, Default, uniffi::Object)]
+pub struct Basket {
+    items: Mutex<Vec<String>>,
+}
+
+#[uniffi::export]
+impl Basket {
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // `items` is a `string[]`.
+    pub fn add(&self, items: Vec<String>) {
+        self.items.lock().unwrap().extend(items);
+    }
+
+    pub fn items(&self) -> Vec<String> {
+        self.items.lock().unwrap().clone()
+    }
+}
+
+uniffi::setup_scaffolding!();
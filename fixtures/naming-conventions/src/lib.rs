@@ -0,0 +1,78 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+// Names are rendered with the rules configured under `[bindings.csharp.naming]` in `uniffi.toml`.
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum StoreError {
+    #[error("key not found: {key}")]
+    NotFound { key: String },
+    #[error("listener rejected the value")]
+    RejectedError,
+}
+
+#[uniffi::export(with_foreign)]
+pub trait Listener: Send + Sync {
+    fn accept(&self, key: String, value: String) -> bool;
+}
+
+#[derive(uniffi::Object)]
+pub struct Store {
+    entries: Mutex<HashMap<String, String>>,
+    listener: Option<Arc<dyn Listener>>,
+}
+
+#[uniffi::export]
+impl Store {
+    #[uniffi::constructor]
+    pub fn new(listener: Option<Arc<dyn Listener>>) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            listener,
+        }
+    }
+
+    pub fn put(&self, key: String, value: String) -> Result<(), StoreError> {
+        if let Some(listener) = &self.listener {
+            if !listener.accept(key.clone(), value.clone()) {
+                return Err(StoreError::RejectedError);
+            }
+        }
+        self.entries.lock().unwrap().insert(key, value);
+        Ok(())
+    }
+
+    pub fn get(&self, key: String) -> Result<String, StoreError> {
+        self.entries
+            .lock()
+            .unwrap()
+            .get(&key)
+            .cloned()
+            .ok_or(StoreError::NotFound { key })
+    }
+
+    // Named like the class, so rendered with `class_method_suffix`.
+    pub fn store(&self) -> u32 {
+        self.entries.lock().unwrap().len() as u32
+    }
+}
+
+// Always rejects, for checking that Rust implementations of the trait get `impl_name`.
+struct RejectAll;
+
+impl Listener for RejectAll {
+    fn accept(&self, _key: String, _value: String) -> bool {
+        false
+    }
+}
+
+#[uniffi::export]
+pub fn reject_all() -> Arc<dyn Listener> {
+    Arc::new(RejectAll)
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,75 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+// Optionals of optionals, which keep `None` apart from `Some(None)` in C#.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Profile {
+    pub name: String,
+    pub nickname: Option<String>,
+    pub age: Option<u32>,
+}
+
+// Update of a profile: `None` leaves a field as is, `Some(None)` clears it.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ProfilePatch {
+    #[uniffi(default = None)]
+    pub nickname: Option<Option<String>>,
+    #[uniffi(default = None)]
+    pub age: Option<Option<u32>>,
+}
+
+#[uniffi::export]
+pub fn apply_patch(profile: Profile, patch: ProfilePatch) -> Profile {
+    Profile {
+        nickname: patch.nickname.unwrap_or(profile.nickname),
+        age: patch.age.unwrap_or(profile.age),
+        ..profile
+    }
+}
+
+#[uniffi::export]
+pub fn echo(value: Option<Option<String>>) -> Option<Option<String>> {
+    value
+}
+
+#[uniffi::export]
+pub fn describe(value: Option<Option<String>>) -> String {
+    match value {
+        None => "none".to_string(),
+        Some(None) => "some(none)".to_string(),
+        Some(Some(value)) => format!("some({value})"),
+    }
+}
+
+#[uniffi::export]
+pub fn echo_all(values: Vec<Option<Option<i32>>>) -> Vec<Option<Option<i32>>> {
+    values
+}
+
+// Answers of a survey, enumerated in C# as `IAsyncEnumerable<string?>`. A skipped question
+// has no answer, but doesn't end the enumeration.
+#[derive(uniffi::Object)]
+pub struct Survey {
+    answers: Mutex<VecDeque<Option<String>>>,
+}
+
+#[uniffi::export]
+impl Survey {
+    #[uniffi::constructor]
+    pub fn new(answers: Vec<Option<String>>) -> Arc<Self> {
+        Arc::new(Self {
+            answers: Mutex::new(answers.into()),
+        })
+    }
+
+    pub async fn next(&self) -> Option<Option<String>> {
+        self.answers.lock().unwrap().pop_front()
+    }
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,75 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::Mutex;
+
+// Accessors are rendered as properties, see `object_properties` in `uniffi.toml`.
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum DocumentError {
+    #[error("document has no title")]
+    Untitled,
+}
+
+#[derive(uniffi::Object)]
+pub struct Document {
+    name: Mutex<String>,
+    lines: Mutex<Vec<String>>,
+}
+
+#[uniffi::export]
+impl Document {
+    #[uniffi::constructor]
+    pub fn new(name: String) -> Self {
+        Self {
+            name: Mutex::new(name),
+            lines: Mutex::new(Vec::new()),
+        }
+    }
+
+    // Property `Name` with a setter.
+    pub fn name(&self) -> String {
+        self.name.lock().unwrap().clone()
+    }
+
+    pub fn set_name(&self, name: String) {
+        *self.name.lock().unwrap() = name;
+    }
+
+    // Read-only property `LineCount`.
+    pub fn get_line_count(&self) -> u32 {
+        self.lines.lock().unwrap().len() as u32
+    }
+
+    // Read-only property `IsEmpty`.
+    pub fn is_empty(&self) -> bool {
+        self.lines.lock().unwrap().is_empty()
+    }
+
+    // Property `Label`, so `get_label` stays a method.
+    pub fn label(&self) -> String {
+        format!("[{}]", self.name())
+    }
+
+    pub fn get_label(&self) -> String {
+        self.label()
+    }
+
+    // Throws, so stays a method.
+    pub fn title(&self) -> Result<String, DocumentError> {
+        self.lines
+            .lock()
+            .unwrap()
+            .first()
+            .cloned()
+            .ok_or(DocumentError::Untitled)
+    }
+
+    // Takes arguments, so stays a method.
+    pub fn add_line(&self, line: String) {
+        self.lines.lock().unwrap().push(line);
+    }
+}
+
+uniffi::setup_scaffolding!();
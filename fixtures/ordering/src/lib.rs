@@ -0,0 +1,110 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+// Types exporting `Ord`. The orderings differ from what C# would do by default, to make sure
+// comparisons call into Rust.
+
+/// Versions order by major, then minor, ignoring the label.
+#[derive(Debug, Clone, uniffi::Record)]
+#[uniffi::export(Eq, Ord)]
+pub struct AppVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub label: String,
+}
+
+impl PartialEq for AppVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for AppVersion {}
+
+impl PartialOrd for AppVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AppVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor).cmp(&(other.major, other.minor))
+    }
+}
+
+/// Priorities order by their variant, then by their value in reverse.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Enum)]
+#[uniffi::export(Eq, Ord)]
+pub enum Priority {
+    Low { value: i32 },
+    High { value: i32 },
+}
+
+impl PartialOrd for Priority {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Priority {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let key = |priority: &Priority| match priority {
+            Priority::Low { value } => (0, -value),
+            Priority::High { value } => (1, -value),
+        };
+        key(self).cmp(&key(other))
+    }
+}
+
+/// Names order case-insensitively.
+#[derive(Debug, uniffi::Object)]
+#[uniffi::export(Eq, Hash, Ord)]
+pub struct Name {
+    value: String,
+}
+
+#[uniffi::export]
+impl Name {
+    #[uniffi::constructor]
+    pub fn new(value: String) -> Arc<Self> {
+        Arc::new(Self { value })
+    }
+
+    pub fn value(&self) -> String {
+        self.value.clone()
+    }
+}
+
+impl PartialEq for Name {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Name {}
+
+impl Hash for Name {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.to_lowercase().hash(state);
+    }
+}
+
+impl PartialOrd for Name {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Name {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.to_lowercase().cmp(&other.value.to_lowercase())
+    }
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,65 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+// Records generated as `readonly record struct`, see `uniffi.toml`.
+
+/// Only fixed-size primitives, so a struct.
+#[derive(Debug, Clone, Copy, uniffi::Record)]
+pub struct Vec2 {
+    pub x: f64,
+    #[uniffi(default = 0.0)]
+    pub y: f64,
+}
+
+/// Configured as a struct.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Label {
+    pub text: String,
+    pub position: Vec2,
+}
+
+/// Exports traits, so stays a record.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, uniffi::Record)]
+#[uniffi::export(Eq, Hash)]
+pub struct Cell {
+    pub row: u32,
+    pub column: u32,
+}
+
+#[uniffi::export]
+pub fn scale(points: Vec<Vec2>, factor: f64) -> Vec<Vec2> {
+    points
+        .into_iter()
+        .map(|p| Vec2 {
+            x: p.x * factor,
+            y: p.y * factor,
+        })
+        .collect()
+}
+
+#[uniffi::export]
+pub fn origin_or(point: Option<Vec2>) -> Vec2 {
+    point.unwrap_or(Vec2 { x: 0.0, y: 0.0 })
+}
+
+#[uniffi::export]
+pub fn nudge(label: Label) -> Label {
+    Label {
+        position: Vec2 {
+            x: label.position.x + 1.0,
+            ..label.position
+        },
+        ..label
+    }
+}
+
+#[uniffi::export]
+pub fn next_cell(cell: Cell) -> Cell {
+    Cell {
+        column: cell.column + 1,
+        ..cell
+    }
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,55 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+// Records generated with each `record_style`, see `uniffi.toml`.
+
+/// Observable class.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Profile {
+    pub name: String,
+    #[uniffi(default = None)]
+    pub nickname: Option<String>,
+    pub tags: Vec<String>,
+}
+
+/// Class with the global style, comparing through Rust.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, uniffi::Record)]
+#[uniffi::export(Eq, Hash)]
+pub struct Settings {
+    pub theme: String,
+    pub volume: u8,
+}
+
+/// Positional record.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[uniffi::export]
+pub fn describe_profile(profile: Profile) -> String {
+    match profile.nickname {
+        Some(nickname) => format!("{} ({nickname}): {}", profile.name, profile.tags.join(", ")),
+        None => format!("{}: {}", profile.name, profile.tags.join(", ")),
+    }
+}
+
+#[uniffi::export]
+pub fn louder(settings: Settings) -> Settings {
+    Settings {
+        volume: settings.volume.saturating_add(10),
+        ..settings
+    }
+}
+
+#[uniffi::export]
+pub fn mirror(point: Point) -> Point {
+    Point {
+        x: -point.x,
+        y: -point.y,
+    }
+}
+
+uniffi::setup_scaffolding!();
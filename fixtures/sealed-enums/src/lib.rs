@@ -0,0 +1,52 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+// Enums with associated data, generated as sealed C# record hierarchies.
+
+#[derive(Debug, Clone, PartialEq, uniffi::Enum)]
+pub enum Shape {
+    Circle { radius: f64 },
+    Rectangle { width: f64, height: f64 },
+    Point,
+}
+
+#[derive(Debug, Clone, PartialEq, uniffi::Enum)]
+pub enum Message {
+    Text(String),
+    Move { x: i32, y: i32 },
+    Quit,
+}
+
+#[uniffi::export]
+pub fn unit_square() -> Shape {
+    Shape::Rectangle {
+        width: 1.0,
+        height: 1.0,
+    }
+}
+
+#[uniffi::export]
+pub fn scale(shape: Shape, factor: f64) -> Shape {
+    match shape {
+        Shape::Circle { radius } => Shape::Circle {
+            radius: radius * factor,
+        },
+        Shape::Rectangle { width, height } => Shape::Rectangle {
+            width: width * factor,
+            height: height * factor,
+        },
+        Shape::Point => Shape::Point,
+    }
+}
+
+#[uniffi::export]
+pub fn messages() -> Vec<Message> {
+    vec![
+        Message::Text("hello".to_string()),
+        Message::Move { x: 1, y: -1 },
+        Message::Quit,
+    ]
+}
+
+uniffi::setup_scaffolding!();
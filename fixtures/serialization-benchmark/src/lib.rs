@@ -0,0 +1,64 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+
+// Large payloads used to measure the allocations of `RustBuffer` serialization in C#.
+
+#[derive(uniffi::Record)]
+pub struct Sample {
+    pub id: u64,
+    pub name: String,
+    pub score: f64,
+    pub tags: Vec<String>,
+}
+
+#[derive(uniffi::Record)]
+pub struct SampleSummary {
+    pub count: u64,
+    pub id_sum: u64,
+    pub name_bytes: u64,
+    pub tag_count: u64,
+}
+
+#[uniffi::export]
+pub fn make_samples(count: u32) -> Vec<Sample> {
+    (0..count as u64)
+        .map(|id| Sample {
+            id,
+            name: format!("sample-{id}"),
+            score: id as f64 / 2.0,
+            tags: vec!["a".to_string(); (id % 3) as usize],
+        })
+        .collect()
+}
+
+#[uniffi::export]
+pub fn summarize_samples(samples: Vec<Sample>) -> SampleSummary {
+    SampleSummary {
+        count: samples.len() as u64,
+        id_sum: samples.iter().map(|s| s.id).sum(),
+        name_bytes: samples.iter().map(|s| s.name.len() as u64).sum(),
+        tag_count: samples.iter().map(|s| s.tags.len() as u64).sum(),
+    }
+}
+
+#[uniffi::export]
+pub fn make_nested(outer: u32, inner: u32) -> HashMap<String, HashMap<String, i64>> {
+    (0..outer)
+        .map(|o| {
+            let values = (0..inner)
+                .map(|i| (format!("inner-{i}"), o as i64 * inner as i64 + i as i64))
+                .collect();
+            (format!("outer-{o}"), values)
+        })
+        .collect()
+}
+
+#[uniffi::export]
+pub fn sum_nested(values: HashMap<String, HashMap<String, i64>>) -> i64 {
+    values.values().flat_map(|inner| inner.values()).sum()
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,66 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+// Bindings for this crate don't inline the FFI runtime, see `generate_bindings.sh` for how the
+// shared runtime is generated.
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum VaultError {
+    #[error("Empty")]
+    Empty,
+}
+
+#[uniffi::export(callback_interface)]
+pub trait Logger: Send + Sync {
+    fn log(&self, message: String);
+}
+
+#[derive(uniffi::Object)]
+pub struct Vault {
+    contents: Mutex<Option<Vec<u8>>>,
+}
+
+#[uniffi::export]
+impl Vault {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            contents: Mutex::new(None),
+        })
+    }
+
+    pub fn store(&self, contents: Vec<u8>) {
+        *self.contents.lock().unwrap() = Some(contents);
+    }
+
+    pub fn open(&self) -> Result<Vec<u8>, VaultError> {
+        self.contents
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or(VaultError::Empty)
+    }
+}
+
+#[uniffi::export]
+pub fn greet(name: String) -> String {
+    format!("Hello, {name}!")
+}
+
+#[uniffi::export]
+pub fn add_duration(time: SystemTime, duration: Duration) -> SystemTime {
+    time + duration
+}
+
+#[uniffi::export]
+pub fn log_all(logger: Box<dyn Logger>, messages: Vec<String>) {
+    for message in messages {
+        logger.log(message);
+    }
+}
+
+uniffi::setup_scaffolding!();
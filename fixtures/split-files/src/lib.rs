@@ -0,0 +1,89 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+// Each of the types below is generated into its own file with `split_files = true`.
+
+#[derive(Clone, uniffi::Record)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Clone, uniffi::Enum)]
+pub enum Direction {
+    North,
+    South,
+}
+
+#[derive(Clone, uniffi::Enum)]
+pub enum Shape {
+    Dot { point: Point },
+    Line { from: Point, to: Point },
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum CanvasError {
+    #[error("OutOfBounds")]
+    OutOfBounds { x: i32, y: i32 },
+}
+
+#[uniffi::export(callback_interface)]
+pub trait CanvasListener: Send + Sync {
+    fn on_draw(&self, shape: Shape);
+}
+
+#[derive(uniffi::Object)]
+pub struct Canvas {
+    shapes: Mutex<Vec<Shape>>,
+    labels: Mutex<HashMap<String, Direction>>,
+}
+
+#[uniffi::export]
+impl Canvas {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            shapes: Mutex::new(Vec::new()),
+            labels: Mutex::new(HashMap::new()),
+        })
+    }
+
+    pub fn draw(&self, shape: Shape, listener: Box<dyn CanvasListener>) -> Result<(), CanvasError> {
+        let points = match &shape {
+            Shape::Dot { point } => vec![point],
+            Shape::Line { from, to } => vec![from, to],
+        };
+        if let Some(p) = points.into_iter().find(|p| p.x < 0 || p.y < 0) {
+            return Err(CanvasError::OutOfBounds { x: p.x, y: p.y });
+        }
+        listener.on_draw(shape.clone());
+        self.shapes.lock().unwrap().push(shape);
+        Ok(())
+    }
+
+    pub fn shape_count(&self) -> u32 {
+        self.shapes.lock().unwrap().len() as u32
+    }
+
+    pub fn label(&self, name: String, direction: Direction) {
+        self.labels.lock().unwrap().insert(name, direction);
+    }
+
+    pub fn labels(&self) -> HashMap<String, Direction> {
+        self.labels.lock().unwrap().clone()
+    }
+}
+
+#[uniffi::export]
+pub fn midpoint(from: Point, to: Point) -> Point {
+    Point {
+        x: (from.x + to.x) / 2,
+        y: (from.y + to.y) / 2,
+    }
+}
+
+uniffi::setup_scaffolding!();
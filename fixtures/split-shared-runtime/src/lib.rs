@@ -0,0 +1,56 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::{Arc, Mutex};
+
+// Each type is generated into its own file, which uses the shared runtime instead of the main
+// bindings file of the component.
+
+#[derive(Clone, uniffi::Record)]
+pub struct Entry {
+    pub key: String,
+    pub value: Vec<u8>,
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum LedgerError {
+    #[error("Missing")]
+    Missing { key: String },
+}
+
+#[derive(uniffi::Object)]
+pub struct Ledger {
+    entries: Mutex<Vec<Entry>>,
+}
+
+#[uniffi::export]
+impl Ledger {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            entries: Mutex::new(Vec::new()),
+        })
+    }
+
+    pub fn record(&self, entry: Entry) {
+        self.entries.lock().unwrap().push(entry);
+    }
+
+    pub fn find(&self, key: String) -> Result<Entry, LedgerError> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|entry| entry.key == key)
+            .cloned()
+            .ok_or(LedgerError::Missing { key })
+    }
+}
+
+#[uniffi::export]
+pub fn entry_size(entry: Entry) -> u32 {
+    (entry.key.len() + entry.value.len()) as u32
+}
+
+uniffi::setup_scaffolding!();
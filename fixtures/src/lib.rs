@@ -22,12 +22,37 @@ mod uniffi_fixtures {
 
     global_methods_class_name::uniffi_reexport_scaffolding!();
     null_to_empty_string::uniffi_reexport_scaffolding!();
+    aot_compatible::uniffi_reexport_scaffolding!();
+    async_blocking::uniffi_reexport_scaffolding!();
+    async_enumerables::uniffi_reexport_scaffolding!();
+    async_suffix::uniffi_reexport_scaffolding!();
+    async_value_task::uniffi_reexport_scaffolding!();
+    byte_spans::uniffi_reexport_scaffolding!();
+    cancellation::uniffi_reexport_scaffolding!();
+    collection_types::uniffi_reexport_scaffolding!();
     uniffi_cs_custom_types_builtin::uniffi_reexport_scaffolding!();
     uniffi_cs_disposable::uniffi_reexport_scaffolding!();
+    naming_conventions::uniffi_reexport_scaffolding!();
+    nested_optionals::uniffi_reexport_scaffolding!();
+    object_properties::uniffi_reexport_scaffolding!();
     uniffi_cs_optional_parameters::uniffi_reexport_scaffolding!();
+    ordering::uniffi_reexport_scaffolding!();
     uniffi_cs_positional_enums::uniffi_reexport_scaffolding!();
+    reference_equality::uniffi_reexport_scaffolding!();
+    record_structs::uniffi_reexport_scaffolding!();
+    record_styles::uniffi_reexport_scaffolding!();
     uniffi_cs_repr_enums::uniffi_reexport_scaffolding!();
+    sealed_enums::uniffi_reexport_scaffolding!();
+    serialization_benchmark::uniffi_reexport_scaffolding!();
+    shared_runtime::uniffi_reexport_scaffolding!();
+    split_files::uniffi_reexport_scaffolding!();
+    split_shared_runtime::uniffi_reexport_scaffolding!();
     stringify::uniffi_reexport_scaffolding!();
+    structural_equality::uniffi_reexport_scaffolding!();
+    time_clamped::uniffi_reexport_scaffolding!();
+    time_lossless::uniffi_reexport_scaffolding!();
+    time_noda::uniffi_reexport_scaffolding!();
+    visibility::uniffi_reexport_scaffolding!();
     issue_28::uniffi_reexport_scaffolding!();
     issue_60::uniffi_reexport_scaffolding!();
     issue_75::uniffi_reexport_scaffolding!();
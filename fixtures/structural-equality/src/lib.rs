@@ -0,0 +1,72 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+// Records and enum variants with sequence, map and bytes fields, which get
+// generated `Equals` and `GetHashCode` comparing those fields by value.
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Snapshot {
+    pub name: String,
+    pub tags: Vec<String>,
+    pub counts: HashMap<String, u32>,
+    pub payload: Vec<u8>,
+    pub thumbnail: Option<Vec<u8>>,
+}
+
+/// Nested records compare through their own generated equality.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct History {
+    pub snapshots: Vec<Snapshot>,
+}
+
+/// Exports `Eq`, so equality comes from Rust.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, uniffi::Record)]
+#[uniffi::export(Eq, Hash)]
+pub struct Fingerprint {
+    pub digest: Vec<u8>,
+}
+
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum Event {
+    Batch { ids: Vec<u32> },
+    Message(Vec<u8>),
+    Renamed { name: String },
+}
+
+#[uniffi::export]
+pub fn take_snapshot(name: String) -> Snapshot {
+    Snapshot {
+        tags: vec!["a".to_string(), "b".to_string()],
+        counts: HashMap::from([("x".to_string(), 1), ("y".to_string(), 2)]),
+        payload: name.as_bytes().to_vec(),
+        thumbnail: Some(vec![1, 2, 3]),
+        name,
+    }
+}
+
+#[uniffi::export]
+pub fn record_history(names: Vec<String>) -> History {
+    History {
+        snapshots: names.into_iter().map(take_snapshot).collect(),
+    }
+}
+
+#[uniffi::export]
+pub fn fingerprint(data: Vec<u8>) -> Fingerprint {
+    Fingerprint { digest: data }
+}
+
+#[uniffi::export]
+pub fn batch(ids: Vec<u32>) -> Event {
+    Event::Batch { ids }
+}
+
+#[uniffi::export]
+pub fn message(data: Vec<u8>) -> Event {
+    Event::Message(data)
+}
+
+uniffi::setup_scaffolding!();
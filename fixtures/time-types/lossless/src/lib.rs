@@ -0,0 +1,48 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+// Timestamps and durations generated as `UniffiTimestamp` and `UniffiDuration`, without loss of
+// precision or range.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Timestamp of `seconds` since the epoch plus `nanoseconds`, which may be outside the range of C#
+// types.
+#[uniffi::export]
+pub fn from_epoch(seconds: i64, nanoseconds: u32) -> SystemTime {
+    let whole = if seconds < 0 {
+        UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs())
+    } else {
+        UNIX_EPOCH + Duration::from_secs(seconds as u64)
+    };
+    whole + Duration::from_nanos(nanoseconds.into())
+}
+
+#[uniffi::export]
+pub fn since_epoch(at: SystemTime) -> String {
+    match at.duration_since(UNIX_EPOCH) {
+        Ok(after) => format!("{}.{:09}", after.as_secs(), after.subsec_nanos()),
+        Err(before) => {
+            let before = before.duration();
+            format!("-{}.{:09}", before.as_secs(), before.subsec_nanos())
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn duration(seconds: u64, nanoseconds: u32) -> Duration {
+    Duration::new(seconds, nanoseconds)
+}
+
+#[uniffi::export]
+pub fn describe_duration(duration: Duration) -> String {
+    format!("{}.{:09}", duration.as_secs(), duration.subsec_nanos())
+}
+
+#[uniffi::export]
+pub fn shift(at: SystemTime, by: Duration) -> SystemTime {
+    at + by
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,69 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::{Arc, Mutex};
+
+// Items configured under `[bindings.csharp.visibility]` in `uniffi.toml`.
+
+#[derive(uniffi::Record)]
+pub struct Settings {
+    pub verbose: bool,
+}
+
+#[derive(uniffi::Record)]
+pub struct Hidden {
+    pub secret: u32,
+}
+
+#[uniffi::export]
+pub fn greet(name: String) -> String {
+    format!("Hello, {name}!")
+}
+
+#[uniffi::export]
+pub fn default_settings() -> Settings {
+    Settings { verbose: true }
+}
+
+#[uniffi::export]
+pub fn make_hidden() -> Hidden {
+    Hidden { secret: 42 }
+}
+
+#[derive(uniffi::Object)]
+pub struct Catalog {
+    items: Mutex<Vec<String>>,
+}
+
+#[uniffi::export]
+impl Catalog {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            items: Mutex::new(Vec::new()),
+        })
+    }
+
+    pub fn add(&self, item: String) {
+        self.items.lock().unwrap().push(item);
+    }
+
+    pub fn count(&self) -> u32 {
+        self.items.lock().unwrap().len() as u32
+    }
+
+    pub fn raw_items(&self) -> Vec<String> {
+        self.items.lock().unwrap().clone()
+    }
+
+    pub fn legacy_count(&self) -> u32 {
+        self.count()
+    }
+
+    pub fn dump(&self) -> String {
+        format!("{:?}", self.items.lock().unwrap())
+    }
+}
+
+uniffi::setup_scaffolding!();
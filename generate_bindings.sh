@@ -22,4 +22,7 @@ esac
 rm -rf "$GEN_DIR"
 mkdir -p "$GEN_DIR"
 
-target/debug/uniffi-bindgen-cs target/debug/$LIB --library --out-dir="$GEN_DIR" --no-format
\ No newline at end of file
+target/debug/uniffi-bindgen-cs target/debug/$LIB --library --out-dir="$GEN_DIR" --no-format
+
+# Runtime shared by fixtures configured with `shared_runtime = true`
+target/debug/uniffi-bindgen-cs target/debug/$LIB --library --crate shared_runtime --runtime --out-dir="$GEN_DIR" --no-format